@@ -13,12 +13,41 @@ pub enum CreateSignedSetKeysExtrinsicError {
     SessionKeysDecoding(String),
     /// Unable to create signed set_keys extrinsic.
     SignedExtrinsicCreation,
+    /// The provided unsigned set_keys extrinsic data is invalid.
+    InvalidUnsignedExtrinsic(String),
+    /// The provided signature is invalid.
+    InvalidSignature,
+}
+
+/// The data required to sign the set_keys extrinsic outside of the node.
+#[derive(Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+pub struct SetKeysSigningPayload {
+    /// The encoded call and signed extensions of the extrinsic, to be submitted back with
+    /// the signature.
+    pub unsigned_extrinsic: Vec<u8>,
+    /// The exact bytes to sign.
+    pub payload: Vec<u8>,
 }
 
 sp_api::decl_runtime_apis! {
     /// Runtime API for the author extension logic.
+    #[api_version(3)]
     pub trait AuthorExtApi<Id: Encode> {
         /// Create signed set_keys extrinsic.
         fn create_signed_set_keys_extrinsic(id: &Id, session_keys: Vec<u8>) -> Result<Block::Extrinsic, CreateSignedSetKeysExtrinsicError>;
+
+        /// Get the encoded session keys registered on chain for the next session for the given `id`.
+        fn next_session_keys(id: &Id) -> Option<Vec<u8>>;
+
+        /// Create the set_keys signing payload, to be signed outside of the node.
+        fn create_set_keys_signing_payload(id: &Id, session_keys: Vec<u8>) -> Result<SetKeysSigningPayload, CreateSignedSetKeysExtrinsicError>;
+
+        /// Create signed set_keys extrinsic from the unsigned extrinsic data and the signature
+        /// made outside of the node.
+        fn create_set_keys_extrinsic_with_signature(
+            id: &Id,
+            unsigned_extrinsic: Vec<u8>,
+            signature: Vec<u8>
+        ) -> Result<Block::Extrinsic, CreateSignedSetKeysExtrinsicError>;
     }
 }
@@ -2,8 +2,6 @@
 
 use rpc_validator_key_logic::Error as ValidatorKeyError;
 
-use super::api_error_code;
-
 /// The `get_validator_public_key` method error kinds.
 #[derive(Debug)]
 pub enum Error {
@@ -14,19 +12,7 @@ pub enum Error {
 impl From<Error> for jsonrpsee::core::Error {
     fn from(err: Error) -> Self {
         match err {
-            Error::KeyExtraction(err @ ValidatorKeyError::MissingValidatorKey) => {
-                rpc_error_response::data(
-                    api_error_code::MISSING_VALIDATOR_KEY,
-                    err.to_string(),
-                    rpc_validator_key_logic::error_data::ValidatorKeyNotAvailable,
-                )
-            }
-            Error::KeyExtraction(err @ ValidatorKeyError::ValidatorKeyExtraction) => {
-                rpc_error_response::simple(
-                    api_error_code::VALIDATOR_KEY_EXTRACTION,
-                    err.to_string(),
-                )
-            }
+            Error::KeyExtraction(err) => err.into(),
         }
     }
 }
@@ -44,7 +30,8 @@ mod tests {
             Error::KeyExtraction(ValidatorKeyError::ValidatorKeyExtraction).into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":600,\"message\":\"unable to extract own key\"}";
+        let expected_error_message =
+            "{\"code\":600,\"message\":\"unable to extract own key\",\"data\":{\"errorCode\":601}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -57,7 +44,7 @@ mod tests {
             Error::KeyExtraction(ValidatorKeyError::MissingValidatorKey).into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":500,\"message\":\"validator key not available\",\"data\":{\"validatorKeyNotAvailable\":true}}";
+        let expected_error_message = "{\"code\":500,\"message\":\"validator key not available\",\"data\":{\"errorCode\":501,\"validatorKeyNotAvailable\":true}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -0,0 +1,71 @@
+//! The `rotate_and_set_keys` method error.
+
+use rpc_error_response::registry;
+use sp_api::ApiError;
+
+use super::set_keys;
+
+/// The `rotate_and_set_keys` method error kinds.
+#[derive(Debug)]
+pub enum Error<TxPoolError: sc_transaction_pool_api::error::IntoPoolError> {
+    /// An error that can occur during doing a call into runtime api to generate the session keys.
+    KeysGeneration(ApiError),
+    /// An error that can occur during setting the generated session keys.
+    SetKeys(set_keys::Error<TxPoolError>),
+}
+
+impl<TxPoolError> From<Error<TxPoolError>> for jsonrpsee::core::Error
+where
+    TxPoolError: sc_transaction_pool_api::error::IntoPoolError,
+{
+    fn from(err: Error<TxPoolError>) -> Self {
+        match err {
+            Error::KeysGeneration(err) => {
+                rpc_error_response::coded(&registry::RUNTIME_API_CALL, err.to_string())
+            }
+            Error::SetKeys(err) => err.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use jsonrpsee::types::ErrorObject;
+    use rpc_validator_key_logic::Error as ValidatorKeyError;
+
+    use super::*;
+
+    #[test]
+    fn error_keys_generation() {
+        let error: jsonrpsee::core::Error =
+            Error::<sc_transaction_pool_api::error::Error>::KeysGeneration(ApiError::Application(
+                "test".into(),
+            ))
+            .into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message =
+            "{\"code\":300,\"message\":\"test\",\"data\":{\"errorCode\":301}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+
+    #[test]
+    fn error_set_keys() {
+        let error: jsonrpsee::core::Error =
+            Error::<sc_transaction_pool_api::error::Error>::SetKeys(
+                set_keys::Error::KeyExtraction(ValidatorKeyError::MissingValidatorKey),
+            )
+            .into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message = "{\"code\":500,\"message\":\"validator key not available\",\"data\":{\"errorCode\":501,\"validatorKeyNotAvailable\":true}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+}
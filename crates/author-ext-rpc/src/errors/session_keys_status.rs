@@ -0,0 +1,78 @@
+//! The `session_keys_status` method error.
+
+use rpc_error_response::registry;
+use rpc_validator_key_logic::Error as ValidatorKeyError;
+use sp_api::ApiError;
+
+/// The `session_keys_status` method error kinds.
+#[derive(Debug)]
+pub enum Error {
+    /// An error that can occur during validator key extraction.
+    KeyExtraction(ValidatorKeyError),
+    /// An error that can occur during doing a call into runtime api.
+    RuntimeApi(ApiError),
+    /// The session keys registered on chain could not be decoded.
+    SessionKeysDecoding,
+}
+
+impl From<Error> for jsonrpsee::core::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::KeyExtraction(err) => err.into(),
+            Error::RuntimeApi(err) => {
+                rpc_error_response::coded(&registry::RUNTIME_API_CALL, err.to_string())
+            }
+            Error::SessionKeysDecoding => rpc_error_response::coded(
+                &registry::RUNTIME_API_SESSION_KEYS_DECODING,
+                "Unable to decode the session keys registered on chain".to_owned(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use jsonrpsee::types::ErrorObject;
+
+    use super::*;
+
+    #[test]
+    fn error_key_extraction_missing_validator_key() {
+        let error: jsonrpsee::core::Error =
+            Error::KeyExtraction(ValidatorKeyError::MissingValidatorKey).into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message = "{\"code\":500,\"message\":\"validator key not available\",\"data\":{\"errorCode\":501,\"validatorKeyNotAvailable\":true}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+
+    #[test]
+    fn error_runtime_api() {
+        let error: jsonrpsee::core::Error =
+            Error::RuntimeApi(ApiError::Application("test".into())).into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message =
+            "{\"code\":300,\"message\":\"test\",\"data\":{\"errorCode\":301}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+
+    #[test]
+    fn error_session_keys_decoding() {
+        let error: jsonrpsee::core::Error = Error::SessionKeysDecoding.into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message = "{\"code\":300,\"message\":\"Unable to decode the session keys registered on chain\",\"data\":{\"errorCode\":302}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+}
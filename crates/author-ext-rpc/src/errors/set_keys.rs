@@ -1,11 +1,11 @@
 //! The `set_keys` method error.
 
 use author_ext_api::CreateSignedSetKeysExtrinsicError;
+use rpc_error_response::registry::{self, ErrorCode};
 use rpc_validator_key_logic::Error as ValidatorKeyError;
 use sp_api::ApiError;
 use sp_runtime::transaction_validity::InvalidTransaction;
 
-use super::api_error_code;
 use crate::error_data::{self, AuthorExtTxErrorDetails};
 
 /// The `set_keys` method error kinds.
@@ -27,37 +27,37 @@ where
 {
     fn from(err: Error<TxPoolError>) -> Self {
         match err {
-            Error::KeyExtraction(err @ ValidatorKeyError::MissingValidatorKey) => {
-                rpc_error_response::data(
-                    api_error_code::MISSING_VALIDATOR_KEY,
-                    err.to_string(),
-                    rpc_validator_key_logic::error_data::ValidatorKeyNotAvailable,
-                )
-            }
-            Error::KeyExtraction(err @ ValidatorKeyError::ValidatorKeyExtraction) => {
-                rpc_error_response::simple(
-                    api_error_code::VALIDATOR_KEY_EXTRACTION,
-                    err.to_string(),
-                )
-            }
+            Error::KeyExtraction(err) => err.into(),
             Error::RuntimeApi(err) => {
-                rpc_error_response::simple(api_error_code::RUNTIME_API, err.to_string())
+                rpc_error_response::coded(&registry::RUNTIME_API_CALL, err.to_string())
             }
             Error::ExtrinsicCreation(CreateSignedSetKeysExtrinsicError::SessionKeysDecoding(
                 ref err_details,
-            )) => rpc_error_response::simple(
-                api_error_code::RUNTIME_API,
+            )) => rpc_error_response::coded(
+                &registry::RUNTIME_API_SESSION_KEYS_DECODING,
                 format!("Error during session keys decoding: {err_details}"),
             ),
             Error::ExtrinsicCreation(
                 CreateSignedSetKeysExtrinsicError::SignedExtrinsicCreation,
-            ) => rpc_error_response::simple(
-                api_error_code::RUNTIME_API,
+            ) => rpc_error_response::coded(
+                &registry::RUNTIME_API_SIGNED_EXTRINSIC_CREATION,
                 "Error during the creation of the signed set keys extrinsic".to_owned(),
             ),
+            Error::ExtrinsicCreation(
+                CreateSignedSetKeysExtrinsicError::InvalidUnsignedExtrinsic(ref err_details),
+            ) => rpc_error_response::coded(
+                &registry::RUNTIME_API_INVALID_UNSIGNED_EXTRINSIC,
+                format!("Invalid unsigned set keys extrinsic: {err_details}"),
+            ),
+            Error::ExtrinsicCreation(CreateSignedSetKeysExtrinsicError::InvalidSignature) => {
+                rpc_error_response::coded(
+                    &registry::RUNTIME_API_INVALID_SIGNATURE,
+                    "Invalid set keys extrinsic signature".to_owned(),
+                )
+            }
             Error::AuthorExtTx(err) => {
-                let (message, data) = map_txpool_error(err);
-                rpc_error_response::raw(api_error_code::TRANSACTION, message, data)
+                let (error_code, message, data) = map_txpool_error(err);
+                rpc_error_response::typed(error_code, message, data)
             }
         }
     }
@@ -66,25 +66,39 @@ where
 /// Convert a transaction pool error into a human-readable.
 fn map_txpool_error<T: sc_transaction_pool_api::error::IntoPoolError>(
     err: T,
-) -> (String, Option<error_data::AuthorExtTxErrorDetails>) {
+) -> (
+    &'static ErrorCode,
+    String,
+    Option<error_data::AuthorExtTxErrorDetails>,
+) {
     let err = match err.into_pool_error() {
         Ok(err) => err,
         Err(err) => {
             // This is not a Transaction Pool API Error, but it may be a kind of wrapper type
             // error (i.e. Transaction Pool Error, without the API bit).
-            return (format!("Transaction failed: {err}"), None);
+            return (
+                &registry::TX_FAILED,
+                format!("Transaction failed: {err}"),
+                None,
+            );
         }
     };
 
     use sc_transaction_pool_api::error::Error;
-    let (kind, message) = match err {
+    let (error_code, kind, message) = match err {
         // Provide some custom-tweaked error messages for a few select cases:
-        Error::InvalidTransaction(InvalidTransaction::Payment) => {
-            (error_data::AuthorExtTxErrorKind::NoFunds, "No funds")
-        }
+        Error::InvalidTransaction(InvalidTransaction::Payment) => (
+            &registry::TX_NO_FUNDS,
+            error_data::AuthorExtTxErrorKind::NoFunds,
+            "No funds",
+        ),
         // For the rest cases, fallback to the native error rendering.
         err => {
-            return (format!("Transaction failed: {err}"), None);
+            return (
+                &registry::TX_FAILED,
+                format!("Transaction failed: {err}"),
+                None,
+            );
         }
     };
 
@@ -96,7 +110,7 @@ fn map_txpool_error<T: sc_transaction_pool_api::error::IntoPoolError>(
 
     // Rewrite the error message for more human-readable errors while the frontend doesn't support
     // the custom data parsing.
-    (message.to_owned(), Some(data))
+    (error_code, message.to_owned(), Some(data))
 }
 
 #[cfg(test)]
@@ -115,7 +129,8 @@ mod tests {
             .into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":600,\"message\":\"unable to extract own key\"}";
+        let expected_error_message =
+            "{\"code\":600,\"message\":\"unable to extract own key\",\"data\":{\"errorCode\":601}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -131,7 +146,7 @@ mod tests {
             .into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":500,\"message\":\"validator key not available\",\"data\":{\"validatorKeyNotAvailable\":true}}";
+        let expected_error_message = "{\"code\":500,\"message\":\"validator key not available\",\"data\":{\"errorCode\":501,\"validatorKeyNotAvailable\":true}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -147,7 +162,8 @@ mod tests {
             .into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":300,\"message\":\"test\"}";
+        let expected_error_message =
+            "{\"code\":300,\"message\":\"test\",\"data\":{\"errorCode\":301}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -164,7 +180,7 @@ mod tests {
         let error: ErrorObject = error.into();
 
         let expected_error_message =
-            "{\"code\":300,\"message\":\"Error during session keys decoding: test\"}";
+            "{\"code\":300,\"message\":\"Error during session keys decoding: test\",\"data\":{\"errorCode\":302}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -181,7 +197,41 @@ mod tests {
         let error: ErrorObject = error.into();
 
         let expected_error_message =
-            "{\"code\":300,\"message\":\"Error during the creation of the signed set keys extrinsic\"}";
+            "{\"code\":300,\"message\":\"Error during the creation of the signed set keys extrinsic\",\"data\":{\"errorCode\":303}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+
+    #[test]
+    fn error_extrinsic_creation_invalid_unsigned_extrinsic() {
+        let error: jsonrpsee::core::Error =
+            Error::<sc_transaction_pool_api::error::Error>::ExtrinsicCreation(
+                CreateSignedSetKeysExtrinsicError::InvalidUnsignedExtrinsic("test".to_owned()),
+            )
+            .into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message =
+            "{\"code\":300,\"message\":\"Invalid unsigned set keys extrinsic: test\",\"data\":{\"errorCode\":304}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+
+    #[test]
+    fn error_extrinsic_creation_invalid_signature() {
+        let error: jsonrpsee::core::Error =
+            Error::<sc_transaction_pool_api::error::Error>::ExtrinsicCreation(
+                CreateSignedSetKeysExtrinsicError::InvalidSignature,
+            )
+            .into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message =
+            "{\"code\":300,\"message\":\"Invalid set keys extrinsic signature\",\"data\":{\"errorCode\":305}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -199,7 +249,7 @@ mod tests {
             .into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":400,\"message\":\"No funds\",\"data\":{\"kind\":\"NO_FUNDS\",\"message\":\"No funds\",\"innerError\":\"Invalid transaction validity: InvalidTransaction::Payment\"}}";
+        let expected_error_message = "{\"code\":400,\"message\":\"No funds\",\"data\":{\"errorCode\":405,\"kind\":\"NO_FUNDS\",\"message\":\"No funds\",\"innerError\":\"Invalid transaction validity: InvalidTransaction::Payment\"}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -215,7 +265,7 @@ mod tests {
             .into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":400,\"message\":\"Transaction failed: The pool is not accepting future transactions\"}";
+        let expected_error_message = "{\"code\":400,\"message\":\"Transaction failed: The pool is not accepting future transactions\",\"data\":{\"errorCode\":407}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
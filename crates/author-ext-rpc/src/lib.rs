@@ -6,7 +6,9 @@ use std::sync::Arc;
 use author_ext_api::AuthorExtApi;
 use bioauth_keys::traits::KeyExtractor as KeyExtractorT;
 use errors::{
-    get_validator_public_key::Error as GetValidatorPublicKeyError, set_keys::Error as SetKeysError,
+    get_validator_public_key::Error as GetValidatorPublicKeyError,
+    rotate_and_set_keys::Error as RotateAndSetKeysError,
+    session_keys_status::Error as SessionKeysStatusError, set_keys::Error as SetKeysError,
 };
 use jsonrpsee::{
     core::{async_trait, RpcResult},
@@ -14,13 +16,61 @@ use jsonrpsee::{
 };
 use rpc_deny_unsafe::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool as TransactionPoolT;
+use serde::{Deserialize, Serialize};
 use sp_api::{BlockT, Encode, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::Bytes;
+use sp_keystore::SyncCryptoStorePtr;
+use sp_session::SessionKeys;
 use tracing::*;
 
 mod error_data;
 mod errors;
+mod session_keys;
+
+#[cfg(test)]
+mod tests;
+
+/// The status of the session keys registered on chain for the validator, as used in the RPC.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionKeysStatus {
+    /// There are no session keys registered on chain for the validator.
+    NotRegistered,
+    /// There are session keys registered on chain for the validator.
+    Registered {
+        /// The individual keys registered on chain.
+        keys: Vec<SessionKeyStatus>,
+    },
+}
+
+/// The status of an individual session key registered on chain, as used in the RPC.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionKeyStatus {
+    /// The key type, as the four-letter key type id.
+    pub key_type: String,
+    /// The raw public key.
+    pub public_key: Bytes,
+    /// Whether the corresponding private key is present in the local keystore.
+    pub in_keystore: bool,
+}
+
+/// The data required to sign the `set_keys` extrinsic outside of the node, as used in the RPC.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetKeysSigningPayload {
+    /// The unsigned extrinsic data, to be submitted back along with the signature.
+    pub unsigned_extrinsic: Bytes,
+    /// The exact bytes to sign.
+    pub payload: Bytes,
+}
+
+impl From<author_ext_api::SetKeysSigningPayload> for SetKeysSigningPayload {
+    fn from(signing_payload: author_ext_api::SetKeysSigningPayload) -> Self {
+        Self {
+            unsigned_extrinsic: signing_payload.unsigned_extrinsic.into(),
+            payload: signing_payload.payload.into(),
+        }
+    }
+}
 
 /// The API exposed via JSON-RPC.
 #[rpc(server)]
@@ -29,6 +79,35 @@ pub trait AuthorExt<VPK> {
     #[method(name = "authorExt_setKeys")]
     async fn set_keys(&self, session_keys: Bytes) -> RpcResult<()>;
 
+    /// Get the `set_keys` signing payload for the provided session keys data, to be signed
+    /// outside of the node with the given validator key.
+    #[method(name = "authorExt_setKeysSigningPayload")]
+    async fn set_keys_signing_payload(
+        &self,
+        session_keys: Bytes,
+        public_key: VPK,
+    ) -> RpcResult<SetKeysSigningPayload>;
+
+    /// Set_keys with the unsigned extrinsic data and the signature made outside of the node
+    /// with the given validator key.
+    #[method(name = "authorExt_setKeysWithSignature")]
+    async fn set_keys_with_signature(
+        &self,
+        unsigned_extrinsic: Bytes,
+        signature: Bytes,
+        public_key: VPK,
+    ) -> RpcResult<()>;
+
+    /// Generate new session keys in the keystore and set them on chain.
+    ///
+    /// Returns the newly generated session keys data.
+    #[method(name = "authorExt_rotateAndSetKeys")]
+    async fn rotate_and_set_keys(&self) -> RpcResult<Bytes>;
+
+    /// Compare the session keys registered on chain for the validator against the local keystore.
+    #[method(name = "authorExt_sessionKeysStatus")]
+    async fn session_keys_status(&self) -> RpcResult<SessionKeysStatus>;
+
     /// Provide validator public key.
     #[method(name = "authorExt_getValidatorPublicKey")]
     async fn get_validator_public_key(&self) -> RpcResult<VPK>;
@@ -40,6 +119,8 @@ pub struct AuthorExt<ValidatorKeyExtractor, Client, Block, TransactionPool> {
     validator_key_extractor: ValidatorKeyExtractor,
     /// The substrate client, provides access to the runtime APIs.
     client: Arc<Client>,
+    /// The keystore that manages the keys of the node.
+    keystore: SyncCryptoStorePtr,
     /// The transaction pool to use.
     pool: Arc<TransactionPool>,
     /// Whether to deny unsafe calls or not.
@@ -55,12 +136,14 @@ impl<ValidatorKeyExtractor, Client, Block, TransactionPool>
     pub fn new(
         validator_key_extractor: ValidatorKeyExtractor,
         client: Arc<Client>,
+        keystore: SyncCryptoStorePtr,
         pool: Arc<TransactionPool>,
         deny_unsafe: DenyUnsafe,
     ) -> Self {
         Self {
             validator_key_extractor,
             client,
+            keystore,
             pool,
             deny_unsafe,
             phantom_types: PhantomData,
@@ -68,6 +151,49 @@ impl<ValidatorKeyExtractor, Client, Block, TransactionPool>
     }
 }
 
+impl<ValidatorKeyExtractor, Client, Block, TransactionPool>
+    AuthorExt<ValidatorKeyExtractor, Client, Block, TransactionPool>
+where
+    ValidatorKeyExtractor: KeyExtractorT,
+    ValidatorKeyExtractor::PublicKeyType: Encode + AsRef<[u8]>,
+    ValidatorKeyExtractor::Error: std::fmt::Debug,
+    Client: HeaderBackend<Block>,
+    Client: ProvideRuntimeApi<Block>,
+    Client::Api: AuthorExtApi<Block, ValidatorKeyExtractor::PublicKeyType>,
+    Block: BlockT,
+    TransactionPool: TransactionPoolT<Block = Block>,
+{
+    /// Create the signed `set_keys` extrinsic for the provided session keys and submit it.
+    async fn submit_set_keys(
+        &self,
+        session_keys: Vec<u8>,
+    ) -> Result<(), SetKeysError<TransactionPool::Error>> {
+        let validator_key =
+            rpc_validator_key_logic::validator_public_key(&self.validator_key_extractor)
+                .map_err(SetKeysError::KeyExtraction)?;
+
+        let at = self.client.info().best_hash;
+
+        let signed_set_keys_extrinsic = self
+            .client
+            .runtime_api()
+            .create_signed_set_keys_extrinsic(at, &validator_key, session_keys)
+            .map_err(SetKeysError::RuntimeApi)?
+            .map_err(SetKeysError::ExtrinsicCreation)?;
+
+        self.pool
+            .submit_and_watch(
+                &sp_api::BlockId::Hash(at),
+                sp_runtime::transaction_validity::TransactionSource::Local,
+                signed_set_keys_extrinsic,
+            )
+            .await
+            .map_err(SetKeysError::AuthorExtTx)?;
+
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl<ValidatorKeyExtractor, Client, Block, TransactionPool>
     AuthorExtServer<ValidatorKeyExtractor::PublicKeyType>
@@ -86,6 +212,7 @@ where
     Client: ProvideRuntimeApi<Block>,
     Client: Send + Sync + 'static,
     Client::Api: AuthorExtApi<Block, ValidatorKeyExtractor::PublicKeyType>,
+    Client::Api: SessionKeys<Block>,
     Block: BlockT,
     TransactionPool: TransactionPoolT<Block = Block>,
 {
@@ -94,19 +221,57 @@ where
 
         info!("Author extension - setting keys in progress");
 
+        self.submit_set_keys(session_keys.0).await?;
+
+        info!("Author extension - setting keys transaction complete");
+
+        Ok(())
+    }
+
+    async fn set_keys_signing_payload(
+        &self,
+        session_keys: Bytes,
+        public_key: ValidatorKeyExtractor::PublicKeyType,
+    ) -> RpcResult<SetKeysSigningPayload> {
         let errtype = |val: errors::set_keys::Error<TransactionPool::Error>| val;
 
-        let validator_key =
-            rpc_validator_key_logic::validator_public_key(&self.validator_key_extractor)
-                .map_err(SetKeysError::KeyExtraction)
-                .map_err(errtype)?;
+        let at = self.client.info().best_hash;
+
+        let signing_payload = self
+            .client
+            .runtime_api()
+            .create_set_keys_signing_payload(at, &public_key, session_keys.0)
+            .map_err(SetKeysError::RuntimeApi)
+            .map_err(errtype)?
+            .map_err(SetKeysError::ExtrinsicCreation)
+            .map_err(errtype)?;
+
+        Ok(signing_payload.into())
+    }
+
+    async fn set_keys_with_signature(
+        &self,
+        unsigned_extrinsic: Bytes,
+        signature: Bytes,
+        public_key: ValidatorKeyExtractor::PublicKeyType,
+    ) -> RpcResult<()> {
+        self.deny_unsafe.check_if_safe()?;
+
+        info!("Author extension - setting keys with signature in progress");
+
+        let errtype = |val: errors::set_keys::Error<TransactionPool::Error>| val;
 
         let at = self.client.info().best_hash;
 
         let signed_set_keys_extrinsic = self
             .client
             .runtime_api()
-            .create_signed_set_keys_extrinsic(at, &validator_key, session_keys.0)
+            .create_set_keys_extrinsic_with_signature(
+                at,
+                &public_key,
+                unsigned_extrinsic.0,
+                signature.0,
+            )
             .map_err(SetKeysError::RuntimeApi)
             .map_err(errtype)?
             .map_err(SetKeysError::ExtrinsicCreation)
@@ -122,11 +287,55 @@ where
             .map_err(SetKeysError::AuthorExtTx)
             .map_err(errtype)?;
 
-        info!("Author extension - setting keys transaction complete");
+        info!("Author extension - setting keys with signature transaction complete");
 
         Ok(())
     }
 
+    async fn rotate_and_set_keys(&self) -> RpcResult<Bytes> {
+        self.deny_unsafe.check_if_safe()?;
+
+        info!("Author extension - rotating keys in progress");
+
+        let errtype = |val: RotateAndSetKeysError<TransactionPool::Error>| val;
+
+        let at = self.client.info().best_hash;
+
+        let mut runtime_api = self.client.runtime_api();
+
+        let session_keys = session_keys::generate(&mut runtime_api, self.keystore.clone(), at)
+            .map_err(RotateAndSetKeysError::KeysGeneration)
+            .map_err(errtype)?;
+
+        self.submit_set_keys(session_keys.clone())
+            .await
+            .map_err(RotateAndSetKeysError::SetKeys)
+            .map_err(errtype)?;
+
+        info!("Author extension - rotating keys transaction complete");
+
+        Ok(session_keys.into())
+    }
+
+    async fn session_keys_status(&self) -> RpcResult<SessionKeysStatus> {
+        self.deny_unsafe.check_if_safe()?;
+
+        let validator_key =
+            rpc_validator_key_logic::validator_public_key(&self.validator_key_extractor)
+                .map_err(SessionKeysStatusError::KeyExtraction)?;
+
+        let at = self.client.info().best_hash;
+
+        let status = session_keys::status(
+            &*self.client.runtime_api(),
+            &*self.keystore,
+            at,
+            &validator_key,
+        )?;
+
+        Ok(status)
+    }
+
     async fn get_validator_public_key(&self) -> RpcResult<ValidatorKeyExtractor::PublicKeyType> {
         let validator_public_key =
             rpc_validator_key_logic::validator_public_key(&self.validator_key_extractor)
@@ -0,0 +1,67 @@
+//! The session keys logic, as served by the RPC.
+
+use author_ext_api::AuthorExtApi;
+use sp_api::{ApiError, ApiExt, BlockT, Encode};
+use sp_keystore::{KeystoreExt, SyncCryptoStore, SyncCryptoStorePtr};
+use sp_session::SessionKeys;
+
+use crate::{errors::session_keys_status::Error, SessionKeyStatus, SessionKeysStatus};
+
+/// Generate new session keys in the given keystore at the given block.
+///
+/// The runtime puts the generated keys into the keystore registered as the runtime api
+/// extension, so the keystore has to be registered before the call.
+pub fn generate<Api, Block>(
+    runtime_api: &mut Api,
+    keystore: SyncCryptoStorePtr,
+    at: Block::Hash,
+) -> Result<Vec<u8>, ApiError>
+where
+    Api: SessionKeys<Block> + ApiExt<Block>,
+    Block: BlockT,
+{
+    runtime_api.register_extension(KeystoreExt(keystore));
+    runtime_api.generate_session_keys(at, None)
+}
+
+/// Compare the session keys registered on chain for the given validator at the given block
+/// against the keystore.
+pub fn status<Api, Block, ValidatorPublicKey>(
+    runtime_api: &Api,
+    keystore: &dyn SyncCryptoStore,
+    at: Block::Hash,
+    validator_key: &ValidatorPublicKey,
+) -> Result<SessionKeysStatus, Error>
+where
+    Api: AuthorExtApi<Block, ValidatorPublicKey> + SessionKeys<Block>,
+    Block: BlockT,
+    ValidatorPublicKey: Encode,
+{
+    let next_session_keys = runtime_api
+        .next_session_keys(at, validator_key)
+        .map_err(Error::RuntimeApi)?;
+
+    let next_session_keys = match next_session_keys {
+        Some(next_session_keys) => next_session_keys,
+        None => return Ok(SessionKeysStatus::NotRegistered),
+    };
+
+    let raw_public_keys = runtime_api
+        .decode_session_keys(at, next_session_keys)
+        .map_err(Error::RuntimeApi)?
+        .ok_or(Error::SessionKeysDecoding)?;
+
+    let keys = raw_public_keys
+        .into_iter()
+        .map(|(public_key, key_type)| {
+            let in_keystore = keystore.has_keys(&[(public_key.clone(), key_type)]);
+            SessionKeyStatus {
+                key_type: String::from_utf8_lossy(&key_type.0).into_owned(),
+                public_key: public_key.into(),
+                in_keystore,
+            }
+        })
+        .collect();
+
+    Ok(SessionKeysStatus::Registered { keys })
+}
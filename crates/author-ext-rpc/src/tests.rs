@@ -0,0 +1,122 @@
+//! The tests for the RPC logic, against a mock runtime API.
+
+use sp_core::crypto::KeyTypeId;
+use sp_keystore::{testing::KeyStore, SyncCryptoStore};
+use sp_runtime::{generic, traits::BlakeTwo256, OpaqueExtrinsic};
+
+use super::*;
+
+/// The block type used in tests.
+type Block = generic::Block<generic::Header<u32, BlakeTwo256>, OpaqueExtrinsic>;
+
+/// The validator public key type used in tests.
+type ValidatorPublicKey = u64;
+
+/// The key type used in tests.
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"test");
+
+/// The mock runtime API, serving the session keys it is constructed with.
+#[derive(Default)]
+struct MockRuntimeApi {
+    /// The session keys registered on chain, by the validator public key.
+    next_session_keys: Vec<(ValidatorPublicKey, Vec<u8>)>,
+    /// The raw public keys the session keys decode into, if the session keys are decodable.
+    decoded_session_keys: Option<Vec<(Vec<u8>, KeyTypeId)>>,
+}
+
+sp_api::mock_impl_runtime_apis! {
+    impl AuthorExtApi<Block, ValidatorPublicKey> for MockRuntimeApi {
+        fn next_session_keys(&self, id: &ValidatorPublicKey) -> Option<Vec<u8>> {
+            self.next_session_keys
+                .iter()
+                .find(|(validator_key, _)| validator_key == id)
+                .map(|(_, session_keys)| session_keys.clone())
+        }
+    }
+
+    impl SessionKeys<Block> for MockRuntimeApi {
+        fn decode_session_keys(&self, _encoded: Vec<u8>) -> Option<Vec<(Vec<u8>, KeyTypeId)>> {
+            self.decoded_session_keys.clone()
+        }
+    }
+}
+
+/// The block hash to query the state at in tests.
+fn at() -> <Block as BlockT>::Hash {
+    Default::default()
+}
+
+#[test]
+fn session_keys_status_not_registered() {
+    let runtime_api = MockRuntimeApi::default();
+    let keystore = KeyStore::new();
+
+    let status = session_keys::status(&runtime_api, &keystore, at(), &1).unwrap();
+
+    assert_eq!(status, SessionKeysStatus::NotRegistered);
+    assert_eq!(serde_json::to_string(&status).unwrap(), "\"NotRegistered\"");
+}
+
+#[test]
+fn session_keys_status_registered() {
+    let keystore = KeyStore::new();
+    let local_public_key = SyncCryptoStore::sr25519_generate_new(&keystore, KEY_TYPE, None)
+        .unwrap()
+        .0
+        .to_vec();
+    let foreign_public_key = vec![1; 32];
+
+    let runtime_api = MockRuntimeApi {
+        next_session_keys: vec![(1, vec![0])],
+        decoded_session_keys: Some(vec![
+            (local_public_key.clone(), KEY_TYPE),
+            (foreign_public_key.clone(), KEY_TYPE),
+        ]),
+    };
+
+    let status = session_keys::status(&runtime_api, &keystore, at(), &1).unwrap();
+
+    assert_eq!(
+        status,
+        SessionKeysStatus::Registered {
+            keys: vec![
+                SessionKeyStatus {
+                    key_type: "test".to_owned(),
+                    public_key: local_public_key.into(),
+                    in_keystore: true,
+                },
+                SessionKeyStatus {
+                    key_type: "test".to_owned(),
+                    public_key: foreign_public_key.into(),
+                    in_keystore: false,
+                },
+            ]
+        }
+    );
+}
+
+#[test]
+fn session_keys_status_of_other_validator() {
+    let runtime_api = MockRuntimeApi {
+        next_session_keys: vec![(2, vec![0])],
+        decoded_session_keys: Some(vec![]),
+    };
+    let keystore = KeyStore::new();
+
+    let status = session_keys::status(&runtime_api, &keystore, at(), &1).unwrap();
+
+    assert_eq!(status, SessionKeysStatus::NotRegistered);
+}
+
+#[test]
+fn session_keys_status_undecodable() {
+    let runtime_api = MockRuntimeApi {
+        next_session_keys: vec![(1, vec![0])],
+        decoded_session_keys: None,
+    };
+    let keystore = KeyStore::new();
+
+    let error = session_keys::status(&runtime_api, &keystore, at(), &1).unwrap_err();
+
+    assert!(matches!(error, SessionKeysStatusError::SessionKeysDecoding));
+}
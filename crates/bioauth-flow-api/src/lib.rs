@@ -7,6 +7,7 @@
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
+use sp_runtime::Perbill;
 use sp_std::prelude::*;
 
 /// The bioauth status.
@@ -21,9 +22,76 @@ pub enum BioauthStatus<Timestamp> {
     },
 }
 
+/// An active authentication.
+#[derive(Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+pub struct ActiveAuthentication<Id, Timestamp> {
+    /// The id of the authenticated validator.
+    pub id: Id,
+    /// The timestamp when the authentication will expire.
+    pub expires_at: Timestamp,
+}
+
+/// The verdict on whether the auth ticket would be accepted by the chain.
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, TypeInfo)]
+pub enum AuthTicketVerdict<Id> {
+    /// The auth ticket is valid and would be accepted.
+    Valid {
+        /// The id of the validator the auth ticket is issued for.
+        id: Id,
+    },
+    /// The robonode signature of the auth ticket could not be validated.
+    UnableToValidateSignature,
+    /// The robonode signature of the auth ticket is invalid.
+    SignatureInvalid,
+    /// The auth ticket could not be parsed.
+    UnableToParse,
+    /// The nonce of the auth ticket has already been used.
+    NonceAlreadyUsed,
+    /// The validator of the auth ticket is already authenticated.
+    AlreadyAuthenticated,
+    /// The validator of the auth ticket does not have the balance required to authenticate.
+    InsufficientBalance,
+    /// The validator of the auth ticket is banned from authenticating.
+    Banned,
+    /// The authentication is rejected for some other reason.
+    Rejected,
+}
+
+/// The sanction applied for an offence.
+#[derive(Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+pub enum Sanction<Timestamp> {
+    /// The offence was only noted.
+    Warning,
+    /// The validator was disabled for the rest of the session.
+    Disable,
+    /// The validator was deauthenticated.
+    Deauthenticate,
+    /// The validator was deauthenticated and banned from authenticating again.
+    Ban {
+        /// The timestamp until which the validator is banned.
+        until: Timestamp,
+    },
+}
+
+/// An offence committed by the validator.
+#[derive(Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+pub struct OffenceRecord<Timestamp> {
+    /// The kind of the offence, if known.
+    pub kind: Option<[u8; 16]>,
+    /// The index of the session the offence was committed at.
+    pub session_index: u32,
+    /// The encoded time slot of the offence, empty if unknown.
+    pub time_slot: Vec<u8>,
+    /// The slash fraction of the offence.
+    pub slash_fraction: Perbill,
+    /// The sanction applied for the offence.
+    pub sanction: Sanction<Timestamp>,
+}
+
 sp_api::decl_runtime_apis! {
     /// Runtime API for the bioauth flow.
-    pub trait BioauthFlowApi<Id: Encode, Timestamp: Decode> {
+    #[api_version(4)]
+    pub trait BioauthFlowApi<Id: Encode + Decode, Timestamp: Decode> {
         /// Determine the bioauth status for the given `id` at the current block.
         ///
         /// This call is intended for use in the bioauth flow, and the `id` passed is likely.
@@ -34,5 +102,28 @@ sp_api::decl_runtime_apis! {
             auth_ticket: Vec<u8>,
             auth_ticket_signature: Vec<u8>
         ) -> Block::Extrinsic;
+
+        /// Get all of the active authentications at the current block.
+        fn active_authentications() -> Vec<ActiveAuthentication<Id, Timestamp>>;
+
+        /// Get the amount of the consumed auth ticket nonces.
+        fn consumed_auth_ticket_nonces_count() -> u32;
+
+        /// Get the raw bytes of the robonode public key currently used to verify auth tickets.
+        fn robonode_public_key() -> Vec<u8>;
+
+        /// Get the duration after which the new authentications expire, for the public keys
+        /// that have not authenticated before.
+        fn authentications_expire_after() -> Timestamp;
+
+        /// Check whether the auth ticket would be accepted at the current block, without
+        /// submitting it.
+        fn validate_auth_ticket(
+            auth_ticket: Vec<u8>,
+            auth_ticket_signature: Vec<u8>
+        ) -> AuthTicketVerdict<Id>;
+
+        /// Get the latest offences committed by the given `id`, oldest first.
+        fn offence_log(id: &Id) -> Vec<OffenceRecord<Timestamp>>;
     }
 }
@@ -0,0 +1,147 @@
+//! The queries of the bioauth chain state, as served by the RPC.
+
+use bioauth_flow_api::BioauthFlowApi;
+use sp_api::{BlockT, Decode, Encode};
+use sp_core::Bytes;
+
+use crate::{
+    errors::{chain_state::Error, status::Error as StatusError},
+    ActiveAuthentication, AuthTicketVerdict, BioauthStatus,
+};
+
+/// The maximum amount of the public keys to get the statuses of in a single batch.
+pub const MAX_STATUS_BATCH_SIZE: usize = 256;
+
+/// Get the bioauth status of the given validator public key at the given block.
+pub fn status_of<Api, Block, ValidatorPublicKey, Timestamp>(
+    runtime_api: &Api,
+    at: Block::Hash,
+    public_key: &ValidatorPublicKey,
+) -> Result<BioauthStatus<Timestamp>, StatusError>
+where
+    Api: BioauthFlowApi<Block, ValidatorPublicKey, Timestamp>,
+    Block: BlockT,
+    ValidatorPublicKey: Encode + Decode,
+    Timestamp: Decode,
+{
+    let status = runtime_api
+        .bioauth_status(at, public_key)
+        .map_err(StatusError::RuntimeApi)?;
+
+    Ok(status.into())
+}
+
+/// Get the bioauth statuses of the given validator public keys at the given block.
+///
+/// The batch is limited to [`MAX_STATUS_BATCH_SIZE`] public keys.
+pub fn status_of_batch<Api, Block, ValidatorPublicKey, Timestamp>(
+    runtime_api: &Api,
+    at: Block::Hash,
+    public_keys: &[ValidatorPublicKey],
+) -> Result<Vec<BioauthStatus<Timestamp>>, StatusError>
+where
+    Api: BioauthFlowApi<Block, ValidatorPublicKey, Timestamp>,
+    Block: BlockT,
+    ValidatorPublicKey: Encode + Decode,
+    Timestamp: Decode,
+{
+    if public_keys.len() > MAX_STATUS_BATCH_SIZE {
+        return Err(StatusError::BatchTooLarge {
+            size: public_keys.len(),
+            max: MAX_STATUS_BATCH_SIZE,
+        });
+    }
+
+    public_keys
+        .iter()
+        .map(|public_key| status_of(runtime_api, at, public_key))
+        .collect()
+}
+
+/// Get all of the active authentications at the given block.
+pub fn active_authentications<Api, Block, ValidatorPublicKey, Timestamp>(
+    runtime_api: &Api,
+    at: Block::Hash,
+) -> Result<Vec<ActiveAuthentication<ValidatorPublicKey, Timestamp>>, Error>
+where
+    Api: BioauthFlowApi<Block, ValidatorPublicKey, Timestamp>,
+    Block: BlockT,
+    ValidatorPublicKey: Encode + Decode,
+    Timestamp: Decode,
+{
+    let active_authentications = runtime_api
+        .active_authentications(at)
+        .map_err(Error::RuntimeApi)?;
+
+    Ok(active_authentications.into_iter().map(Into::into).collect())
+}
+
+/// Get the amount of the consumed auth ticket nonces at the given block.
+pub fn consumed_nonces_count<Api, Block, ValidatorPublicKey, Timestamp>(
+    runtime_api: &Api,
+    at: Block::Hash,
+) -> Result<u32, Error>
+where
+    Api: BioauthFlowApi<Block, ValidatorPublicKey, Timestamp>,
+    Block: BlockT,
+    ValidatorPublicKey: Encode + Decode,
+    Timestamp: Decode,
+{
+    runtime_api
+        .consumed_auth_ticket_nonces_count(at)
+        .map_err(Error::RuntimeApi)
+}
+
+/// Get the robonode public key used to verify auth tickets at the given block.
+pub fn robonode_public_key<Api, Block, ValidatorPublicKey, Timestamp>(
+    runtime_api: &Api,
+    at: Block::Hash,
+) -> Result<Bytes, Error>
+where
+    Api: BioauthFlowApi<Block, ValidatorPublicKey, Timestamp>,
+    Block: BlockT,
+    ValidatorPublicKey: Encode + Decode,
+    Timestamp: Decode,
+{
+    let robonode_public_key = runtime_api
+        .robonode_public_key(at)
+        .map_err(Error::RuntimeApi)?;
+
+    Ok(robonode_public_key.into())
+}
+
+/// Get the duration after which the new authentications expire at the given block.
+pub fn authentications_expire_after<Api, Block, ValidatorPublicKey, Timestamp>(
+    runtime_api: &Api,
+    at: Block::Hash,
+) -> Result<Timestamp, Error>
+where
+    Api: BioauthFlowApi<Block, ValidatorPublicKey, Timestamp>,
+    Block: BlockT,
+    ValidatorPublicKey: Encode + Decode,
+    Timestamp: Decode,
+{
+    runtime_api
+        .authentications_expire_after(at)
+        .map_err(Error::RuntimeApi)
+}
+
+/// Check whether the auth ticket would be accepted at the given block, without submitting it.
+pub fn validate_auth_ticket<Api, Block, ValidatorPublicKey, Timestamp>(
+    runtime_api: &Api,
+    at: Block::Hash,
+    auth_ticket: Vec<u8>,
+    auth_ticket_signature: Vec<u8>,
+) -> Result<AuthTicketVerdict<ValidatorPublicKey>, Error>
+where
+    Api: BioauthFlowApi<Block, ValidatorPublicKey, Timestamp>,
+    Block: BlockT,
+    ValidatorPublicKey: Encode + Decode,
+    Timestamp: Decode,
+{
+    let verdict = runtime_api
+        .validate_auth_ticket(at, auth_ticket, auth_ticket_signature)
+        .map_err(Error::RuntimeApi)?;
+
+    Ok(verdict.into())
+}
@@ -41,4 +41,6 @@ pub enum BioauthTxErrorKind {
     NonceAlreadyUsed,
     /// The aactive authentication issued by this ticket is still on.
     AlreadyAuthenticated,
+    /// The authentication was rejected by the checks that run before it.
+    RejectedByBeforeAuthHook,
 }
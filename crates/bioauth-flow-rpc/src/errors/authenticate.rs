@@ -1,10 +1,11 @@
 //! The `authenticate` method error.
 
+use rpc_error_response::registry::{self, ErrorCode};
 use rpc_validator_key_logic::Error as ValidatorKeyError;
 use sp_api::ApiError;
 use sp_runtime::transaction_validity::InvalidTransaction;
 
-use super::{api_error_code, sign::Error as SignError};
+use super::{robonode, sign::Error as SignError};
 use crate::error_data::{self, BioauthTxErrorDetails};
 
 /// The `authenticate` method error kinds.
@@ -20,6 +21,9 @@ pub enum Error<TxPoolError: sc_transaction_pool_api::error::IntoPoolError> {
     RuntimeApi(ApiError),
     /// An error that can occur with transaction pool logic.
     BioauthTx(TxPoolError),
+    /// The transaction has been accepted by the transaction pool, but did not make it into
+    /// a block.
+    BioauthTxNotIncluded(&'static str),
 }
 
 impl<TxPoolError> From<Error<TxPoolError>> for jsonrpsee::core::Error
@@ -28,39 +32,20 @@ where
 {
     fn from(err: Error<TxPoolError>) -> Self {
         match err {
-            Error::KeyExtraction(err @ ValidatorKeyError::MissingValidatorKey) => {
-                rpc_error_response::data(
-                    api_error_code::MISSING_VALIDATOR_KEY,
-                    err.to_string(),
-                    rpc_validator_key_logic::error_data::ValidatorKeyNotAvailable,
-                )
-            }
-            Error::KeyExtraction(err @ ValidatorKeyError::ValidatorKeyExtraction) => {
-                rpc_error_response::simple(
-                    api_error_code::VALIDATOR_KEY_EXTRACTION,
-                    err.to_string(),
-                )
-            }
-            Error::Sign(err) => rpc_error_response::simple(api_error_code::SIGN, err.to_string()),
-            Error::Robonode(
-                err @ robonode_client::Error::Call(
-                    robonode_client::AuthenticateError::FaceScanRejected,
-                ),
-            ) => rpc_error_response::data(
-                api_error_code::ROBONODE,
-                err.to_string(),
-                error_data::ShouldRetry,
-            ),
-            Error::Robonode(err) => {
-                rpc_error_response::simple(api_error_code::ROBONODE, err.to_string())
-            }
+            Error::KeyExtraction(err) => err.into(),
+            Error::Sign(err) => rpc_error_response::coded(&registry::SIGN_FAILED, err.to_string()),
+            Error::Robonode(err) => robonode::into_rpc_error(err),
             Error::RuntimeApi(err) => {
-                rpc_error_response::simple(api_error_code::RUNTIME_API, err.to_string())
+                rpc_error_response::coded(&registry::RUNTIME_API_CALL, err.to_string())
             }
             Error::BioauthTx(err) => {
-                let (message, data) = map_txpool_error(err);
-                rpc_error_response::raw(api_error_code::TRANSACTION, message, data)
+                let (error_code, message, data) = map_txpool_error(err);
+                rpc_error_response::typed(error_code, message, data)
             }
+            Error::BioauthTxNotIncluded(reason) => rpc_error_response::coded(
+                &registry::TX_NOT_INCLUDED,
+                format!("authenticate transaction was not included: {reason}"),
+            ),
         }
     }
 }
@@ -68,20 +53,29 @@ where
 /// Convert a transaction pool error into a human-readable.
 fn map_txpool_error<T: sc_transaction_pool_api::error::IntoPoolError>(
     err: T,
-) -> (String, Option<error_data::BioauthTxErrorDetails>) {
+) -> (
+    &'static ErrorCode,
+    String,
+    Option<error_data::BioauthTxErrorDetails>,
+) {
     let err = match err.into_pool_error() {
         Ok(err) => err,
         Err(err) => {
             // This is not a Transaction Pool API Error, but it may be a kind of wrapper type
             // error (i.e. Transaction Pool Error, without the API bit).
-            return (format!("Transaction failed: {err}"), None);
+            return (
+                &registry::TX_FAILED,
+                format!("Transaction failed: {err}"),
+                None,
+            );
         }
     };
 
     use sc_transaction_pool_api::error::Error;
-    let (kind, message) = match err {
+    let (error_code, kind, message) = match err {
         // Provide some custom-tweaked error messages for a few select cases:
         Error::InvalidTransaction(InvalidTransaction::BadProof) => (
+            &registry::TX_AUTH_TICKET_SIGNATURE_INVALID,
             error_data::BioauthTxErrorKind::AuthTicketSignatureInvalid,
             "Invalid auth ticket signature",
         ),
@@ -91,21 +85,39 @@ fn map_txpool_error<T: sc_transaction_pool_api::error::IntoPoolError>(
                     as u8) =>
         {
             (
+                &registry::TX_UNABLE_TO_PARSE_AUTH_TICKET,
                 error_data::BioauthTxErrorKind::UnableToParseAuthTicket,
                 "Unable to parse a validly signed auth ticket",
             )
         }
+        Error::InvalidTransaction(InvalidTransaction::Custom(custom_code))
+            if custom_code
+                == (pallet_bioauth::CustomInvalidTransactionCodes::RejectedByBeforeAuthHook
+                    as u8) =>
+        {
+            (
+                &registry::TX_REJECTED_BY_BEFORE_AUTH_HOOK,
+                error_data::BioauthTxErrorKind::RejectedByBeforeAuthHook,
+                "The authentication was rejected, e.g. due to the insufficient balance or a ban",
+            )
+        }
         Error::InvalidTransaction(InvalidTransaction::Stale) => (
+            &registry::TX_NONCE_ALREADY_USED,
             error_data::BioauthTxErrorKind::NonceAlreadyUsed,
             "The auth ticket you provided has already been used",
         ),
         Error::InvalidTransaction(InvalidTransaction::Future) => (
+            &registry::TX_ALREADY_AUTHENTICATED,
             error_data::BioauthTxErrorKind::AlreadyAuthenticated,
             "Active authentication exists currently, and you can't authenticate again yet",
         ),
         // For the rest cases, fallback to simple error rendering.
         err => {
-            return (format!("Transaction failed: {err}"), None);
+            return (
+                &registry::TX_FAILED,
+                format!("Transaction failed: {err}"),
+                None,
+            );
         }
     };
 
@@ -117,7 +129,7 @@ fn map_txpool_error<T: sc_transaction_pool_api::error::IntoPoolError>(
 
     // Rewrite the error message for more human-readable errors while the frontend doesn't support
     // the custom data parsing.
-    (message.to_owned(), Some(data))
+    (error_code, message.to_owned(), Some(data))
 }
 
 #[cfg(test)]
@@ -136,7 +148,8 @@ mod tests {
             .into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":600,\"message\":\"unable to extract own key\"}";
+        let expected_error_message =
+            "{\"code\":600,\"message\":\"unable to extract own key\",\"data\":{\"errorCode\":601}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -152,7 +165,7 @@ mod tests {
             .into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":500,\"message\":\"validator key not available\",\"data\":{\"validatorKeyNotAvailable\":true}}";
+        let expected_error_message = "{\"code\":500,\"message\":\"validator key not available\",\"data\":{\"errorCode\":501,\"validatorKeyNotAvailable\":true}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -165,7 +178,8 @@ mod tests {
             Error::<sc_transaction_pool_api::error::Error>::Sign(SignError::SigningFailed).into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":100,\"message\":\"signing failed\"}";
+        let expected_error_message =
+            "{\"code\":100,\"message\":\"signing failed\",\"data\":{\"errorCode\":101}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -182,7 +196,7 @@ mod tests {
         let error: ErrorObject = error.into();
 
         let expected_error_message =
-            "{\"code\":200,\"message\":\"server error: face scan rejected\",\"data\":{\"shouldRetry\":true}}";
+            "{\"code\":200,\"message\":\"server error: face scan rejected\",\"data\":{\"errorCode\":204,\"shouldRetry\":true}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -199,7 +213,7 @@ mod tests {
         let error: ErrorObject = error.into();
 
         let expected_error_message =
-            "{\"code\":200,\"message\":\"server error: unknown error: test\"}";
+            "{\"code\":200,\"message\":\"server error: unknown error: test\",\"data\":{\"errorCode\":209}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -215,7 +229,8 @@ mod tests {
             .into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":300,\"message\":\"test\"}";
+        let expected_error_message =
+            "{\"code\":300,\"message\":\"test\",\"data\":{\"errorCode\":301}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -233,7 +248,7 @@ mod tests {
             .into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":400,\"message\":\"Invalid auth ticket signature\",\"data\":{\"kind\":\"AUTH_TICKET_SIGNATURE_INVALID\",\"message\":\"Invalid auth ticket signature\",\"innerError\":\"Invalid transaction validity: InvalidTransaction::BadProof\"}}";
+        let expected_error_message = "{\"code\":400,\"message\":\"Invalid auth ticket signature\",\"data\":{\"errorCode\":401,\"kind\":\"AUTH_TICKET_SIGNATURE_INVALID\",\"message\":\"Invalid auth ticket signature\",\"innerError\":\"Invalid transaction validity: InvalidTransaction::BadProof\"}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -254,7 +269,28 @@ mod tests {
             .into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":400,\"message\":\"Unable to parse a validly signed auth ticket\",\"data\":{\"kind\":\"UNABLE_TO_PARSE_AUTH_TICKET\",\"message\":\"Unable to parse a validly signed auth ticket\",\"innerError\":\"Invalid transaction validity: InvalidTransaction::Custom(116)\"}}";
+        let expected_error_message = "{\"code\":400,\"message\":\"Unable to parse a validly signed auth ticket\",\"data\":{\"errorCode\":402,\"kind\":\"UNABLE_TO_PARSE_AUTH_TICKET\",\"message\":\"Unable to parse a validly signed auth ticket\",\"innerError\":\"Invalid transaction validity: InvalidTransaction::Custom(116)\"}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+
+    #[test]
+    fn error_bioauth_tx_rejected_by_before_auth_hook() {
+        let error: jsonrpsee::core::Error =
+            Error::<sc_transaction_pool_api::error::Error>::BioauthTx(
+                sc_transaction_pool_api::error::Error::InvalidTransaction(
+                    InvalidTransaction::Custom(
+                        pallet_bioauth::CustomInvalidTransactionCodes::RejectedByBeforeAuthHook
+                            as u8,
+                    ),
+                ),
+            )
+            .into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message = "{\"code\":400,\"message\":\"The authentication was rejected, e.g. due to the insufficient balance or a ban\",\"data\":{\"errorCode\":408,\"kind\":\"REJECTED_BY_BEFORE_AUTH_HOOK\",\"message\":\"The authentication was rejected, e.g. due to the insufficient balance or a ban\",\"innerError\":\"Invalid transaction validity: InvalidTransaction::Custom(104)\"}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -272,7 +308,7 @@ mod tests {
             .into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":400,\"message\":\"The auth ticket you provided has already been used\",\"data\":{\"kind\":\"NONCE_ALREADY_USED\",\"message\":\"The auth ticket you provided has already been used\",\"innerError\":\"Invalid transaction validity: InvalidTransaction::Stale\"}}";
+        let expected_error_message = "{\"code\":400,\"message\":\"The auth ticket you provided has already been used\",\"data\":{\"errorCode\":403,\"kind\":\"NONCE_ALREADY_USED\",\"message\":\"The auth ticket you provided has already been used\",\"innerError\":\"Invalid transaction validity: InvalidTransaction::Stale\"}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -290,7 +326,7 @@ mod tests {
             .into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":400,\"message\":\"Active authentication exists currently, and you can't authenticate again yet\",\"data\":{\"kind\":\"ALREADY_AUTHENTICATED\",\"message\":\"Active authentication exists currently, and you can't authenticate again yet\",\"innerError\":\"Invalid transaction validity: InvalidTransaction::Future\"}}";
+        let expected_error_message = "{\"code\":400,\"message\":\"Active authentication exists currently, and you can't authenticate again yet\",\"data\":{\"errorCode\":404,\"kind\":\"ALREADY_AUTHENTICATED\",\"message\":\"Active authentication exists currently, and you can't authenticate again yet\",\"innerError\":\"Invalid transaction validity: InvalidTransaction::Future\"}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -306,7 +342,23 @@ mod tests {
             .into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":400,\"message\":\"Transaction failed: The pool is not accepting future transactions\"}";
+        let expected_error_message = "{\"code\":400,\"message\":\"Transaction failed: The pool is not accepting future transactions\",\"data\":{\"errorCode\":407}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+
+    #[test]
+    fn error_bioauth_tx_not_included() {
+        let error: jsonrpsee::core::Error =
+            Error::<sc_transaction_pool_api::error::Error>::BioauthTxNotIncluded(
+                "the transaction was dropped",
+            )
+            .into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message = "{\"code\":400,\"message\":\"authenticate transaction was not included: the transaction was dropped\",\"data\":{\"errorCode\":406}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -0,0 +1,44 @@
+//! The error of the methods querying the bioauth chain state.
+
+use rpc_error_response::registry;
+use sp_api::ApiError;
+
+/// The chain state querying methods error kinds.
+#[derive(Debug)]
+pub enum Error {
+    /// An error that can occur during doing a call into runtime api.
+    RuntimeApi(ApiError),
+}
+
+impl From<Error> for jsonrpsee::core::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::RuntimeApi(err) => rpc_error_response::coded(
+                &registry::RUNTIME_API_CALL,
+                format!("unable to get bioauth state from the runtime: {err}"),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use jsonrpsee::types::ErrorObject;
+
+    use super::*;
+
+    #[test]
+    fn error_runtime_api() {
+        let error: jsonrpsee::core::Error =
+            Error::RuntimeApi(ApiError::Application("test".into())).into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message =
+            "{\"code\":300,\"message\":\"unable to get bioauth state from the runtime: test\",\"data\":{\"errorCode\":301}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+}
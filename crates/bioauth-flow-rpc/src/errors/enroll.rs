@@ -1,9 +1,9 @@
 //! The `enroll` method error.
 
+use rpc_error_response::registry;
 use rpc_validator_key_logic::Error as ValidatorKeyError;
 
-use super::{api_error_code, sign::Error as SignError};
-use crate::error_data;
+use super::{robonode, sign::Error as SignError};
 
 /// The `enroll` method error kinds.
 #[derive(Debug)]
@@ -19,30 +19,9 @@ pub enum Error {
 impl From<Error> for jsonrpsee::core::Error {
     fn from(err: Error) -> Self {
         match err {
-            Error::KeyExtraction(err @ ValidatorKeyError::MissingValidatorKey) => {
-                rpc_error_response::data(
-                    api_error_code::MISSING_VALIDATOR_KEY,
-                    err.to_string(),
-                    rpc_validator_key_logic::error_data::ValidatorKeyNotAvailable,
-                )
-            }
-            Error::KeyExtraction(err @ ValidatorKeyError::ValidatorKeyExtraction) => {
-                rpc_error_response::simple(
-                    api_error_code::VALIDATOR_KEY_EXTRACTION,
-                    err.to_string(),
-                )
-            }
-            Error::Robonode(
-                err @ robonode_client::Error::Call(robonode_client::EnrollError::FaceScanRejected),
-            ) => rpc_error_response::data(
-                api_error_code::ROBONODE,
-                err.to_string(),
-                error_data::ShouldRetry,
-            ),
-            Error::Robonode(err) => {
-                rpc_error_response::simple(api_error_code::ROBONODE, err.to_string())
-            }
-            Error::Sign(err) => rpc_error_response::simple(api_error_code::SIGN, err.to_string()),
+            Error::KeyExtraction(err) => err.into(),
+            Error::Robonode(err) => robonode::into_rpc_error(err),
+            Error::Sign(err) => rpc_error_response::coded(&registry::SIGN_FAILED, err.to_string()),
         }
     }
 }
@@ -60,7 +39,8 @@ mod tests {
             Error::KeyExtraction(ValidatorKeyError::ValidatorKeyExtraction).into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":600,\"message\":\"unable to extract own key\"}";
+        let expected_error_message =
+            "{\"code\":600,\"message\":\"unable to extract own key\",\"data\":{\"errorCode\":601}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -73,7 +53,7 @@ mod tests {
             Error::KeyExtraction(ValidatorKeyError::MissingValidatorKey).into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":500,\"message\":\"validator key not available\",\"data\":{\"validatorKeyNotAvailable\":true}}";
+        let expected_error_message = "{\"code\":500,\"message\":\"validator key not available\",\"data\":{\"errorCode\":501,\"validatorKeyNotAvailable\":true}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -89,7 +69,7 @@ mod tests {
         let error: ErrorObject = error.into();
 
         let expected_error_message =
-            "{\"code\":200,\"message\":\"server error: face scan rejected\",\"data\":{\"shouldRetry\":true}}";
+            "{\"code\":200,\"message\":\"server error: face scan rejected\",\"data\":{\"errorCode\":204,\"shouldRetry\":true}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -105,7 +85,7 @@ mod tests {
         let error: ErrorObject = error.into();
 
         let expected_error_message =
-            "{\"code\":200,\"message\":\"server error: unknown error: test\"}";
+            "{\"code\":200,\"message\":\"server error: unknown error: test\",\"data\":{\"errorCode\":209}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -117,7 +97,8 @@ mod tests {
         let error: jsonrpsee::core::Error = Error::Sign(SignError::SigningFailed).into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":100,\"message\":\"signing failed\"}";
+        let expected_error_message =
+            "{\"code\":100,\"message\":\"signing failed\",\"data\":{\"errorCode\":101}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
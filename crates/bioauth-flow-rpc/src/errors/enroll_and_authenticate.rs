@@ -0,0 +1,92 @@
+//! The `enroll_and_authenticate` method error.
+
+use rpc_error_response::registry;
+use rpc_validator_key_logic::Error as ValidatorKeyError;
+
+use super::{authenticate::Error as AuthenticateError, robonode, sign::Error as SignError};
+
+/// The `enroll_and_authenticate` method error kinds.
+#[derive(Debug)]
+pub enum Error<TxPoolError: sc_transaction_pool_api::error::IntoPoolError> {
+    /// An error that can occur during validator key extraction.
+    KeyExtraction(ValidatorKeyError),
+    /// An error that can occur during signing process.
+    Sign(SignError),
+    /// An error that can occur during doing a call into robonode.
+    Robonode(robonode_client::Error<robonode_client::EnrollAndAuthenticateError>),
+    /// An error that can occur during the authenticate transaction submission.
+    Authenticate(AuthenticateError<TxPoolError>),
+}
+
+impl<TxPoolError> From<Error<TxPoolError>> for jsonrpsee::core::Error
+where
+    TxPoolError: sc_transaction_pool_api::error::IntoPoolError,
+{
+    fn from(err: Error<TxPoolError>) -> Self {
+        match err {
+            Error::KeyExtraction(err) => err.into(),
+            Error::Sign(err) => rpc_error_response::coded(&registry::SIGN_FAILED, err.to_string()),
+            Error::Robonode(err) => robonode::into_rpc_error(err),
+            Error::Authenticate(err) => err.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use jsonrpsee::types::ErrorObject;
+
+    use super::*;
+
+    #[test]
+    fn error_robonode_face_scan_rejected() {
+        let error: jsonrpsee::core::Error =
+            Error::<sc_transaction_pool_api::error::Error>::Robonode(robonode_client::Error::Call(
+                robonode_client::EnrollAndAuthenticateError::FaceScanRejected,
+            ))
+            .into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message =
+            "{\"code\":200,\"message\":\"server error: face scan rejected\",\"data\":{\"errorCode\":204,\"shouldRetry\":true}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+
+    #[test]
+    fn error_robonode_person_already_enrolled() {
+        let error: jsonrpsee::core::Error =
+            Error::<sc_transaction_pool_api::error::Error>::Robonode(robonode_client::Error::Call(
+                robonode_client::EnrollAndAuthenticateError::PersonAlreadyEnrolled,
+            ))
+            .into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message =
+            "{\"code\":200,\"message\":\"server error: person already enrolled\",\"data\":{\"errorCode\":206}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+
+    #[test]
+    fn error_authenticate_tx_not_included() {
+        let error: jsonrpsee::core::Error =
+            Error::<sc_transaction_pool_api::error::Error>::Authenticate(
+                AuthenticateError::BioauthTxNotIncluded("the transaction was dropped"),
+            )
+            .into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message =
+            "{\"code\":400,\"message\":\"authenticate transaction was not included: the transaction was dropped\",\"data\":{\"errorCode\":406}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+}
@@ -1,6 +1,6 @@
 //! The `get_facetec_device_sdk_params` method error.
 
-use super::api_error_code;
+use super::robonode;
 
 /// The `get_facetec_device_sdk_params` method error kinds.
 #[derive(Debug)]
@@ -12,9 +12,7 @@ pub enum Error {
 impl From<Error> for jsonrpsee::core::Error {
     fn from(err: Error) -> Self {
         match err {
-            Error::Robonode(err) => {
-                rpc_error_response::simple(api_error_code::ROBONODE, err.to_string())
-            }
+            Error::Robonode(err) => robonode::into_rpc_error(err),
         }
     }
 }
@@ -35,7 +33,7 @@ mod tests {
         let error: ErrorObject = error.into();
 
         let expected_error_message =
-            "{\"code\":200,\"message\":\"server error: unknown error: test\"}";
+            "{\"code\":200,\"message\":\"server error: unknown error: test\",\"data\":{\"errorCode\":209}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -0,0 +1,39 @@
+//! The `list_keys` method error.
+
+use rpc_validator_key_logic::Error as ValidatorKeyError;
+
+/// The `list_keys` method error kinds.
+#[derive(Debug)]
+pub enum Error {
+    /// An error that can occur during validator keys extraction.
+    ValidatorKeyExtraction,
+}
+
+impl From<Error> for jsonrpsee::core::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::ValidatorKeyExtraction => ValidatorKeyError::ValidatorKeyExtraction.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use jsonrpsee::types::ErrorObject;
+
+    use super::*;
+
+    #[test]
+    fn error_validator_key_extraction() {
+        let error: jsonrpsee::core::Error = Error::ValidatorKeyExtraction.into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message =
+            "{\"code\":600,\"message\":\"unable to extract own key\",\"data\":{\"errorCode\":601}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! The robonode call errors.
+
+use rpc_error_response::registry::{self, ErrorCode};
+
+use crate::error_data;
+
+/// The robonode call error conditions that have the registered error codes.
+pub trait CallError: std::error::Error + 'static {
+    /// The registered error code of the error condition.
+    fn error_code(&self) -> &'static ErrorCode;
+}
+
+impl CallError for robonode_client::EnrollError {
+    fn error_code(&self) -> &'static ErrorCode {
+        match self {
+            Self::InvalidPublicKey => &registry::ROBONODE_INVALID_PUBLIC_KEY,
+            Self::InvalidLivenessData => &registry::ROBONODE_INVALID_LIVENESS_DATA,
+            Self::FaceScanRejected => &registry::ROBONODE_FACE_SCAN_REJECTED,
+            Self::PublicKeyAlreadyUsed => &registry::ROBONODE_PUBLIC_KEY_ALREADY_USED,
+            Self::PersonAlreadyEnrolled => &registry::ROBONODE_PERSON_ALREADY_ENROLLED,
+            Self::LogicInternal => &registry::ROBONODE_LOGIC_INTERNAL,
+            Self::UnknownCode(_) | Self::Unknown(_) => &registry::ROBONODE_UNKNOWN,
+        }
+    }
+}
+
+impl CallError for robonode_client::AuthenticateError {
+    fn error_code(&self) -> &'static ErrorCode {
+        match self {
+            Self::InvalidLivenessData => &registry::ROBONODE_INVALID_LIVENESS_DATA,
+            Self::PersonNotFound => &registry::ROBONODE_PERSON_NOT_FOUND,
+            Self::FaceScanRejected => &registry::ROBONODE_FACE_SCAN_REJECTED,
+            Self::SignatureInvalid => &registry::ROBONODE_SIGNATURE_INVALID,
+            Self::LogicInternal => &registry::ROBONODE_LOGIC_INTERNAL,
+            Self::UnknownCode(_) | Self::Unknown(_) => &registry::ROBONODE_UNKNOWN,
+        }
+    }
+}
+
+impl CallError for robonode_client::EnrollAndAuthenticateError {
+    fn error_code(&self) -> &'static ErrorCode {
+        match self {
+            Self::InvalidPublicKey => &registry::ROBONODE_INVALID_PUBLIC_KEY,
+            Self::InvalidLivenessData => &registry::ROBONODE_INVALID_LIVENESS_DATA,
+            Self::SignatureInvalid => &registry::ROBONODE_SIGNATURE_INVALID,
+            Self::FaceScanRejected => &registry::ROBONODE_FACE_SCAN_REJECTED,
+            Self::PublicKeyAlreadyUsed => &registry::ROBONODE_PUBLIC_KEY_ALREADY_USED,
+            Self::PersonAlreadyEnrolled => &registry::ROBONODE_PERSON_ALREADY_ENROLLED,
+            Self::LogicInternal => &registry::ROBONODE_LOGIC_INTERNAL,
+            Self::UnknownCode(_) | Self::Unknown(_) => &registry::ROBONODE_UNKNOWN,
+        }
+    }
+}
+
+impl CallError for robonode_client::GetFacetecSessionTokenError {
+    fn error_code(&self) -> &'static ErrorCode {
+        match self {
+            Self::Unknown(_) => &registry::ROBONODE_UNKNOWN,
+        }
+    }
+}
+
+impl CallError for robonode_client::GetFacetecDeviceSdkParamsError {
+    fn error_code(&self) -> &'static ErrorCode {
+        match self {
+            Self::Unknown(_) => &registry::ROBONODE_UNKNOWN,
+        }
+    }
+}
+
+/// Convert the robonode call error into the RPC error.
+///
+/// The face scan rejections additionally request a retry with a new liveness data.
+pub fn into_rpc_error<T: CallError>(err: robonode_client::Error<T>) -> jsonrpsee::core::Error {
+    let error_code = match err {
+        robonode_client::Error::Call(ref err) => err.error_code(),
+        robonode_client::Error::Reqwest(_) => &registry::ROBONODE_TRANSPORT,
+    };
+
+    if *error_code == registry::ROBONODE_FACE_SCAN_REJECTED {
+        return rpc_error_response::typed(error_code, err.to_string(), error_data::ShouldRetry);
+    }
+
+    rpc_error_response::coded(error_code, err.to_string())
+}
@@ -1,10 +1,9 @@
 //! The `status` method error.
 
+use rpc_error_response::registry;
 use rpc_validator_key_logic::Error as ValidatorKeyError;
 use sp_api::ApiError;
 
-use super::api_error_code;
-
 /// The `status` method error kinds.
 #[derive(Debug)]
 pub enum Error {
@@ -13,19 +12,27 @@ pub enum Error {
     ValidatorKeyExtraction,
     /// An error that can occur during doing a call into runtime api.
     RuntimeApi(ApiError),
+    /// The batch of the public keys to get the statuses of is too large.
+    BatchTooLarge {
+        /// The size of the requested batch.
+        size: usize,
+        /// The maximum allowed batch size.
+        max: usize,
+    },
 }
 
 impl From<Error> for jsonrpsee::core::Error {
     fn from(err: Error) -> Self {
         match err {
-            Error::ValidatorKeyExtraction => rpc_error_response::simple(
-                api_error_code::VALIDATOR_KEY_EXTRACTION,
-                ValidatorKeyError::ValidatorKeyExtraction.to_string(),
-            ),
-            Error::RuntimeApi(err) => rpc_error_response::simple(
-                api_error_code::RUNTIME_API,
+            Error::ValidatorKeyExtraction => ValidatorKeyError::ValidatorKeyExtraction.into(),
+            Error::RuntimeApi(err) => rpc_error_response::coded(
+                &registry::RUNTIME_API_CALL,
                 format!("unable to get status from the runtime: {err}"),
             ),
+            Error::BatchTooLarge { size, max } => rpc_error_response::coded(
+                &registry::REQUEST_BATCH_TOO_LARGE,
+                format!("too many public keys in the batch: {size}, at most {max} allowed"),
+            ),
         }
     }
 }
@@ -42,7 +49,8 @@ mod tests {
         let error: jsonrpsee::core::Error = Error::ValidatorKeyExtraction.into();
         let error: ErrorObject = error.into();
 
-        let expected_error_message = "{\"code\":600,\"message\":\"unable to extract own key\"}";
+        let expected_error_message =
+            "{\"code\":600,\"message\":\"unable to extract own key\",\"data\":{\"errorCode\":601}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
@@ -56,7 +64,20 @@ mod tests {
         let error: ErrorObject = error.into();
 
         let expected_error_message =
-            "{\"code\":300,\"message\":\"unable to get status from the runtime: test\"}";
+            "{\"code\":300,\"message\":\"unable to get status from the runtime: test\",\"data\":{\"errorCode\":301}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+
+    #[test]
+    fn error_batch_too_large() {
+        let error: jsonrpsee::core::Error = Error::BatchTooLarge { size: 2, max: 1 }.into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message =
+            "{\"code\":700,\"message\":\"too many public keys in the batch: 2, at most 1 allowed\",\"data\":{\"errorCode\":701}}";
         assert_eq!(
             expected_error_message,
             serde_json::to_string(&error).unwrap()
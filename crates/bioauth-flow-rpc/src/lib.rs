@@ -5,30 +5,47 @@
 //! and the robonode server that issues auth tickets.
 
 use std::marker::PhantomData;
+use std::pin::Pin;
 use std::sync::Arc;
 
 use bioauth_flow_api::BioauthFlowApi;
 use bioauth_keys::traits::KeyExtractor as KeyExtractorT;
 use errors::{
-    authenticate::Error as AuthenticateError, enroll::Error as EnrollError,
+    authenticate::Error as AuthenticateError, chain_state::Error as ChainStateError,
+    enroll::Error as EnrollError, enroll_and_authenticate::Error as EnrollAndAuthenticateError,
     get_facetec_device_sdk_params::Error as GetFacetecDeviceSdkParamsError,
-    get_facetec_session_token::Error as GetFacetecSessionToken, sign::Error as SignError,
-    status::Error as StatusError,
+    get_facetec_session_token::Error as GetFacetecSessionToken, list_keys::Error as ListKeysError,
+    sign::Error as SignError, status::Error as StatusError,
+};
+use futures::{FutureExt, StreamExt};
+use jsonrpsee::{
+    core::{RpcResult, SubscriptionResult},
+    proc_macros::rpc,
+    SubscriptionSink,
 };
-use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use primitives_liveness_data::{LivenessData, OpaqueLivenessData};
-use robonode_client::{AuthenticateRequest, EnrollRequest};
+use robonode_client::{AuthenticateRequest, EnrollAndAuthenticateRequest, EnrollRequest};
 use rpc_deny_unsafe::DenyUnsafe;
-use sc_transaction_pool_api::TransactionPool as TransactionPoolT;
+use sc_client_api::BlockchainEvents;
+use sc_transaction_pool_api::{
+    TransactionPool as TransactionPoolT, TransactionStatus, TransactionStatusStreamFor, TxHash,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use sp_api::{BlockT, Decode, Encode, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
+use sp_core::{traits::SpawnNamed, Bytes};
 use tracing::*;
 
+mod chain_state;
 pub mod error_data;
 mod errors;
 
+#[cfg(test)]
+mod tests;
+
+pub use chain_state::MAX_STATUS_BATCH_SIZE;
+
 /// Signer provides signatures for the data.
 #[async_trait::async_trait]
 pub trait Signer<S> {
@@ -68,7 +85,7 @@ where
 type FacetecDeviceSdkParams = Map<String, Value>;
 
 /// The bioauth status as used in the RPC.
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BioauthStatus<Timestamp> {
     /// When the status can't be determined, but there was no error.
     /// Can happen if the validator key is absent.
@@ -91,9 +108,168 @@ impl<T> From<bioauth_flow_api::BioauthStatus<T>> for BioauthStatus<T> {
     }
 }
 
+/// An active authentication as used in the RPC.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActiveAuthentication<ValidatorPublicKey, Timestamp> {
+    /// The public key of the authenticated validator.
+    pub public_key: ValidatorPublicKey,
+    /// The timestamp when the authentication will expire.
+    pub expires_at: Timestamp,
+}
+
+impl<K, T> From<bioauth_flow_api::ActiveAuthentication<K, T>> for ActiveAuthentication<K, T> {
+    fn from(authentication: bioauth_flow_api::ActiveAuthentication<K, T>) -> Self {
+        Self {
+            public_key: authentication.id,
+            expires_at: authentication.expires_at,
+        }
+    }
+}
+
+/// The auth ticket verdict as used in the RPC.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuthTicketVerdict<ValidatorPublicKey> {
+    /// The auth ticket is valid and would be accepted.
+    Valid {
+        /// The public key of the validator the auth ticket is issued for.
+        public_key: ValidatorPublicKey,
+    },
+    /// The robonode signature of the auth ticket could not be validated.
+    UnableToValidateSignature,
+    /// The robonode signature of the auth ticket is invalid.
+    SignatureInvalid,
+    /// The auth ticket could not be parsed.
+    UnableToParse,
+    /// The nonce of the auth ticket has already been used.
+    NonceAlreadyUsed,
+    /// The validator of the auth ticket is already authenticated.
+    AlreadyAuthenticated,
+    /// The authentication is rejected by the checks that run before it.
+    RejectedByBeforeAuthHook {
+        /// The reason of the rejection.
+        reason: AuthRejectionReason,
+        /// The error code `bioauth_authenticate` reports for the rejected authentication.
+        error_code: i32,
+    },
+}
+
+/// The reason the authentication is rejected by the checks that run before it, as used in
+/// the RPC.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuthRejectionReason {
+    /// The validator of the auth ticket does not have the balance required to authenticate.
+    InsufficientBalance,
+    /// The validator of the auth ticket is banned from authenticating.
+    Banned,
+    /// The authentication is rejected for some other reason.
+    Other,
+}
+
+impl<K> AuthTicketVerdict<K> {
+    /// The verdict for the authentication rejected by the checks that run before it.
+    fn rejected_by_before_auth_hook(reason: AuthRejectionReason) -> Self {
+        Self::RejectedByBeforeAuthHook {
+            reason,
+            error_code: rpc_error_response::registry::TX_REJECTED_BY_BEFORE_AUTH_HOOK.code,
+        }
+    }
+}
+
+impl<K> From<bioauth_flow_api::AuthTicketVerdict<K>> for AuthTicketVerdict<K> {
+    fn from(verdict: bioauth_flow_api::AuthTicketVerdict<K>) -> Self {
+        match verdict {
+            bioauth_flow_api::AuthTicketVerdict::Valid { id } => Self::Valid { public_key: id },
+            bioauth_flow_api::AuthTicketVerdict::UnableToValidateSignature => {
+                Self::UnableToValidateSignature
+            }
+            bioauth_flow_api::AuthTicketVerdict::SignatureInvalid => Self::SignatureInvalid,
+            bioauth_flow_api::AuthTicketVerdict::UnableToParse => Self::UnableToParse,
+            bioauth_flow_api::AuthTicketVerdict::NonceAlreadyUsed => Self::NonceAlreadyUsed,
+            bioauth_flow_api::AuthTicketVerdict::AlreadyAuthenticated => Self::AlreadyAuthenticated,
+            bioauth_flow_api::AuthTicketVerdict::InsufficientBalance => {
+                Self::rejected_by_before_auth_hook(AuthRejectionReason::InsufficientBalance)
+            }
+            bioauth_flow_api::AuthTicketVerdict::Banned => {
+                Self::rejected_by_before_auth_hook(AuthRejectionReason::Banned)
+            }
+            bioauth_flow_api::AuthTicketVerdict::Rejected => {
+                Self::rejected_by_before_auth_hook(AuthRejectionReason::Other)
+            }
+        }
+    }
+}
+
+/// The sanction applied for an offence, as used in the RPC.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Sanction<Timestamp> {
+    /// The offence was only noted.
+    Warning,
+    /// The validator was disabled for the rest of the session.
+    Disable,
+    /// The validator was deauthenticated.
+    Deauthenticate,
+    /// The validator was deauthenticated and banned from authenticating again.
+    Ban {
+        /// The timestamp until which the validator is banned.
+        until: Timestamp,
+    },
+}
+
+impl<T> From<bioauth_flow_api::Sanction<T>> for Sanction<T> {
+    fn from(sanction: bioauth_flow_api::Sanction<T>) -> Self {
+        match sanction {
+            bioauth_flow_api::Sanction::Warning => Self::Warning,
+            bioauth_flow_api::Sanction::Disable => Self::Disable,
+            bioauth_flow_api::Sanction::Deauthenticate => Self::Deauthenticate,
+            bioauth_flow_api::Sanction::Ban { until } => Self::Ban { until },
+        }
+    }
+}
+
+/// An offence committed by the validator, as used in the RPC.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OffenceRecord<Timestamp> {
+    /// The kind of the offence, if known.
+    pub kind: Option<Bytes>,
+    /// The index of the session the offence was committed at.
+    pub session_index: u32,
+    /// The encoded time slot of the offence, empty if unknown.
+    pub time_slot: Bytes,
+    /// The slash fraction of the offence, in parts per billion.
+    pub slash_fraction: u32,
+    /// The sanction applied for the offence.
+    pub sanction: Sanction<Timestamp>,
+}
+
+impl<T> From<bioauth_flow_api::OffenceRecord<T>> for OffenceRecord<T> {
+    fn from(record: bioauth_flow_api::OffenceRecord<T>) -> Self {
+        Self {
+            kind: record.kind.map(|kind| kind.to_vec().into()),
+            session_index: record.session_index,
+            time_slot: record.time_slot.into(),
+            slash_fraction: record.slash_fraction.deconstruct(),
+            sanction: record.sanction.into(),
+        }
+    }
+}
+
+/// The status of the authentication submitted via the RPC, as used in the RPC.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuthenticateStatus<Hash, BlockHash, Timestamp> {
+    /// The status of the authenticate transaction has changed.
+    Transaction(TransactionStatus<Hash, BlockHash>),
+    /// The authenticate transaction has been finalized, and the authentication is active.
+    Authenticated {
+        /// The timestamp when the authentication will expire.
+        expires_at: Timestamp,
+    },
+    /// The authenticate transaction has been finalized, but the authentication is not active.
+    NotAuthenticated,
+}
+
 /// The API exposed via JSON-RPC.
 #[rpc(server)]
-pub trait Bioauth<Timestamp> {
+pub trait Bioauth<Timestamp, ValidatorPublicKey, TxHash, BlockHash> {
     /// Get the configuration required for the Device SDK.
     #[method(name = "bioauth_getFacetecDeviceSdkParams")]
     async fn get_facetec_device_sdk_params(&self) -> RpcResult<FacetecDeviceSdkParams>;
@@ -103,16 +279,168 @@ pub trait Bioauth<Timestamp> {
     async fn get_facetec_session_token(&self) -> RpcResult<String>;
 
     /// Get the current bioauth status.
+    ///
+    /// If the public key is specified, it is used instead of the one selected from
+    /// the keystore by default.
     #[method(name = "bioauth_status")]
-    async fn status(&self) -> RpcResult<BioauthStatus<Timestamp>>;
+    async fn status(
+        &self,
+        public_key: Option<ValidatorPublicKey>,
+    ) -> RpcResult<BioauthStatus<Timestamp>>;
+
+    /// List all of the bioauth public keys available in the keystore.
+    #[method(name = "bioauth_listKeys")]
+    async fn list_keys(&self) -> RpcResult<Vec<ValidatorPublicKey>>;
+
+    /// Subscribe to the bioauth status changes.
+    ///
+    /// The current status is sent right away, and then the new status is sent every time it
+    /// changes with the new best block.
+    #[subscription(
+        name = "bioauth_subscribeStatus" => "bioauth_statusChanged",
+        unsubscribe = "bioauth_unsubscribeStatus",
+        item = BioauthStatus<Timestamp>,
+    )]
+    fn subscribe_status(&self);
+
+    /// Get the bioauth status of a given validator public key at a given block, or at the best
+    /// block if the block is not specified.
+    #[method(name = "bioauth_statusOf")]
+    async fn status_of(
+        &self,
+        public_key: ValidatorPublicKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BioauthStatus<Timestamp>>;
+
+    /// Get the bioauth statuses of the given validator public keys at a given block, or at
+    /// the best block if the block is not specified.
+    ///
+    /// At most [`MAX_STATUS_BATCH_SIZE`] public keys are accepted in a single batch.
+    #[method(name = "bioauth_statusOfBatch")]
+    async fn status_of_batch(
+        &self,
+        public_keys: Vec<ValidatorPublicKey>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<BioauthStatus<Timestamp>>>;
 
     /// Enroll with provided liveness data.
+    ///
+    /// If the public key is specified, it is used instead of the one selected from
+    /// the keystore by default.
     #[method(name = "bioauth_enroll")]
-    async fn enroll(&self, liveness_data: LivenessData) -> RpcResult<()>;
+    async fn enroll(
+        &self,
+        liveness_data: LivenessData,
+        public_key: Option<ValidatorPublicKey>,
+    ) -> RpcResult<()>;
 
     /// Authenticate with provided liveness data.
+    ///
+    /// If `wait_for_inclusion` is set, the call returns only after the authenticate transaction
+    /// is included in a block.
+    /// If the public key is specified, it is used instead of the one selected from
+    /// the keystore by default.
     #[method(name = "bioauth_authenticate")]
-    async fn authenticate(&self, liveness_data: LivenessData) -> RpcResult<()>;
+    async fn authenticate(
+        &self,
+        liveness_data: LivenessData,
+        wait_for_inclusion: Option<bool>,
+        public_key: Option<ValidatorPublicKey>,
+    ) -> RpcResult<()>;
+
+    /// Get the exact bytes of the provided liveness data to sign with the validator key outside
+    /// of the node.
+    #[method(name = "bioauth_livenessDataSigningPayload")]
+    async fn liveness_data_signing_payload(&self, liveness_data: LivenessData) -> RpcResult<Bytes>;
+
+    /// Enroll with provided liveness data and the liveness data signature made outside of
+    /// the node with the given public key.
+    #[method(name = "bioauth_enrollWithSignature")]
+    async fn enroll_with_signature(
+        &self,
+        liveness_data: LivenessData,
+        liveness_data_signature: Bytes,
+        public_key: ValidatorPublicKey,
+    ) -> RpcResult<()>;
+
+    /// Authenticate with provided liveness data and the liveness data signature made outside of
+    /// the node.
+    ///
+    /// If `wait_for_inclusion` is set, the call returns only after the authenticate transaction
+    /// is included in a block.
+    #[method(name = "bioauth_authenticateWithSignature")]
+    async fn authenticate_with_signature(
+        &self,
+        liveness_data: LivenessData,
+        liveness_data_signature: Bytes,
+        wait_for_inclusion: Option<bool>,
+    ) -> RpcResult<()>;
+
+    /// Enroll and authenticate with provided liveness data, using a single FaceScan.
+    ///
+    /// If `wait_for_inclusion` is set, the call returns only after the authenticate transaction
+    /// is included in a block.
+    /// If the public key is specified, it is used instead of the one selected from
+    /// the keystore by default.
+    #[method(name = "bioauth_enrollAndAuthenticate")]
+    async fn enroll_and_authenticate(
+        &self,
+        liveness_data: LivenessData,
+        wait_for_inclusion: Option<bool>,
+        public_key: Option<ValidatorPublicKey>,
+    ) -> RpcResult<()>;
+
+    /// Authenticate with provided liveness data, and watch the authenticate transaction status
+    /// up until the finalization.
+    ///
+    /// If the public key is specified, it is used instead of the one selected from
+    /// the keystore by default.
+    #[subscription(
+        name = "bioauth_authenticateAndWatch" => "bioauth_authenticateStatus",
+        unsubscribe = "bioauth_unwatchAuthenticate",
+        item = AuthenticateStatus<TxHash, BlockHash, Timestamp>,
+    )]
+    fn authenticate_and_watch(
+        &self,
+        liveness_data: LivenessData,
+        public_key: Option<ValidatorPublicKey>,
+    );
+
+    /// Get all of the active authentications.
+    #[method(name = "bioauth_activeAuthentications")]
+    async fn active_authentications(
+        &self,
+    ) -> RpcResult<Vec<ActiveAuthentication<ValidatorPublicKey, Timestamp>>>;
+
+    /// Get the amount of the consumed auth ticket nonces.
+    #[method(name = "bioauth_consumedNoncesCount")]
+    async fn consumed_nonces_count(&self) -> RpcResult<u32>;
+
+    /// Get the robonode public key currently used to verify auth tickets.
+    #[method(name = "bioauth_robonodePublicKey")]
+    async fn robonode_public_key(&self) -> RpcResult<Bytes>;
+
+    /// Get the duration after which the new authentications expire.
+    #[method(name = "bioauth_authenticationsExpireAfter")]
+    async fn authentications_expire_after(&self) -> RpcResult<Timestamp>;
+
+    /// Check whether the auth ticket would be accepted by the chain at the best block,
+    /// without submitting it.
+    #[method(name = "bioauth_validateAuthTicket")]
+    async fn validate_auth_ticket(
+        &self,
+        auth_ticket: Bytes,
+        auth_ticket_signature: Bytes,
+    ) -> RpcResult<AuthTicketVerdict<ValidatorPublicKey>>;
+
+    /// Get the latest offences committed by a given validator public key at a given block,
+    /// or at the best block if the block is not specified.
+    #[method(name = "bioauth_offenceLog")]
+    async fn offence_log(
+        &self,
+        public_key: ValidatorPublicKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<OffenceRecord<Timestamp>>>;
 }
 
 /// The RPC implementation.
@@ -135,6 +463,8 @@ pub struct Bioauth<
     client: Arc<Client>,
     /// The transaction pool to use.
     pool: Arc<TransactionPool>,
+    /// The executor to run the subscriptions at.
+    executor: Arc<dyn SpawnNamed>,
     /// Whether to deny unsafe calls or not.
     deny_unsafe: DenyUnsafe,
     /// The phantom types.
@@ -167,6 +497,7 @@ impl<
         validator_signer_factory: ValidatorSignerFactory,
         client: Arc<Client>,
         pool: Arc<TransactionPool>,
+        executor: Arc<dyn SpawnNamed>,
         deny_unsafe: DenyUnsafe,
     ) -> Self {
         Self {
@@ -175,12 +506,50 @@ impl<
             validator_signer_factory,
             client,
             pool,
+            executor,
             deny_unsafe,
             phantom_types: PhantomData,
         }
     }
 }
 
+impl<
+        RobonodeClient,
+        ValidatorKeyExtractor,
+        ValidatorSignerFactory,
+        Client,
+        Block,
+        Timestamp,
+        TransactionPool,
+    > Clone
+    for Bioauth<
+        RobonodeClient,
+        ValidatorKeyExtractor,
+        ValidatorSignerFactory,
+        Client,
+        Block,
+        Timestamp,
+        TransactionPool,
+    >
+where
+    RobonodeClient: Clone,
+    ValidatorKeyExtractor: Clone,
+    ValidatorSignerFactory: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            robonode_client: self.robonode_client.clone(),
+            validator_key_extractor: self.validator_key_extractor.clone(),
+            validator_signer_factory: self.validator_signer_factory.clone(),
+            client: Arc::clone(&self.client),
+            pool: Arc::clone(&self.pool),
+            executor: Arc::clone(&self.executor),
+            deny_unsafe: self.deny_unsafe,
+            phantom_types: PhantomData,
+        }
+    }
+}
+
 impl<
         RobonodeClient,
         ValidatorKeyExtractor,
@@ -205,6 +574,15 @@ where
     ValidatorSignerFactory: SignerFactory<Vec<u8>, ValidatorKeyExtractor::PublicKeyType>,
     <<ValidatorSignerFactory as SignerFactory<Vec<u8>, ValidatorKeyExtractor::PublicKeyType>>::Signer as Signer<Vec<u8>>>::Error:
         std::error::Error + 'static,
+    RobonodeClient: AsRef<robonode_client::Client>,
+    ValidatorKeyExtractor::PublicKeyType: Encode + Decode + AsRef<[u8]> + Clone + PartialEq,
+    Client: HeaderBackend<Block>,
+    Client: ProvideRuntimeApi<Block>,
+    Client::Api:
+        bioauth_flow_api::BioauthFlowApi<Block, ValidatorKeyExtractor::PublicKeyType, Timestamp>,
+    Block: BlockT,
+    Timestamp: Encode + Decode,
+    TransactionPool: TransactionPoolT<Block = Block>,
 {
     /// Return the opaque liveness data and corresponding signature.
     async fn sign(&self, validator_key: <ValidatorKeyExtractor as KeyExtractorT>::PublicKeyType, liveness_data: &LivenessData) -> Result<(OpaqueLivenessData, Vec<u8>), SignError> {
@@ -218,6 +596,176 @@ where
 
         Ok((opaque_liveness_data, signature))
     }
+
+    /// Obtain an auth ticket for the provided liveness data and submit the authenticate
+    /// transaction.
+    /// Returns the public key being authenticated and the transaction status stream.
+    #[allow(clippy::type_complexity)]
+    async fn submit_authenticate(
+        &self,
+        liveness_data: &LivenessData,
+        requested_public_key: Option<ValidatorKeyExtractor::PublicKeyType>,
+    ) -> Result<
+        (
+            ValidatorKeyExtractor::PublicKeyType,
+            Pin<Box<TransactionStatusStreamFor<TransactionPool>>>,
+        ),
+        AuthenticateError<TransactionPool::Error>,
+    > {
+        let public_key = rpc_validator_key_logic::select_validator_public_key(&self.validator_key_extractor, requested_public_key).map_err(AuthenticateError::KeyExtraction)?;
+        let (opaque_liveness_data, signature) = self.sign(public_key.clone(), liveness_data).await
+            .map_err(AuthenticateError::Sign)?;
+
+        let watcher = self
+            .submit_signed_authenticate(&opaque_liveness_data, &signature)
+            .await?;
+
+        Ok((public_key, watcher))
+    }
+
+    /// Obtain an auth ticket for the provided signed liveness data and submit the authenticate
+    /// transaction.
+    /// Returns the transaction status stream.
+    async fn submit_signed_authenticate(
+        &self,
+        opaque_liveness_data: &OpaqueLivenessData,
+        signature: &[u8],
+    ) -> Result<
+        Pin<Box<TransactionStatusStreamFor<TransactionPool>>>,
+        AuthenticateError<TransactionPool::Error>,
+    > {
+        let response = self
+            .robonode_client
+            .as_ref()
+            .authenticate(AuthenticateRequest {
+                liveness_data: opaque_liveness_data.as_ref(),
+                liveness_data_signature: signature,
+            })
+            .await
+            .map_err(AuthenticateError::Robonode)?;
+
+        info!("Bioauth flow - authentication complete");
+
+        self.submit_auth_ticket(response.auth_ticket, response.auth_ticket_signature)
+            .await
+    }
+
+    /// Submit the authenticate transaction with the provided auth ticket.
+    /// Returns the transaction status stream.
+    async fn submit_auth_ticket(
+        &self,
+        auth_ticket: Box<[u8]>,
+        auth_ticket_signature: Box<[u8]>,
+    ) -> Result<
+        Pin<Box<TransactionStatusStreamFor<TransactionPool>>>,
+        AuthenticateError<TransactionPool::Error>,
+    > {
+        info!(message = "We've obtained an auth ticket", ?auth_ticket);
+
+        let at = self.client.info().best_hash;
+
+        let ext = self
+            .client
+            .runtime_api()
+            .create_authenticate_extrinsic(
+                at,
+                auth_ticket.into(),
+                auth_ticket_signature.into(),
+            )
+            .map_err(AuthenticateError::RuntimeApi)?;
+
+        let watcher = self
+            .pool
+            .submit_and_watch(
+                &sp_api::BlockId::Hash(at),
+                sp_runtime::transaction_validity::TransactionSource::Local,
+                ext,
+            )
+            .await
+            .map_err(AuthenticateError::BioauthTx)?;
+
+        Ok(watcher)
+    }
+
+    /// Wait until the authenticate transaction is included in a block.
+    async fn wait_for_inclusion(
+        mut watcher: Pin<Box<TransactionStatusStreamFor<TransactionPool>>>,
+    ) -> Result<(), AuthenticateError<TransactionPool::Error>> {
+        info!("Bioauth flow - waiting for the authenticate transaction inclusion");
+
+        loop {
+            let status = match watcher.next().await {
+                Some(status) => status,
+                None => {
+                    return Err(AuthenticateError::BioauthTxNotIncluded(
+                        "the transaction status stream has ended",
+                    ))
+                }
+            };
+            if let Some(reason) = tx_exclusion_reason(&status) {
+                return Err(AuthenticateError::BioauthTxNotIncluded(reason));
+            }
+            if matches!(
+                status,
+                TransactionStatus::InBlock(_) | TransactionStatus::Finalized(_)
+            ) {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Get the bioauth status of the local validator key at a given block.
+    fn status_at(&self, at: Block::Hash, requested_public_key: Option<ValidatorKeyExtractor::PublicKeyType>) -> Result<BioauthStatus<Timestamp>, StatusError> {
+        let own_key = match rpc_validator_key_logic::select_validator_public_key(&self.validator_key_extractor, requested_public_key) {
+            Ok(v) => v,
+            Err(rpc_validator_key_logic::Error::MissingValidatorKey) => return Ok(BioauthStatus::Unknown),
+            Err(rpc_validator_key_logic::Error::ValidatorKeyExtraction) => return Err(StatusError::ValidatorKeyExtraction),
+        };
+
+        let status = self
+            .client
+            .runtime_api()
+            .bioauth_status(at, &own_key)
+            .map_err(StatusError::RuntimeApi)?;
+
+        Ok(status.into())
+    }
+}
+
+/// Send the statuses to the subscription sink as they change, until either the statuses stream
+/// ends or the subscription is closed.
+///
+/// The status only changes via the bioauth pallet events (new authentications, expirations and
+/// deauthentications), so we only send the status when it differs from the previous one.
+async fn pipe_status_changes<Status>(
+    mut sink: SubscriptionSink,
+    statuses: impl futures::Stream<Item = Status>,
+) where
+    Status: Serialize + PartialEq + Clone,
+{
+    let mut last_status = None;
+    let status_changes = statuses.filter(move |status| {
+        let changed = last_status.as_ref() != Some(status);
+        if changed {
+            last_status = Some(status.clone());
+        }
+        futures::future::ready(changed)
+    });
+
+    let _ = sink.pipe_from_stream(Box::pin(status_changes)).await;
+}
+
+/// The reason the transaction is not going to make it into a block, if the transaction status
+/// is final.
+fn tx_exclusion_reason<Hash, BlockHash>(
+    status: &TransactionStatus<Hash, BlockHash>,
+) -> Option<&'static str> {
+    match status {
+        TransactionStatus::Usurped(_) => Some("the transaction was usurped"),
+        TransactionStatus::Dropped => Some("the transaction was dropped"),
+        TransactionStatus::Invalid => Some("the transaction became invalid"),
+        _ => None,
+    }
 }
 
 #[jsonrpsee::core::async_trait]
@@ -229,7 +777,7 @@ impl<
         Block,
         Timestamp,
         TransactionPool,
-    > BioauthServer<Timestamp>
+    > BioauthServer<Timestamp, ValidatorKeyExtractor::PublicKeyType, TxHash<TransactionPool>, Block::Hash>
     for Bioauth<
         RobonodeClient,
         ValidatorKeyExtractor,
@@ -250,20 +798,21 @@ where
     Timestamp: Send + Sync + 'static,
     TransactionPool: Send + Sync + 'static,
 
-    RobonodeClient: AsRef<robonode_client::Client>,
-    ValidatorKeyExtractor: KeyExtractorT,
-    ValidatorKeyExtractor::PublicKeyType: Encode + AsRef<[u8]> + Clone,
+    RobonodeClient: AsRef<robonode_client::Client> + Clone,
+    ValidatorKeyExtractor: KeyExtractorT + Clone,
+    ValidatorKeyExtractor::PublicKeyType: Encode + Decode + AsRef<[u8]> + Clone + PartialEq,
     ValidatorKeyExtractor::Error: std::fmt::Debug,
-    ValidatorSignerFactory: SignerFactory<Vec<u8>, ValidatorKeyExtractor::PublicKeyType>,
+    ValidatorSignerFactory: SignerFactory<Vec<u8>, ValidatorKeyExtractor::PublicKeyType> + Clone,
     <<ValidatorSignerFactory as SignerFactory<Vec<u8>, ValidatorKeyExtractor::PublicKeyType>>::Signer as Signer<Vec<u8>>>::Error:
         std::error::Error + 'static,
     Client: HeaderBackend<Block>,
     Client: ProvideRuntimeApi<Block>,
+    Client: BlockchainEvents<Block>,
     Client: Send + Sync + 'static,
     Client::Api:
         bioauth_flow_api::BioauthFlowApi<Block, ValidatorKeyExtractor::PublicKeyType, Timestamp>,
     Block: BlockT,
-    Timestamp: Encode + Decode,
+    Timestamp: Encode + Decode + Serialize + PartialEq,
     TransactionPool: TransactionPoolT<Block = Block>,
 {
     async fn get_facetec_device_sdk_params(&self) -> RpcResult<FacetecDeviceSdkParams> {
@@ -286,31 +835,83 @@ where
         Ok(res.session_token)
     }
 
-    async fn status(&self) -> RpcResult<BioauthStatus<Timestamp>> {
-        let own_key = match rpc_validator_key_logic::validator_public_key(&self.validator_key_extractor) {
-            Ok(v) => v,
-            Err(rpc_validator_key_logic::Error::MissingValidatorKey) => return Ok(BioauthStatus::Unknown),
-            Err(rpc_validator_key_logic::Error::ValidatorKeyExtraction) => return Err(StatusError::ValidatorKeyExtraction.into()),
-        };
-
+    async fn status(&self, public_key: Option<ValidatorKeyExtractor::PublicKeyType>) -> RpcResult<BioauthStatus<Timestamp>> {
         // Extract an id of the last imported block.
         let at = self.client.info().best_hash;
 
-        let status = self
-            .client
-            .runtime_api()
-            .bioauth_status(at, &own_key)
-            .map_err(StatusError::RuntimeApi)?;
+        Ok(self.status_at(at, public_key)?)
+    }
 
-        Ok(status.into())
+    async fn list_keys(&self) -> RpcResult<Vec<ValidatorKeyExtractor::PublicKeyType>> {
+        let public_keys = self
+            .validator_key_extractor
+            .extract_all_keys()
+            .map_err(|error| {
+                error!(message = "Unable to extract own keys at bioauth flow RPC", ?error);
+                ListKeysError::ValidatorKeyExtraction
+            })?;
+
+        Ok(public_keys)
+    }
+
+    fn subscribe_status(&self, sink: SubscriptionSink) -> SubscriptionResult {
+        let this = self.clone();
+        let fut = async move {
+            // Start with the current best block, and then follow the new best blocks.
+            let best_hash = this.client.info().best_hash;
+            let new_best_hashes = this
+                .client
+                .import_notification_stream()
+                .filter_map(|notification| {
+                    futures::future::ready(notification.is_new_best.then_some(notification.hash))
+                });
+            let statuses = futures::stream::once(futures::future::ready(best_hash))
+                .chain(new_best_hashes)
+                .filter_map(move |at| {
+                    let status = this
+                        .status_at(at, None)
+                        .map_err(|error| {
+                            error!(message = "Unable to get the bioauth status", ?error);
+                        })
+                        .ok();
+                    futures::future::ready(status)
+                });
+
+            pipe_status_changes(sink, statuses).await;
+        };
+
+        self.executor.spawn("bioauth-status-subscription", Some("rpc"), fut.boxed());
+
+        Ok(())
+    }
+
+    async fn status_of(
+        &self,
+        public_key: ValidatorKeyExtractor::PublicKeyType,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<BioauthStatus<Timestamp>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        Ok(chain_state::status_of(&*self.client.runtime_api(), at, &public_key)?)
     }
 
-    async fn enroll(&self, liveness_data: LivenessData) -> RpcResult<()> {
+    async fn status_of_batch(
+        &self,
+        public_keys: Vec<ValidatorKeyExtractor::PublicKeyType>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<BioauthStatus<Timestamp>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        // Use the same runtime API instance for all of the keys.
+        Ok(chain_state::status_of_batch(&*self.client.runtime_api(), at, &public_keys)?)
+    }
+
+    async fn enroll(&self, liveness_data: LivenessData, public_key: Option<ValidatorKeyExtractor::PublicKeyType>) -> RpcResult<()> {
         self.deny_unsafe.check_if_safe()?;
 
         info!("Bioauth flow - enrolling in progress");
 
-        let public_key = rpc_validator_key_logic::validator_public_key(&self.validator_key_extractor).map_err(EnrollError::KeyExtraction)?;
+        let public_key = rpc_validator_key_logic::select_validator_public_key(&self.validator_key_extractor, public_key).map_err(EnrollError::KeyExtraction)?;
         let (opaque_liveness_data, signature) = self.sign(public_key.clone(), &liveness_data).await
             .map_err(EnrollError::Sign)?;
 
@@ -329,54 +930,211 @@ where
         Ok(())
     }
 
-    async fn authenticate(&self, liveness_data: LivenessData) -> RpcResult<()> {
+    async fn authenticate(&self, liveness_data: LivenessData, wait_for_inclusion: Option<bool>, public_key: Option<ValidatorKeyExtractor::PublicKeyType>) -> RpcResult<()> {
         self.deny_unsafe.check_if_safe()?;
 
         info!("Bioauth flow - authentication in progress");
 
-        let errtype = |val: errors::authenticate::Error<TransactionPool::Error>| {  val };
+        let (_, watcher) = self.submit_authenticate(&liveness_data, public_key).await?;
+
+        if wait_for_inclusion.unwrap_or(false) {
+            Self::wait_for_inclusion(watcher).await?;
+        }
+
+        info!("Bioauth flow - authenticate transaction complete");
+
+        Ok(())
+    }
+
+    async fn liveness_data_signing_payload(&self, liveness_data: LivenessData) -> RpcResult<Bytes> {
+        let opaque_liveness_data = OpaqueLivenessData::from(&liveness_data);
+        Ok(opaque_liveness_data.as_ref().to_vec().into())
+    }
+
+    async fn enroll_with_signature(&self, liveness_data: LivenessData, liveness_data_signature: Bytes, public_key: ValidatorKeyExtractor::PublicKeyType) -> RpcResult<()> {
+        self.deny_unsafe.check_if_safe()?;
+
+        info!("Bioauth flow - enrolling with signature in progress");
+
+        let opaque_liveness_data = OpaqueLivenessData::from(&liveness_data);
+
+        self.robonode_client
+            .as_ref()
+            .enroll(EnrollRequest {
+                liveness_data: opaque_liveness_data.as_ref(),
+                liveness_data_signature: liveness_data_signature.as_ref(),
+                public_key: public_key.as_ref(),
+            })
+            .await
+            .map_err(EnrollError::Robonode)?;
+
+        info!("Bioauth flow - enrolling with signature complete");
+
+        Ok(())
+    }
+
+    async fn authenticate_with_signature(&self, liveness_data: LivenessData, liveness_data_signature: Bytes, wait_for_inclusion: Option<bool>) -> RpcResult<()> {
+        self.deny_unsafe.check_if_safe()?;
+
+        info!("Bioauth flow - authentication with signature in progress");
+
+        let opaque_liveness_data = OpaqueLivenessData::from(&liveness_data);
+
+        let watcher = self
+            .submit_signed_authenticate(&opaque_liveness_data, &liveness_data_signature)
+            .await?;
+
+        if wait_for_inclusion.unwrap_or(false) {
+            Self::wait_for_inclusion(watcher).await?;
+        }
+
+        info!("Bioauth flow - authenticate transaction complete");
+
+        Ok(())
+    }
+
+    async fn enroll_and_authenticate(&self, liveness_data: LivenessData, wait_for_inclusion: Option<bool>, public_key: Option<ValidatorKeyExtractor::PublicKeyType>) -> RpcResult<()> {
+        self.deny_unsafe.check_if_safe()?;
 
-        let public_key = rpc_validator_key_logic::validator_public_key(&self.validator_key_extractor).map_err(AuthenticateError::KeyExtraction).map_err(errtype)?;
-        let (opaque_liveness_data, signature) = self.sign(public_key, &liveness_data).await
-            .map_err(AuthenticateError::Sign).map_err(errtype)?;
+        info!("Bioauth flow - enroll and authenticate in progress");
+
+        let public_key = rpc_validator_key_logic::select_validator_public_key(&self.validator_key_extractor, public_key).map_err(EnrollAndAuthenticateError::KeyExtraction)?;
+        let (opaque_liveness_data, signature) = self.sign(public_key.clone(), &liveness_data).await
+            .map_err(EnrollAndAuthenticateError::Sign)?;
 
         let response = self
             .robonode_client
             .as_ref()
-            .authenticate(AuthenticateRequest {
+            .enroll_and_authenticate(EnrollAndAuthenticateRequest {
                 liveness_data: opaque_liveness_data.as_ref(),
                 liveness_data_signature: signature.as_ref(),
+                public_key: public_key.as_ref(),
             })
             .await
-            .map_err(AuthenticateError::Robonode).map_err(errtype)?;
+            .map_err(EnrollAndAuthenticateError::Robonode)?;
 
-        info!("Bioauth flow - authentication complete");
+        info!("Bioauth flow - enroll and authenticate complete");
 
-        info!(message = "We've obtained an auth ticket", auth_ticket = ?response.auth_ticket);
+        let watcher = self
+            .submit_auth_ticket(response.auth_ticket, response.auth_ticket_signature)
+            .await
+            .map_err(EnrollAndAuthenticateError::Authenticate)?;
 
-        let at = self.client.info().best_hash;
+        if wait_for_inclusion.unwrap_or(false) {
+            Self::wait_for_inclusion(watcher)
+                .await
+                .map_err(EnrollAndAuthenticateError::Authenticate)?;
+        }
 
-        let ext = self
-            .client
-            .runtime_api()
-            .create_authenticate_extrinsic(
-                at,
-                response.auth_ticket.into(),
-                response.auth_ticket_signature.into(),
-            )
-            .map_err(AuthenticateError::RuntimeApi).map_err(errtype)?;
+        info!("Bioauth flow - authenticate transaction complete");
 
-        self.pool
-            .submit_and_watch(
-                &sp_api::BlockId::Hash(at),
-                sp_runtime::transaction_validity::TransactionSource::Local,
-                ext,
-            )
-            .await
-            .map_err(AuthenticateError::BioauthTx).map_err(errtype)?;
+        Ok(())
+    }
 
-        info!("Bioauth flow - authenticate transaction complete");
+    fn authenticate_and_watch(&self, mut sink: SubscriptionSink, liveness_data: LivenessData, public_key: Option<ValidatorKeyExtractor::PublicKeyType>) -> SubscriptionResult {
+        if let Err(err) = self.deny_unsafe.check_if_safe() {
+            let _ = sink.reject(jsonrpsee::core::Error::from(err));
+            return Ok(());
+        }
+
+        let this = self.clone();
+        let fut = async move {
+            info!("Bioauth flow - authentication in progress");
+
+            let (public_key, mut watcher) = match this.submit_authenticate(&liveness_data, public_key).await {
+                Ok(val) => val,
+                Err(err) => {
+                    let _ = sink.reject(jsonrpsee::core::Error::from(err));
+                    return;
+                }
+            };
+
+            if sink.accept().is_err() {
+                return;
+            }
+
+            while let Some(status) = watcher.next().await {
+                let finalized_at = match status {
+                    TransactionStatus::Finalized((block_hash, _)) => Some(block_hash),
+                    _ => None,
+                };
+
+                if !matches!(sink.send(&AuthenticateStatus::Transaction(status)), Ok(true)) {
+                    return;
+                }
+
+                let at = match finalized_at {
+                    Some(at) => at,
+                    None => continue,
+                };
+
+                let outcome = match this.client.runtime_api().bioauth_status(at, &public_key) {
+                    Ok(bioauth_flow_api::BioauthStatus::Active { expires_at }) => {
+                        info!("Bioauth flow - authenticate transaction finalized");
+                        AuthenticateStatus::Authenticated { expires_at }
+                    }
+                    Ok(bioauth_flow_api::BioauthStatus::Inactive) => {
+                        warn!("Bioauth flow - authenticate transaction finalized, but the authentication is not active");
+                        AuthenticateStatus::NotAuthenticated
+                    }
+                    Err(error) => {
+                        error!(message = "Unable to get the bioauth status after finalization", ?error);
+                        return;
+                    }
+                };
+                let _ = sink.send(&outcome);
+                return;
+            }
+        };
+
+        self.executor.spawn("bioauth-authenticate-watch", Some("rpc"), fut.boxed());
 
         Ok(())
     }
+
+    async fn active_authentications(&self) -> RpcResult<Vec<ActiveAuthentication<ValidatorKeyExtractor::PublicKeyType, Timestamp>>> {
+        let at = self.client.info().best_hash;
+
+        Ok(chain_state::active_authentications(&*self.client.runtime_api(), at)?)
+    }
+
+    async fn consumed_nonces_count(&self) -> RpcResult<u32> {
+        let at = self.client.info().best_hash;
+
+        Ok(chain_state::consumed_nonces_count(&*self.client.runtime_api(), at)?)
+    }
+
+    async fn robonode_public_key(&self) -> RpcResult<Bytes> {
+        let at = self.client.info().best_hash;
+
+        Ok(chain_state::robonode_public_key(&*self.client.runtime_api(), at)?)
+    }
+
+    async fn authentications_expire_after(&self) -> RpcResult<Timestamp> {
+        let at = self.client.info().best_hash;
+
+        Ok(chain_state::authentications_expire_after(&*self.client.runtime_api(), at)?)
+    }
+
+    async fn validate_auth_ticket(&self, auth_ticket: Bytes, auth_ticket_signature: Bytes) -> RpcResult<AuthTicketVerdict<ValidatorKeyExtractor::PublicKeyType>> {
+        let at = self.client.info().best_hash;
+
+        Ok(chain_state::validate_auth_ticket(&*self.client.runtime_api(), at, auth_ticket.0, auth_ticket_signature.0)?)
+    }
+
+    async fn offence_log(
+        &self,
+        public_key: ValidatorKeyExtractor::PublicKeyType,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<OffenceRecord<Timestamp>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let offence_log = self
+            .client
+            .runtime_api()
+            .offence_log(at, &public_key)
+            .map_err(ChainStateError::RuntimeApi)?;
+
+        Ok(offence_log.into_iter().map(Into::into).collect())
+    }
 }
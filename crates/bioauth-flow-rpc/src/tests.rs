@@ -0,0 +1,329 @@
+//! The tests for the RPC logic, against a mock runtime API.
+
+// Allow simple integer arithmetic in tests.
+#![allow(clippy::arithmetic_side_effects)]
+
+use sp_runtime::{generic, traits::BlakeTwo256, OpaqueExtrinsic};
+
+use super::*;
+
+/// The block type used in tests.
+type Block = generic::Block<generic::Header<u32, BlakeTwo256>, OpaqueExtrinsic>;
+
+/// The validator public key type used in tests.
+type ValidatorPublicKey = u64;
+
+/// The timestamp type used in tests.
+type Timestamp = u64;
+
+/// The mock runtime API, serving the bioauth chain state it is constructed with.
+#[derive(Default)]
+struct MockRuntimeApi {
+    /// The active authentications, as pairs of the public key and the expiration timestamp.
+    active_authentications: Vec<(ValidatorPublicKey, Timestamp)>,
+    /// The amount of the consumed auth ticket nonces.
+    consumed_nonces_count: u32,
+    /// The robonode public key.
+    robonode_public_key: Vec<u8>,
+    /// The duration after which the new authentications expire.
+    authentications_expire_after: Timestamp,
+    /// The public keys with the active authentications, and the expiration timestamps.
+    active_statuses: Vec<(ValidatorPublicKey, Timestamp)>,
+    /// The verdict on any auth ticket, if set.
+    auth_ticket_verdict: Option<bioauth_flow_api::AuthTicketVerdict<ValidatorPublicKey>>,
+}
+
+sp_api::mock_impl_runtime_apis! {
+    impl BioauthFlowApi<Block, ValidatorPublicKey, Timestamp> for MockRuntimeApi {
+        fn bioauth_status(
+            &self,
+            id: &ValidatorPublicKey,
+        ) -> bioauth_flow_api::BioauthStatus<Timestamp> {
+            match self.active_statuses.iter().find(|(public_key, _)| public_key == id) {
+                Some(&(_, expires_at)) => bioauth_flow_api::BioauthStatus::Active { expires_at },
+                None => bioauth_flow_api::BioauthStatus::Inactive,
+            }
+        }
+
+        fn active_authentications(
+            &self,
+        ) -> Vec<bioauth_flow_api::ActiveAuthentication<ValidatorPublicKey, Timestamp>> {
+            self.active_authentications
+                .iter()
+                .map(|&(id, expires_at)| bioauth_flow_api::ActiveAuthentication { id, expires_at })
+                .collect()
+        }
+
+        fn consumed_auth_ticket_nonces_count(&self) -> u32 {
+            self.consumed_nonces_count
+        }
+
+        fn robonode_public_key(&self) -> Vec<u8> {
+            self.robonode_public_key.clone()
+        }
+
+        fn authentications_expire_after(&self) -> Timestamp {
+            self.authentications_expire_after
+        }
+
+        fn validate_auth_ticket(
+            &self,
+            _auth_ticket: Vec<u8>,
+            _auth_ticket_signature: Vec<u8>,
+        ) -> bioauth_flow_api::AuthTicketVerdict<ValidatorPublicKey> {
+            self.auth_ticket_verdict
+                .clone()
+                .expect("the auth ticket verdict is not set")
+        }
+    }
+}
+
+/// The block hash to query the state at in tests.
+fn at() -> <Block as BlockT>::Hash {
+    Default::default()
+}
+
+#[test]
+fn active_authentications() {
+    let runtime_api = MockRuntimeApi {
+        active_authentications: vec![(1, 100), (2, 200)],
+        ..Default::default()
+    };
+
+    let active_authentications = chain_state::active_authentications(&runtime_api, at()).unwrap();
+
+    assert_eq!(
+        active_authentications,
+        vec![
+            ActiveAuthentication {
+                public_key: 1,
+                expires_at: 100,
+            },
+            ActiveAuthentication {
+                public_key: 2,
+                expires_at: 200,
+            },
+        ]
+    );
+    assert_eq!(
+        serde_json::to_string(&active_authentications).unwrap(),
+        "[{\"public_key\":1,\"expires_at\":100},{\"public_key\":2,\"expires_at\":200}]"
+    );
+}
+
+#[test]
+fn active_authentications_empty() {
+    let runtime_api = MockRuntimeApi::default();
+
+    let active_authentications = chain_state::active_authentications(&runtime_api, at()).unwrap();
+
+    assert!(active_authentications.is_empty());
+}
+
+#[test]
+fn consumed_nonces_count() {
+    let runtime_api = MockRuntimeApi {
+        consumed_nonces_count: 3,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        chain_state::consumed_nonces_count(&runtime_api, at()).unwrap(),
+        3
+    );
+}
+
+#[test]
+fn robonode_public_key() {
+    let runtime_api = MockRuntimeApi {
+        robonode_public_key: vec![1, 2, 3],
+        ..Default::default()
+    };
+
+    let robonode_public_key = chain_state::robonode_public_key(&runtime_api, at()).unwrap();
+
+    assert_eq!(robonode_public_key, Bytes(vec![1, 2, 3]));
+    assert_eq!(
+        serde_json::to_string(&robonode_public_key).unwrap(),
+        "\"0x010203\""
+    );
+}
+
+#[test]
+fn authentications_expire_after() {
+    let runtime_api = MockRuntimeApi {
+        authentications_expire_after: 1000,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        chain_state::authentications_expire_after(&runtime_api, at()).unwrap(),
+        1000
+    );
+}
+
+#[test]
+fn validate_auth_ticket_valid() {
+    let runtime_api = MockRuntimeApi {
+        auth_ticket_verdict: Some(bioauth_flow_api::AuthTicketVerdict::Valid { id: 1 }),
+        ..Default::default()
+    };
+
+    let verdict = chain_state::validate_auth_ticket(&runtime_api, at(), vec![1], vec![2]).unwrap();
+
+    assert_eq!(verdict, AuthTicketVerdict::Valid { public_key: 1 });
+    assert_eq!(
+        serde_json::to_string(&verdict).unwrap(),
+        "{\"Valid\":{\"public_key\":1}}"
+    );
+}
+
+#[test]
+fn validate_auth_ticket_rejected_by_before_auth_hook() {
+    let runtime_api = MockRuntimeApi {
+        auth_ticket_verdict: Some(bioauth_flow_api::AuthTicketVerdict::Banned),
+        ..Default::default()
+    };
+
+    let verdict: AuthTicketVerdict<ValidatorPublicKey> =
+        chain_state::validate_auth_ticket(&runtime_api, at(), vec![1], vec![2]).unwrap();
+
+    assert_eq!(
+        verdict,
+        AuthTicketVerdict::RejectedByBeforeAuthHook {
+            reason: AuthRejectionReason::Banned,
+            error_code: rpc_error_response::registry::TX_REJECTED_BY_BEFORE_AUTH_HOOK.code,
+        }
+    );
+    assert_eq!(
+        serde_json::to_string(&verdict).unwrap(),
+        "{\"RejectedByBeforeAuthHook\":{\"reason\":\"Banned\",\"error_code\":408}}"
+    );
+}
+
+#[test]
+fn status_of() {
+    let runtime_api = MockRuntimeApi {
+        active_statuses: vec![(1, 100)],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        chain_state::status_of(&runtime_api, at(), &1).unwrap(),
+        BioauthStatus::Active { expires_at: 100 }
+    );
+    assert_eq!(
+        chain_state::status_of(&runtime_api, at(), &2).unwrap(),
+        BioauthStatus::Inactive
+    );
+}
+
+#[test]
+fn status_of_batch() {
+    let runtime_api = MockRuntimeApi {
+        active_statuses: vec![(1, 100), (3, 300)],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        chain_state::status_of_batch(&runtime_api, at(), &[3, 2, 1]).unwrap(),
+        vec![
+            BioauthStatus::Active { expires_at: 300 },
+            BioauthStatus::Inactive,
+            BioauthStatus::Active { expires_at: 100 },
+        ]
+    );
+}
+
+#[test]
+fn status_of_batch_empty() {
+    let runtime_api = MockRuntimeApi::default();
+
+    assert!(chain_state::status_of_batch(&runtime_api, at(), &[])
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn status_of_batch_at_the_limit() {
+    let runtime_api = MockRuntimeApi::default();
+    let public_keys: Vec<ValidatorPublicKey> = (0..MAX_STATUS_BATCH_SIZE as u64).collect();
+
+    let statuses = chain_state::status_of_batch(&runtime_api, at(), &public_keys).unwrap();
+
+    assert_eq!(statuses.len(), MAX_STATUS_BATCH_SIZE);
+}
+
+#[test]
+fn status_of_batch_too_large() {
+    let runtime_api = MockRuntimeApi::default();
+    let public_keys: Vec<ValidatorPublicKey> = (0..=MAX_STATUS_BATCH_SIZE as u64).collect();
+
+    let error = chain_state::status_of_batch(&runtime_api, at(), &public_keys).unwrap_err();
+
+    assert!(matches!(
+        error,
+        StatusError::BatchTooLarge { size, max }
+            if size == MAX_STATUS_BATCH_SIZE + 1 && max == MAX_STATUS_BATCH_SIZE
+    ));
+}
+
+#[tokio::test]
+async fn status_subscription_ends_after_unsubscribe() {
+    let (ended_tx, ended_rx) = futures::channel::oneshot::channel();
+    let ended_tx = std::sync::Mutex::new(Some(ended_tx));
+
+    let mut module = jsonrpsee::RpcModule::new(());
+    module
+        .register_subscription(
+            "test_subscribeStatus",
+            "test_status",
+            "test_unsubscribeStatus",
+            move |_, sink, _| {
+                let ended_tx = ended_tx.lock().unwrap().take();
+                let statuses = futures::stream::iter([
+                    BioauthStatus::<Timestamp>::Inactive,
+                    BioauthStatus::Inactive,
+                    BioauthStatus::Active { expires_at: 100 },
+                ])
+                .chain(futures::stream::pending());
+                tokio::spawn(async move {
+                    pipe_status_changes(sink, statuses).await;
+                    if let Some(ended_tx) = ended_tx {
+                        let _ = ended_tx.send(());
+                    }
+                });
+                Ok(())
+            },
+        )
+        .unwrap();
+
+    let mut subscription = module
+        .subscribe(
+            "test_subscribeStatus",
+            jsonrpsee::types::EmptyServerParams::new(),
+        )
+        .await
+        .unwrap();
+
+    // Only the status changes are sent.
+    let (status, _) = subscription
+        .next::<BioauthStatus<Timestamp>>()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(status, BioauthStatus::Inactive);
+    let (status, _) = subscription
+        .next::<BioauthStatus<Timestamp>>()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(status, BioauthStatus::Active { expires_at: 100 });
+
+    // The task ends once the subscription is closed, even though the statuses never end.
+    drop(subscription);
+    tokio::time::timeout(std::time::Duration::from_secs(5), ended_rx)
+        .await
+        .expect("the subscription task did not end")
+        .unwrap();
+}
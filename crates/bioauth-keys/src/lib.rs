@@ -51,19 +51,15 @@ impl<Id, Selector> KeyExtractor<Id, Selector> {
     }
 }
 
-impl<Id, Selector> traits::KeyExtractor for KeyExtractor<Id, Selector>
+impl<Id, Selector> KeyExtractor<Id, Selector>
 where
     Id: for<'a> TryFrom<&'a [u8]> + AppKey,
-    Selector: KeySelector<Id>,
 {
-    type Error = KeyExtractorError<Selector::Error>;
-    type PublicKeyType = Id;
-
-    fn extract_key(&self) -> Result<Option<Self::PublicKeyType>, Self::Error> {
+    /// List the keys of the matching type from the keystore.
+    fn matching_keys(&self) -> Result<impl Iterator<Item = Id>, sp_keystore::Error> {
         let keystore_ref = self.keystore.as_ref();
 
-        let crypto_type_public_pairs = sp_keystore::SyncCryptoStore::keys(keystore_ref, Id::ID)
-            .map_err(KeyExtractorError::Keystore)?;
+        let crypto_type_public_pairs = sp_keystore::SyncCryptoStore::keys(keystore_ref, Id::ID)?;
 
         let matching_crypto_public_keys = crypto_type_public_pairs.into_iter().filter_map(
             |CryptoTypePublicPair(crypto_type_id, public_key)| {
@@ -75,8 +71,20 @@ where
             },
         );
 
-        let matching_keys =
-            matching_crypto_public_keys.filter_map(|bytes| Id::try_from(&bytes).ok());
+        Ok(matching_crypto_public_keys.filter_map(|bytes| Id::try_from(&bytes).ok()))
+    }
+}
+
+impl<Id, Selector> traits::KeyExtractor for KeyExtractor<Id, Selector>
+where
+    Id: for<'a> TryFrom<&'a [u8]> + AppKey,
+    Selector: KeySelector<Id>,
+{
+    type Error = KeyExtractorError<Selector::Error>;
+    type PublicKeyType = Id;
+
+    fn extract_key(&self) -> Result<Option<Self::PublicKeyType>, Self::Error> {
+        let matching_keys = self.matching_keys().map_err(KeyExtractorError::Keystore)?;
 
         let key = self
             .selector
@@ -85,6 +93,11 @@ where
 
         Ok(key)
     }
+
+    fn extract_all_keys(&self) -> Result<Vec<Self::PublicKeyType>, Self::Error> {
+        let matching_keys = self.matching_keys().map_err(KeyExtractorError::Keystore)?;
+        Ok(matching_keys.collect())
+    }
 }
 
 /// Selects one key out of one.
@@ -110,3 +123,68 @@ impl<Id> KeySelector<Id> for OneOfOneSelector {
         Ok(first_key)
     }
 }
+
+/// Selects the key with a strategy that is chosen at runtime.
+#[derive(Debug, Clone)]
+pub enum StrategySelector<Id> {
+    /// Select the first key out of many.
+    First,
+    /// Select one key out of one, see [`OneOfOneSelector`].
+    OneOfOne,
+    /// Select the explicitly specified key, if it is available.
+    Explicit(Id),
+}
+
+impl<Id: PartialEq> KeySelector<Id> for StrategySelector<Id> {
+    type Error = MultipleKeysError;
+
+    fn select_key<T: Iterator<Item = Id>>(&self, mut keys: T) -> Result<Option<Id>, Self::Error> {
+        match self {
+            Self::First => Ok(keys.next()),
+            Self::OneOfOne => OneOfOneSelector.select_key(keys),
+            Self::Explicit(explicit_key) => Ok(keys.find(|key| key == explicit_key)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strategy_selector_first() {
+        let selector = StrategySelector::First;
+
+        assert_eq!(
+            selector.select_key(Vec::<u8>::new().into_iter()).unwrap(),
+            None
+        );
+        assert_eq!(
+            selector.select_key(vec![1, 2, 3].into_iter()).unwrap(),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn strategy_selector_one_of_one() {
+        let selector = StrategySelector::OneOfOne;
+
+        assert_eq!(
+            selector.select_key(Vec::<u8>::new().into_iter()).unwrap(),
+            None
+        );
+        assert_eq!(selector.select_key(vec![1].into_iter()).unwrap(), Some(1));
+        assert!(selector.select_key(vec![1, 2].into_iter()).is_err());
+    }
+
+    #[test]
+    fn strategy_selector_explicit() {
+        let selector = StrategySelector::Explicit(2);
+
+        assert_eq!(
+            selector.select_key(vec![1, 2, 3].into_iter()).unwrap(),
+            Some(2)
+        );
+        assert_eq!(selector.select_key(vec![1, 3].into_iter()).unwrap(), None);
+    }
+}
@@ -9,6 +9,9 @@ pub trait KeyExtractor {
 
     /// Extract public key.
     fn extract_key(&self) -> Result<Option<Self::PublicKeyType>, Self::Error>;
+
+    /// Extract all of the available public keys, without the selection.
+    fn extract_all_keys(&self) -> Result<Vec<Self::PublicKeyType>, Self::Error>;
 }
 
 impl<T: KeyExtractor> KeyExtractor for std::sync::Arc<T> {
@@ -18,4 +21,8 @@ impl<T: KeyExtractor> KeyExtractor for std::sync::Arc<T> {
     fn extract_key(&self) -> Result<Option<Self::PublicKeyType>, Self::Error> {
         self.as_ref().extract_key()
     }
+
+    fn extract_all_keys(&self) -> Result<Vec<Self::PublicKeyType>, Self::Error> {
+        self.as_ref().extract_all_keys()
+    }
 }
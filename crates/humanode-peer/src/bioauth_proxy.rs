@@ -0,0 +1,242 @@
+//! The bioauth proxy - a lightweight RPC server that exposes only the bioauth RPC methods,
+//! guarded by the pairing tokens.
+//!
+//! It allows authenticating from a handheld device without exposing the full node RPC.
+//!
+//! Every pairing token is valid for a single connection: the web app opens a WS connection
+//! with the token from the QR Code, and the token is redeemed at the connection handshake.
+//! The rest of the session goes over the same connection, and a new QR Code (and token) is
+//! needed to connect again.
+//!
+//! The proxy does not terminate TLS itself: the RPC server we build on has no TLS support,
+//! and the handheld device can only trust a certificate from a public CA anyway, which
+//! the node has no way to obtain for its address. So the TLS has to be terminated in front
+//! of the proxy (for instance, by an ngrok tunnel) for the web app to connect via WSS,
+//! and the proxy should not be reachable from the outside otherwise.
+
+use std::{
+    future::Future,
+    net::SocketAddr,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use jsonrpsee::server::{ServerBuilder, ServerHandle};
+use rand::distributions::{Alphanumeric, DistString};
+use url::Url;
+
+/// The URL query parameter to pass the pairing token with.
+pub const PAIRING_TOKEN_QUERY_PARAM: &str = "token";
+
+/// The length of the pairing token.
+const PAIRING_TOKEN_LENGTH: usize = 32;
+
+/// The registry of the pairing tokens.
+///
+/// Only the most recently issued token is valid, so issuing a new token for a new QR Code
+/// revokes the previous one. The token is valid for a single use only.
+#[derive(Debug, Default)]
+pub struct PairingTokens {
+    /// The currently valid token, if it was not used yet.
+    current: Mutex<Option<String>>,
+}
+
+impl PairingTokens {
+    /// Issue a new pairing token, revoking the previous one.
+    pub fn issue(&self) -> String {
+        let token = Alphanumeric.sample_string(&mut rand::thread_rng(), PAIRING_TOKEN_LENGTH);
+        *self.current.lock().expect("mutex poisoned") = Some(token.clone());
+        token
+    }
+
+    /// Redeem the provided pairing token, returning whether it was valid.
+    ///
+    /// A valid token is revoked, so it can not be used again.
+    pub fn redeem(&self, token: &str) -> bool {
+        let mut current = self.current.lock().expect("mutex poisoned");
+        let is_valid = match current.as_deref() {
+            Some(current) => constant_time_eq(current.as_bytes(), token.as_bytes()),
+            None => false,
+        };
+        if is_valid {
+            *current = None;
+        }
+        is_valid
+    }
+}
+
+/// Compare the byte strings in the time that does not depend on their contents, so that
+/// the token can not be guessed byte by byte from the response timings.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    // The length of the token is not a secret.
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+/// Add the pairing token to the bioauth proxy URL.
+pub fn pairing_url(proxy_url: &str, token: &str) -> Result<String, url::ParseError> {
+    let mut url = Url::parse(proxy_url)?;
+    url.query_pairs_mut()
+        .append_pair(PAIRING_TOKEN_QUERY_PARAM, token);
+    Ok(url.into())
+}
+
+/// Start the bioauth proxy server with the provided RPC methods.
+pub async fn start(
+    listen_addr: SocketAddr,
+    pairing_tokens: Arc<PairingTokens>,
+    methods: impl Into<jsonrpsee::Methods>,
+) -> Result<ServerHandle, jsonrpsee::core::Error> {
+    let middleware = tower::ServiceBuilder::new().layer(PairingTokenLayer { pairing_tokens });
+    let server = ServerBuilder::default()
+        .set_middleware(middleware)
+        .build(listen_addr)
+        .await?;
+    server.start(methods)
+}
+
+/// The middleware layer that rejects the requests without a valid pairing token.
+#[derive(Debug, Clone)]
+struct PairingTokenLayer {
+    /// The pairing tokens to check against.
+    pairing_tokens: Arc<PairingTokens>,
+}
+
+impl<S> tower::Layer<S> for PairingTokenLayer {
+    type Service = PairingTokenService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        PairingTokenService {
+            inner,
+            pairing_tokens: Arc::clone(&self.pairing_tokens),
+        }
+    }
+}
+
+/// The middleware service that rejects the requests without a valid pairing token.
+#[derive(Debug, Clone)]
+struct PairingTokenService<S> {
+    /// The inner service.
+    inner: S,
+    /// The pairing tokens to check against.
+    pairing_tokens: Arc<PairingTokens>,
+}
+
+impl<S> tower::Service<hyper::Request<hyper::Body>> for PairingTokenService<S>
+where
+    S: tower::Service<hyper::Request<hyper::Body>, Response = hyper::Response<hyper::Body>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: hyper::Request<hyper::Body>) -> Self::Future {
+        if !redeem_pairing_token(&request, &self.pairing_tokens) {
+            let response = hyper::Response::builder()
+                .status(hyper::StatusCode::UNAUTHORIZED)
+                .body(hyper::Body::from("invalid pairing token"))
+                .expect("static response is valid");
+            return Box::pin(futures::future::ready(Ok(response)));
+        }
+
+        Box::pin(self.inner.call(request))
+    }
+}
+
+/// Redeem the pairing token the request carries, returning whether it was valid.
+fn redeem_pairing_token<B>(request: &hyper::Request<B>, pairing_tokens: &PairingTokens) -> bool {
+    let query = match request.uri().query() {
+        Some(query) => query,
+        None => return false,
+    };
+
+    url::form_urlencoded::parse(query.as_bytes())
+        .any(|(key, value)| key == PAIRING_TOKEN_QUERY_PARAM && pairing_tokens.redeem(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(uri: &str) -> hyper::Request<()> {
+        hyper::Request::builder().uri(uri).body(()).unwrap()
+    }
+
+    #[test]
+    fn pairing_url_construction() {
+        let url = pairing_url("wss://example.com", "abc").unwrap();
+        assert_eq!(url, "wss://example.com/?token=abc");
+    }
+
+    #[test]
+    fn only_the_latest_token_is_valid() {
+        let pairing_tokens = PairingTokens::default();
+
+        let first = pairing_tokens.issue();
+        let second = pairing_tokens.issue();
+
+        assert!(!pairing_tokens.redeem(&first));
+        assert!(pairing_tokens.redeem(&second));
+    }
+
+    #[test]
+    fn tokens_are_single_use() {
+        let pairing_tokens = PairingTokens::default();
+        let token = pairing_tokens.issue();
+
+        assert!(pairing_tokens.redeem(&token));
+        assert!(!pairing_tokens.redeem(&token));
+    }
+
+    #[test]
+    fn invalid_token_does_not_revoke_the_valid_one() {
+        let pairing_tokens = PairingTokens::default();
+        let token = pairing_tokens.issue();
+
+        assert!(!pairing_tokens.redeem("invalid"));
+        assert!(!pairing_tokens.redeem(&token[1..]));
+        assert!(pairing_tokens.redeem(&token));
+    }
+
+    #[test]
+    fn requests_are_checked_for_the_token() {
+        let pairing_tokens = PairingTokens::default();
+        let token = pairing_tokens.issue();
+
+        assert!(!redeem_pairing_token(
+            &request("/?token=invalid"),
+            &pairing_tokens
+        ));
+        assert!(!redeem_pairing_token(&request("/"), &pairing_tokens));
+        assert!(redeem_pairing_token(
+            &request(&format!("/?token={token}")),
+            &pairing_tokens
+        ));
+        assert!(!redeem_pairing_token(
+            &request(&format!("/?token={token}")),
+            &pairing_tokens
+        ));
+    }
+
+    #[test]
+    fn no_tokens_are_valid_before_issuing() {
+        let pairing_tokens = PairingTokens::default();
+        assert!(!pairing_tokens.redeem(""));
+    }
+
+    #[test]
+    fn constant_time_comparison() {
+        assert!(constant_time_eq(b"abc", b"abc"));
+        assert!(constant_time_eq(b"", b""));
+        assert!(!constant_time_eq(b"abc", b"abd"));
+        assert!(!constant_time_eq(b"abc", b"ab"));
+    }
+}
@@ -2,11 +2,12 @@
 
 use sc_chain_spec::get_extension;
 
-use super::{params, BioauthFlowParams, RpcUrlSchemePreference};
+use super::{params, BioauthFlowParams, BioauthKeySelectorStrategy, RpcUrlSchemePreference};
 use crate::{
     chain_spec::Extensions,
     configuration::{self, Configuration},
     rpc_url::RpcUrl,
+    service::KeystoreBioauthId,
     time_warp::{current_timestamp, TimeWarp, DEFAULT_WARP_FACTOR},
 };
 
@@ -42,6 +43,17 @@ pub trait CliConfigurationExt: SubstrateCliConfigurationProvider {
                     .unwrap_or_else(|| "http://127.0.0.1:3033".into()),
                 webapp_url: params.webapp_url.clone().or(extensions.webapp_url),
                 rpc_url,
+                expiry_webhook_command: params.bioauth_expiry_webhook_command.clone(),
+                key_selector: key_selector_from_params(params),
+                proxy: params.bioauth_proxy_listen_addr.map(|listen_addr| {
+                    configuration::BioauthProxy {
+                        listen_addr,
+                        public_url: params
+                            .bioauth_proxy_url
+                            .clone()
+                            .unwrap_or_else(|| format!("ws://localhost:{}", listen_addr.port())),
+                    }
+                }),
             }
         });
 
@@ -123,6 +135,19 @@ impl<T: sc_cli::CliConfiguration> SubstrateCliConfigurationProvider for T {
     }
 }
 
+/// Construct the bioauth key selector from the params.
+fn key_selector_from_params(
+    params: &BioauthFlowParams,
+) -> bioauth_keys::StrategySelector<KeystoreBioauthId> {
+    if let Some(key) = &params.bioauth_key {
+        return bioauth_keys::StrategySelector::Explicit(key.clone());
+    }
+    match params.bioauth_key_selector {
+        BioauthKeySelectorStrategy::First => bioauth_keys::StrategySelector::First,
+        BioauthKeySelectorStrategy::ErrorOnMany => bioauth_keys::StrategySelector::OneOfOne,
+    }
+}
+
 /// Construct an RPC URL from the bioauth flow params and an RPC endpoint port.
 fn rpc_url_from_params(
     params: &BioauthFlowParams,
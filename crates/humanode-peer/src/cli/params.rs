@@ -1,6 +1,6 @@
 //! Shared CLI parameters.
 
-use crate::configuration::FrontierBackendType;
+use crate::{configuration::FrontierBackendType, service::KeystoreBioauthId};
 
 /// Possible RPC URL scheme preference options.
 #[derive(Debug, clap::ValueEnum, Clone)]
@@ -13,6 +13,15 @@ pub enum RpcUrlSchemePreference {
     NoPreference,
 }
 
+/// Possible bioauth key selection strategies.
+#[derive(Debug, clap::ValueEnum, Clone)]
+pub enum BioauthKeySelectorStrategy {
+    /// Use the first bioauth key found in the keystore.
+    First,
+    /// Expect no more than one bioauth key in the keystore, and error if there are more.
+    ErrorOnMany,
+}
+
 /// Shared CLI parameters used to configure bioauth flow.
 #[derive(Debug, clap::Parser, Clone)]
 pub struct BioauthFlowParams {
@@ -51,6 +60,47 @@ pub struct BioauthFlowParams {
     /// The URL of robonode to authenticate with.
     #[arg(long, value_name = "ROBONODE_URL")]
     pub robonode_url: Option<String>,
+
+    /// The shell command to run when the authentication of this node is about to expire.
+    /// The expiring public key is passed to the command via the `BIOAUTH_PUBLIC_KEY` env var.
+    #[arg(long, value_name = "COMMAND")]
+    pub bioauth_expiry_webhook_command: Option<String>,
+
+    /// The strategy to select the bioauth key with, if there are multiple bioauth keys in
+    /// the keystore.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "STRATEGY",
+        default_value = "error-on-many",
+        conflicts_with = "bioauth_key"
+    )]
+    pub bioauth_key_selector: BioauthKeySelectorStrategy,
+
+    /// The SS58 public key of the bioauth key to use explicitly, if there are multiple bioauth
+    /// keys in the keystore.
+    #[arg(long, value_name = "PUBLIC_KEY", value_parser = parse_bioauth_key)]
+    pub bioauth_key: Option<KeystoreBioauthId>,
+
+    /// Start the bioauth proxy at a given address.
+    /// The bioauth proxy is a lightweight RPC server that only exposes the bioauth methods,
+    /// guarded by the single-use pairing tokens passed to the web app via the QR Code.
+    /// The proxy serves plain WS and does not terminate TLS, so it should only listen on
+    /// a local address, with the TLS-terminating tunnel in front of it.
+    #[arg(long, value_name = "LISTEN_ADDR")]
+    pub bioauth_proxy_listen_addr: Option<std::net::SocketAddr>,
+
+    /// The URL to pass to the web app to connect to the bioauth proxy, typically the WSS URL
+    /// of the TLS-terminating tunnel in front of the proxy.
+    /// If not passed, a WS URL with `localhost` and the bioauth proxy port will be used.
+    #[arg(long, value_name = "URL", requires = "bioauth_proxy_listen_addr")]
+    pub bioauth_proxy_url: Option<String>,
+}
+
+/// Parse the bioauth public key from the SS58 representation.
+fn parse_bioauth_key(val: &str) -> Result<KeystoreBioauthId, String> {
+    use sp_core::crypto::Ss58Codec;
+    KeystoreBioauthId::from_ss58check(val).map_err(|err| format!("invalid public key: {err}"))
 }
 
 /// Shared CLI parameters used to configure Ethereum RPC.
@@ -1,6 +1,6 @@
 //! Humanode peer configuration.
 
-use std::borrow::Cow;
+use std::{borrow::Cow, net::SocketAddr};
 
 use crate::{
     rpc_url::{RpcUrl, RpcUrlResolver},
@@ -47,17 +47,40 @@ pub struct BioauthFlow {
 
     /// The URL of robonode to authenticate with.
     pub robonode_url: String,
+
+    /// The shell command to run when the authentication of this node is about to expire.
+    pub expiry_webhook_command: Option<String>,
+
+    /// The selector of the bioauth key to use, if there are multiple keys in the keystore.
+    pub key_selector: bioauth_keys::StrategySelector<crate::service::KeystoreBioauthId>,
+
+    /// The bioauth proxy configuration.
+    /// If not defined, the bioauth proxy isn't started.
+    pub proxy: Option<BioauthProxy>,
 }
 
 impl BioauthFlow {
     /// Obtain QR Code URL params.
     pub async fn qrcode_params(&self) -> Result<(&str, Cow<'_, str>), Cow<'static, str>> {
         let webapp_url = self.webapp_url.as_deref().ok_or("webapp URL is not set")?;
-        let rpc_url = self.rpc_url_resolver.resolve(&self.rpc_url).await?;
+        let rpc_url = match self.proxy {
+            // When the bioauth proxy is used, the web app connects to it instead of the node RPC.
+            Some(ref proxy) => Cow::Borrowed(proxy.public_url.as_str()),
+            None => self.rpc_url_resolver.resolve(&self.rpc_url).await?,
+        };
         Ok((webapp_url, rpc_url))
     }
 }
 
+/// Bioauth proxy configuration parameters.
+pub struct BioauthProxy {
+    /// The address to listen at.
+    pub listen_addr: SocketAddr,
+
+    /// The URL to pass to the web app to connect to the bioauth proxy.
+    pub public_url: String,
+}
+
 /// Ethereum RPC configuration parameters.
 pub struct EthereumRpc {
     /// Maximum number of blocks to keep the log information available
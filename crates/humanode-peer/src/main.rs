@@ -3,6 +3,7 @@
 mod api_versions;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+mod bioauth_proxy;
 mod build_info;
 mod chain_spec;
 mod cli;
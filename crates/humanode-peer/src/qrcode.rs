@@ -1,10 +1,12 @@
 //! QR Code generation.
 
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc};
 
 use tracing::{error, info};
 use url::Url;
 
+use crate::bioauth_proxy::{self, PairingTokens};
+
 /// The information necessary for printing the Web App QR Code.
 pub struct WebApp {
     /// The Web App URL.
@@ -38,6 +40,52 @@ impl WebApp {
     }
 }
 
+/// Makes the Web App QR Codes, issuing a new pairing token for each of them if the bioauth
+/// proxy is used.
+pub struct WebAppFactory {
+    /// The Web App URL.
+    webapp_url: String,
+    /// The RPC URL to pass to the Web App.
+    rpc_url: String,
+    /// The bioauth proxy pairing tokens, if the bioauth proxy is used.
+    pairing_tokens: Option<Arc<PairingTokens>>,
+}
+
+impl WebAppFactory {
+    /// Create a new [`WebAppFactory`].
+    pub fn new(
+        webapp_url: impl Into<String>,
+        rpc_url: impl Into<String>,
+        pairing_tokens: Option<Arc<PairingTokens>>,
+    ) -> Self {
+        Self {
+            webapp_url: webapp_url.into(),
+            rpc_url: rpc_url.into(),
+            pairing_tokens,
+        }
+    }
+
+    /// Make a new [`WebApp`].
+    pub fn make(&self) -> Result<WebApp, Cow<'static, str>> {
+        let rpc_url = match self.pairing_tokens {
+            Some(ref pairing_tokens) => {
+                bioauth_proxy::pairing_url(&self.rpc_url, &pairing_tokens.issue())
+                    .map_err(|err| err.to_string())?
+            }
+            None => self.rpc_url.clone(),
+        };
+        WebApp::new(&self.webapp_url, rpc_url)
+    }
+
+    /// Make a new [`WebApp`] and print its QR Code to the terminal.
+    pub fn print(&self) {
+        match self.make() {
+            Ok(qrcode) => qrcode.print(),
+            Err(err) => error!("Bioauth flow - unable to display QR Code: {}", err),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
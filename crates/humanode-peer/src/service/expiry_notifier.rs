@@ -0,0 +1,141 @@
+//! The notifier about the upcoming expiration of the authentication of this node.
+
+use std::sync::Arc;
+
+use bioauth_keys::traits::KeyExtractor;
+use codec::Decode;
+use futures::StreamExt;
+use humanode_runtime::{opaque::Block, AccountId, Hash, Runtime, RuntimeEvent};
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sp_core::storage::StorageKey;
+use tracing::*;
+
+use super::{FullBackend, FullClient, KeystoreBioauthId};
+
+/// The event record type of our runtime.
+type EventRecord = frame_system::EventRecord<RuntimeEvent, Hash>;
+
+/// The expiry notifier parameters.
+pub struct Params<ValidatorKeyExtractor> {
+    /// The client to get the finalized blocks and their events from.
+    pub client: Arc<FullClient>,
+    /// The extractor of the bioauth key of this node.
+    pub validator_key_extractor: ValidatorKeyExtractor,
+    /// The Web App QR Code factory to print a new QR Code with, so that the node can be
+    /// re-authenticated.
+    pub webapp_qrcode: Option<crate::qrcode::WebAppFactory>,
+    /// The shell command to run when the authentication is about to expire.
+    pub webhook_command: Option<String>,
+}
+
+/// Watch the finalized blocks for the `AuthenticationsExpiringSoon` event, and notify the node
+/// operator if the authentication of this node is about to expire.
+pub async fn run<ValidatorKeyExtractor>(params: Params<ValidatorKeyExtractor>)
+where
+    ValidatorKeyExtractor: KeyExtractor<PublicKeyType = KeystoreBioauthId>,
+    ValidatorKeyExtractor::Error: std::fmt::Display,
+{
+    let Params {
+        client,
+        validator_key_extractor,
+        webapp_qrcode,
+        webhook_command,
+    } = params;
+
+    let events_key = StorageKey(frame_system::Events::<Runtime>::hashed_key().to_vec());
+
+    let mut finality_notifications = client.finality_notification_stream();
+    while let Some(notification) = finality_notifications.next().await {
+        let public_key = match validator_key_extractor.extract_key() {
+            Ok(Some(public_key)) => public_key,
+            Ok(None) => continue,
+            Err(err) => {
+                warn!(message = "Bioauth expiry notifier - unable to extract the validator key", %err);
+                continue;
+            }
+        };
+
+        let finalized_hashes = notification
+            .tree_route
+            .iter()
+            .chain(std::iter::once(&notification.hash));
+
+        for hash in finalized_hashes {
+            let expiring = expiring_authentications(&client, &events_key, *hash);
+            let own_key = match expiring
+                .into_iter()
+                .find(|account_id| AsRef::<[u8]>::as_ref(account_id) == public_key.as_ref())
+            {
+                Some(own_key) => own_key,
+                None => continue,
+            };
+
+            warn!(
+                "Bioauth flow - the authentication of {} is about to expire, please re-authenticate",
+                own_key
+            );
+            if let Some(ref qrcode) = webapp_qrcode {
+                qrcode.print();
+            }
+            if let Some(ref command) = webhook_command {
+                run_webhook(command, &own_key).await;
+            }
+        }
+    }
+}
+
+/// Obtain the public keys listed in the `AuthenticationsExpiringSoon` events of a given block.
+fn expiring_authentications(
+    client: &FullClient,
+    events_key: &StorageKey,
+    hash: Hash,
+) -> Vec<AccountId> {
+    let data = match StorageProvider::<Block, FullBackend>::storage(client, hash, events_key) {
+        Ok(Some(data)) => data,
+        Ok(None) => return Vec::new(),
+        Err(err) => {
+            warn!(message = "Bioauth expiry notifier - unable to read the block events", %err);
+            return Vec::new();
+        }
+    };
+
+    // The events might not be decodable with the native runtime after a runtime upgrade.
+    let records = match Vec::<EventRecord>::decode(&mut data.0.as_slice()) {
+        Ok(records) => records,
+        Err(err) => {
+            debug!(message = "Bioauth expiry notifier - unable to decode the block events", %err);
+            return Vec::new();
+        }
+    };
+
+    records
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::Bioauth(pallet_bioauth::Event::AuthenticationsExpiringSoon {
+                expiring,
+            }) => Some(expiring),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+/// Run the webhook command, passing the expiring public key via the `BIOAUTH_PUBLIC_KEY` env var.
+async fn run_webhook(command: &str, public_key: &AccountId) {
+    let result = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .env("BIOAUTH_PUBLIC_KEY", public_key.to_string())
+        .status()
+        .await;
+
+    match result {
+        Ok(status) if status.success() => {}
+        Ok(status) => {
+            warn!(message = "Bioauth expiry notifier - webhook command failed", %status);
+        }
+        Err(err) => {
+            error!(message = "Bioauth expiry notifier - unable to run the webhook command", %err);
+        }
+    }
+}
@@ -11,7 +11,7 @@ use fc_consensus::FrontierBlockImport;
 use fc_rpc::EthTask;
 use fc_rpc_core::types::{FeeHistoryCache, FilterPool};
 use futures::StreamExt;
-use humanode_runtime::{self, opaque::Block, RuntimeApi};
+use humanode_runtime::{self, opaque::Block, RuntimeApi, UnixMilliseconds};
 use sc_client_api::{BlockBackend, BlockchainEvents};
 use sc_consensus_babe::SlotProportion;
 use sc_consensus_grandpa::SharedVoterState;
@@ -24,6 +24,7 @@ use tracing::*;
 
 use crate::configuration::Configuration;
 
+pub mod expiry_notifier;
 pub mod frontier;
 pub mod inherents;
 
@@ -299,7 +300,7 @@ pub async fn new_full(config: Configuration) -> Result<TaskManager, ServiceError
     let account_validator_key_extractor =
         Arc::new(bioauth_keys::KeyExtractor::<KeystoreBioauthId, _>::new(
             keystore_container.sync_keystore(),
-            bioauth_keys::OneOfOneSelector,
+            bioauth_flow_config.key_selector.clone(),
         ));
 
     let (network, system_rpc_tx, tx_handler_controller, network_starter, sync_service) =
@@ -327,21 +328,23 @@ pub async fn new_full(config: Configuration) -> Result<TaskManager, ServiceError
         reqwest: reqwest::Client::new(),
     });
 
+    let bioauth_validator_signer_factory = {
+        let keystore = keystore_container.keystore();
+        Arc::new(move |key| {
+            crate::validator_key::AppCryptoSigner::new(
+                Arc::clone(&keystore),
+                crate::validator_key::AppCryptoPublic(key),
+            )
+        })
+    };
+
     let rpc_extensions_builder = {
         let client = Arc::clone(&client);
         let pool = Arc::clone(&transaction_pool);
         let robonode_client = Arc::clone(&robonode_client);
         let is_authority = role.is_authority();
         let bioauth_validator_key_extractor = Arc::clone(&account_validator_key_extractor);
-        let bioauth_validator_signer_factory = {
-            let keystore = keystore_container.keystore();
-            Arc::new(move |key| {
-                crate::validator_key::AppCryptoSigner::new(
-                    Arc::clone(&keystore),
-                    crate::validator_key::AppCryptoPublic(key),
-                )
-            })
-        };
+        let bioauth_validator_signer_factory = Arc::clone(&bioauth_validator_signer_factory);
         let network = Arc::clone(&network);
         let sync_service = Arc::clone(&sync_service);
 
@@ -394,6 +397,7 @@ pub async fn new_full(config: Configuration) -> Result<TaskManager, ServiceError
                 chain_spec: chain_spec.cloned_box(),
                 author_ext: humanode_rpc::AuthorExtDeps {
                     author_validator_key_extractor: Arc::clone(&bioauth_validator_key_extractor),
+                    keystore: Arc::clone(&keystore),
                 },
                 is_authority,
                 bioauth: humanode_rpc::BioauthDeps {
@@ -585,18 +589,79 @@ pub async fn new_full(config: Configuration) -> Result<TaskManager, ServiceError
 
     network_starter.start_network();
 
-    let webapp_qrcode = bioauth_flow_config
-        .qrcode_params()
-        .await
-        .and_then(|(webapp_url, rpc_url)| crate::qrcode::WebApp::new(webapp_url, rpc_url));
+    let bioauth_proxy_pairing_tokens = match bioauth_flow_config.proxy {
+        Some(ref proxy_config) => {
+            let pairing_tokens = Arc::new(crate::bioauth_proxy::PairingTokens::default());
+
+            // The bioauth proxy only exposes the bioauth methods, so the unsafe calls are allowed.
+            let bioauth_rpc =
+                bioauth_flow_rpc::Bioauth::<_, _, _, _, Block, UnixMilliseconds, _>::new(
+                    Arc::clone(&robonode_client),
+                    Arc::clone(&account_validator_key_extractor),
+                    Arc::clone(&bioauth_validator_signer_factory),
+                    Arc::clone(&client),
+                    Arc::clone(&transaction_pool),
+                    Arc::new(task_manager.spawn_handle()),
+                    rpc_deny_unsafe::DenyUnsafe::No,
+                );
+
+            let server_handle = crate::bioauth_proxy::start(
+                proxy_config.listen_addr,
+                Arc::clone(&pairing_tokens),
+                bioauth_flow_rpc::BioauthServer::into_rpc(bioauth_rpc),
+            )
+            .await
+            .map_err(|err| {
+                ServiceError::Other(format!("unable to start the bioauth proxy: {err}"))
+            })?;
+
+            info!("Bioauth proxy is listening at {}", proxy_config.listen_addr);
+            if !proxy_config.listen_addr.ip().is_loopback() {
+                warn!(
+                    "Bioauth proxy is listening at a non-local address {} without TLS, \
+                    make sure it is only reachable via a TLS-terminating tunnel",
+                    proxy_config.listen_addr
+                );
+            }
+
+            task_manager.spawn_handle().spawn(
+                "bioauth-proxy",
+                Some("bioauth"),
+                server_handle.stopped(),
+            );
 
-    match &webapp_qrcode {
-        Ok(ref qrcode) => qrcode.print(),
-        Err(ref err) => {
+            Some(pairing_tokens)
+        }
+        None => None,
+    };
+
+    let webapp_qrcode = match bioauth_flow_config.qrcode_params().await {
+        Ok((webapp_url, rpc_url)) => Some(crate::qrcode::WebAppFactory::new(
+            webapp_url,
+            rpc_url,
+            bioauth_proxy_pairing_tokens,
+        )),
+        Err(err) => {
             error!("Bioauth flow - unable to display QR Code: {}", err);
+            None
         }
     };
 
+    if let Some(ref webapp_qrcode) = webapp_qrcode {
+        webapp_qrcode.print();
+    }
+
+    task_manager.spawn_handle().spawn(
+        "bioauth-expiry-notifier",
+        Some("bioauth"),
+        expiry_notifier::run(expiry_notifier::Params {
+            client: Arc::clone(&client),
+            validator_key_extractor: account_validator_key_extractor,
+            webapp_qrcode,
+            webhook_command: bioauth_flow_config.expiry_webhook_command.clone(),
+        }),
+    );
+
     Ok(task_manager)
 }
 
@@ -34,7 +34,7 @@ pub use sc_rpc_api::DenyUnsafe;
 use sc_rpc_spec_v2::chain_spec::{ChainSpec, ChainSpecApiServer};
 use sc_transaction_pool::{ChainApi, Pool};
 use sc_transaction_pool_api::TransactionPool;
-use sp_api::{CallApiAt, Encode, ProvideRuntimeApi};
+use sp_api::{CallApiAt, Decode, Encode, ProvideRuntimeApi};
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 use sp_consensus::SelectChain;
@@ -46,6 +46,8 @@ use sp_keystore::SyncCryptoStorePtr;
 pub struct AuthorExtDeps<VKE> {
     /// Extracts the currently used author validator key.
     pub author_validator_key_extractor: VKE,
+    /// The keystore that manages the keys of the node.
+    pub keystore: SyncCryptoStorePtr,
 }
 
 /// Extra dependencies for Bioauth.
@@ -169,13 +171,22 @@ where
     C::Api: BabeApi<Block>,
     C::Api: BlockBuilder<Block>,
     C::Api: AuthorExtApi<Block, VKE::PublicKeyType>,
+    C::Api: sp_session::SessionKeys<Block>,
     C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
     C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>,
     P: TransactionPool<Block = Block> + 'static,
-    VKE: KeyExtractorT + Send + Sync + 'static,
-    VKE::PublicKeyType: Encode + AsRef<[u8]> + Clone + Send + Sync + sp_runtime::Serialize,
+    VKE: KeyExtractorT + Clone + Send + Sync + 'static,
+    VKE::PublicKeyType: Encode
+        + Decode
+        + AsRef<[u8]>
+        + Clone
+        + PartialEq
+        + Send
+        + Sync
+        + sp_runtime::Serialize
+        + sp_runtime::DeserializeOwned,
     VKE::Error: std::fmt::Debug,
-    VSF: SignerFactory<Vec<u8>, VKE::PublicKeyType> + Send + Sync + 'static,
+    VSF: SignerFactory<Vec<u8>, VKE::PublicKeyType> + Clone + Send + Sync + 'static,
     VSF::Signer: Send + Sync + 'static,
     <<VSF as SignerFactory<Vec<u8>, VKE::PublicKeyType>>::Signer as Signer<Vec<u8>>>::Error:
         std::error::Error + 'static,
@@ -208,6 +219,7 @@ where
 
     let AuthorExtDeps {
         author_validator_key_extractor,
+        keystore: author_ext_keystore,
     } = author_ext;
 
     let BioauthDeps {
@@ -283,6 +295,7 @@ where
         AuthorExt::new(
             author_validator_key_extractor,
             Arc::clone(&client),
+            author_ext_keystore,
             Arc::clone(&pool),
             deny_unsafe,
         )
@@ -296,6 +309,7 @@ where
             bioauth_validator_signer_factory,
             Arc::clone(&client),
             Arc::clone(&pool),
+            Arc::clone(&subscription_task_executor),
             deny_unsafe,
         )
         .into_rpc(),
@@ -0,0 +1,90 @@
+//! The author extension logic, exposed via the runtime api.
+
+use codec::{alloc::string::ToString, Decode, Encode};
+use keystore_bioauth_account_id::KeystoreBioauthAccountId;
+use sp_runtime::traits::{Block as BlockT, StaticLookup};
+use sp_std::prelude::*;
+
+use crate::{utils, AccountId, Block, Runtime, RuntimeCall, SignedExtra, SignedPayload, System};
+
+/// Convert the keystore bioauth account id into the account id.
+fn account_id(id: &KeystoreBioauthAccountId) -> AccountId {
+    AccountId::new(
+        <KeystoreBioauthAccountId as sp_application_crypto::AppKey>::UntypedGeneric::from(
+            id.clone(),
+        )
+        .0,
+    )
+}
+
+/// Create the `set_keys` signing payload for the given session keys, to be signed outside
+/// of the node with the given validator key.
+pub fn create_set_keys_signing_payload(
+    id: &KeystoreBioauthAccountId,
+    session_keys: Vec<u8>,
+) -> Result<author_ext_api::SetKeysSigningPayload, author_ext_api::CreateSignedSetKeysExtrinsicError>
+{
+    let account_id = account_id(id);
+
+    let keys = <Runtime as pallet_session::Config>::Keys::decode(&mut session_keys.as_slice())
+        .map_err(|err| {
+            author_ext_api::CreateSignedSetKeysExtrinsicError::SessionKeysDecoding(err.to_string())
+        })?;
+    let session_call = pallet_session::Call::set_keys::<Runtime> {
+        keys,
+        proof: vec![],
+    };
+
+    let era = utils::current_era::<Runtime>();
+    let extra = utils::create_extra::<Runtime>(System::account_nonce(account_id), era, 0);
+    let raw_payload = SignedPayload::new(session_call.into(), extra)
+        .map_err(|_| author_ext_api::CreateSignedSetKeysExtrinsicError::SignedExtrinsicCreation)?;
+
+    let payload = raw_payload.using_encoded(|payload| payload.to_vec());
+    let (call, extra, _) = raw_payload.deconstruct();
+
+    Ok(author_ext_api::SetKeysSigningPayload {
+        unsigned_extrinsic: (call, extra).encode(),
+        payload,
+    })
+}
+
+/// Create the `set_keys` extrinsic from the unsigned extrinsic data and the signature made
+/// outside of the node with the given validator key.
+pub fn create_set_keys_extrinsic_with_signature(
+    id: &KeystoreBioauthAccountId,
+    unsigned_extrinsic: Vec<u8>,
+    signature: Vec<u8>,
+) -> Result<<Block as BlockT>::Extrinsic, author_ext_api::CreateSignedSetKeysExtrinsicError> {
+    let account_id = account_id(id);
+
+    let (call, extra) = <(RuntimeCall, SignedExtra)>::decode(&mut unsigned_extrinsic.as_slice())
+        .map_err(|err| {
+            author_ext_api::CreateSignedSetKeysExtrinsicError::InvalidUnsignedExtrinsic(
+                err.to_string(),
+            )
+        })?;
+
+    // Only allow the set_keys calls to be submitted this way.
+    if !matches!(
+        call,
+        RuntimeCall::Session(pallet_session::Call::set_keys { .. })
+    ) {
+        return Err(
+            author_ext_api::CreateSignedSetKeysExtrinsicError::InvalidUnsignedExtrinsic(
+                "the call is not a set_keys call".to_string(),
+            ),
+        );
+    }
+
+    let signature = sp_core::sr25519::Signature::try_from(signature.as_slice())
+        .map_err(|_| author_ext_api::CreateSignedSetKeysExtrinsicError::InvalidSignature)?;
+    let address = <Runtime as frame_system::Config>::Lookup::unlookup(account_id);
+
+    Ok(<Block as BlockT>::Extrinsic::new_signed(
+        call,
+        address,
+        signature.into(),
+        extra,
+    ))
+}
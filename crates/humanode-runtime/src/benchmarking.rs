@@ -178,6 +178,42 @@ impl pallet_vesting::benchmarking::Interface for Runtime {
     }
 }
 
+impl pallet_humanode_staking::benchmarking::Interface for Runtime {
+    fn authenticated_validator() -> <Self as frame_system::Config>::AccountId {
+        let validator = account_id("Bob");
+
+        // The authenticated validators are looked up in order, so put the validator last among
+        // the max amount of the active authentications.
+        let max_authentications = <Runtime as pallet_bioauth::Config>::MaxAuthentications::get();
+        let authentication = |public_key| pallet_bioauth::Authentication {
+            public_key,
+            expires_at: UnixMilliseconds::MAX,
+            authenticated_at: 0,
+            authenticated_at_block: 0,
+            robonode_public_key_id: Default::default(),
+        };
+        let active_authentications = (1..max_authentications)
+            .map(|index| authentication(frame_benchmarking::account("validator", index, 0)))
+            .chain(core::iter::once(authentication(validator.clone())))
+            .collect::<Vec<_>>();
+        pallet_bioauth::ActiveAuthentications::<Runtime>::put(
+            frame_support::BoundedVec::try_from(active_authentications).unwrap(),
+        );
+
+        validator
+    }
+}
+
+impl pallet_session_rewards::benchmarking::Interface for Runtime {
+    fn set_validators(validators: Vec<<Self as frame_system::Config>::AccountId>) {
+        pallet_session::Validators::<Runtime>::put(validators);
+    }
+
+    fn max_validators() -> u32 {
+        <Runtime as pallet_bioauth::Config>::MaxAuthentications::get()
+    }
+}
+
 impl pallet_vesting::benchmarking::SchedulingDriver for vesting::SchedulingDriver {
     type Data = ();
 
@@ -0,0 +1,41 @@
+//! The hooks we run around the bioauth.
+
+use frame_support::sp_runtime::DispatchError;
+
+use crate::{constants, Balances, BioauthId, BlockNumber, Runtime, UnixMilliseconds};
+
+/// The error of the authentication rejected by the [`RequireMinimumBalance`].
+pub const INSUFFICIENT_BALANCE: DispatchError =
+    DispatchError::Other("insufficient balance for the authentication");
+
+/// Requires the validator account to have a certain minimum usable balance before
+/// the authentication is accepted.
+pub struct RequireMinimumBalance;
+
+impl pallet_bioauth::BeforeAuthHook<BioauthId, UnixMilliseconds, BlockNumber>
+    for RequireMinimumBalance
+{
+    type Data = ();
+
+    fn hook(
+        authentication: &pallet_bioauth::Authentication<BioauthId, UnixMilliseconds, BlockNumber>,
+    ) -> Result<Self::Data, DispatchError> {
+        let usable_balance = Balances::usable_balance(&authentication.public_key);
+        if usable_balance < constants::bioauth::MIN_AUTHENTICATION_BALANCE {
+            return Err(INSUFFICIENT_BALANCE);
+        }
+        Ok(())
+    }
+}
+
+/// Determine the auth ticket verdict for the authentication rejected by the before auth hooks
+/// with the given error.
+pub fn rejection_verdict<Id>(error: DispatchError) -> bioauth_flow_api::AuthTicketVerdict<Id> {
+    if error == INSUFFICIENT_BALANCE {
+        return bioauth_flow_api::AuthTicketVerdict::InsufficientBalance;
+    }
+    if error == pallet_humanode_offences::Error::<Runtime>::Banned.into() {
+        return bioauth_flow_api::AuthTicketVerdict::Banned;
+    }
+    bioauth_flow_api::AuthTicketVerdict::Rejected
+}
@@ -34,11 +34,96 @@ pub mod timestamp {
 
 /// Bioath constants.
 pub mod bioauth {
-    use crate::UnixMilliseconds;
+    use crate::{Balance, UnixMilliseconds};
 
     pub const MAX_AUTHENTICATIONS: u32 = 3 * 1024;
     pub const MAX_NONCES: u32 = 10000 * MAX_AUTHENTICATIONS;
     pub const AUTHENTICATIONS_EXPIRE_AFTER: UnixMilliseconds = 7 * super::timestamp::TIMESTAMP_DAY;
+    /// The amount of time before the expiration at which we warn about the expiring
+    /// authentications.
+    pub const AUTHENTICATIONS_EXPIRY_WARNING_PERIOD: UnixMilliseconds =
+        super::timestamp::TIMESTAMP_DAY;
+    /// The minimum usable balance the validator account must hold to authenticate (1 HMND).
+    pub const MIN_AUTHENTICATION_BALANCE: Balance = 10u128.pow(18);
+}
+
+/// Humanode session constants.
+pub mod humanode_session {
+    /// The amount of the past sessions to keep the validator identities for, so that
+    /// the late-arriving offence reports can still be resolved.
+    ///
+    /// This is counted in sessions rather than time: it covers a day, as the sessions are tied to
+    /// the BABE epochs, which can not be shortened.
+    pub const SESSION_IDENTITIES_RETENTION: u32 = 6;
+    /// The minimum size of the session validator set, below which we keep the previous session
+    /// authorities rather than stall the consensus.
+    pub const MIN_VALIDATOR_SET_SIZE: u32 = 3;
+}
+
+/// Humanode offences constants.
+pub mod humanode_offences {
+    use crate::UnixMilliseconds;
+
+    /// The offence score points for being unresponsive during a session.
+    pub const UNRESPONSIVENESS_SEVERITY: u32 = 2;
+
+    /// The offence score points for an equivocation.
+    pub const EQUIVOCATION_SEVERITY: u32 = 6;
+
+    /// The offence score points for an offence of an unknown kind.
+    pub const UNKNOWN_OFFENCE_SEVERITY: u32 = 6;
+
+    /// The offence score points added on top for the full slash fraction, scaled down
+    /// proportionally for the partial slash fractions.
+    pub const FULL_SLASH_SEVERITY: u32 = 10;
+
+    /// The amount of the offence score points that decay every session.
+    pub const SCORE_DECAY_PER_SESSION: u32 = 1;
+
+    /// The offence score at which the validator is disabled for the rest of the session.
+    pub const DISABLE_THRESHOLD: u32 = 3;
+
+    /// The offence score at which the validator is deauthenticated.
+    pub const DEAUTHENTICATION_THRESHOLD: u32 = 6;
+
+    /// The offence score at which the validator is banned from authenticating.
+    pub const BAN_THRESHOLD: u32 = 15;
+
+    /// The duration of the ban on authenticating.
+    pub const BAN_DURATION: UnixMilliseconds = 30 * super::timestamp::TIMESTAMP_DAY;
+
+    /// The max amount of the latest offences kept in the offence log of a validator.
+    pub const MAX_OFFENCE_LOG_ENTRIES: u32 = 32;
+}
+
+/// Session rewards constants.
+pub mod session_rewards {
+    use sp_runtime::Perbill;
+
+    /// The share of the fees pot balance paid out to the validators at every session end.
+    pub const SESSION_REWARDS_SHARE: Perbill = Perbill::from_percent(10);
+}
+
+/// Humanode staking constants.
+pub mod humanode_staking {
+    use sp_runtime::Perbill;
+
+    use crate::{Balance, BlockNumber};
+
+    /// The max commission the validators can take of the session rewards.
+    pub const MAX_COMMISSION: Perbill = Perbill::from_percent(50);
+
+    /// The min amount that can be delegated to a validator (1 HMND).
+    pub const MIN_DELEGATION: Balance = 10u128.pow(18);
+
+    /// The max amount of the delegators of a single validator.
+    pub const MAX_DELEGATORS_PER_VALIDATOR: u32 = 256;
+
+    /// The max amount of the unbonding chunks of a single delegator.
+    pub const MAX_UNBONDING_CHUNKS: u32 = 32;
+
+    /// The amount of blocks the undelegated balance stays locked for.
+    pub const UNBONDING_PERIOD: BlockNumber = 7 * 24 * super::block_time::HOURS;
 }
 
 /// Babe constants.
@@ -32,6 +32,7 @@ pub use frame_support::{
     ConsensusEngineId, PalletId, StorageValue, WeakBoundedVec,
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::EnsureRoot;
 use keystore_bioauth_account_id::KeystoreBioauthAccountId;
 pub use pallet_balances::Call as BalancesCall;
 use pallet_bioauth::AuthTicket;
@@ -82,8 +83,10 @@ mod frontier_precompiles;
 mod vesting;
 use frontier_precompiles::{precompiles_constants, FrontierPrecompiles};
 
+mod author_ext;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+mod bioauth_hooks;
 pub mod constants;
 mod currency_swap;
 mod deauthentication_reason;
@@ -93,6 +96,7 @@ mod display_moment;
 pub mod eth_sig;
 mod find_author;
 mod fixed_supply;
+mod offence_severity;
 pub mod robonode;
 #[cfg(test)]
 mod tests;
@@ -102,11 +106,24 @@ pub mod utils;
 
 pub use constants::{
     babe::{BABE_GENESIS_EPOCH_CONFIG, EPOCH_DURATION_IN_SLOTS, MAX_AUTHORITIES, SLOT_DURATION},
-    bioauth::{AUTHENTICATIONS_EXPIRE_AFTER, MAX_AUTHENTICATIONS, MAX_NONCES},
+    bioauth::{
+        AUTHENTICATIONS_EXPIRE_AFTER, AUTHENTICATIONS_EXPIRY_WARNING_PERIOD, MAX_AUTHENTICATIONS,
+        MAX_NONCES,
+    },
     block_time::MILLISECS_PER_BLOCK,
     equivocation::REPORT_LONGEVITY,
     ethereum::EXTRA_DATA_LENGTH,
+    humanode_offences::{
+        BAN_DURATION, BAN_THRESHOLD, DEAUTHENTICATION_THRESHOLD, DISABLE_THRESHOLD,
+        MAX_OFFENCE_LOG_ENTRIES, SCORE_DECAY_PER_SESSION,
+    },
+    humanode_session::{MIN_VALIDATOR_SET_SIZE, SESSION_IDENTITIES_RETENTION},
+    humanode_staking::{
+        MAX_COMMISSION, MAX_DELEGATORS_PER_VALIDATOR, MAX_UNBONDING_CHUNKS, MIN_DELEGATION,
+        UNBONDING_PERIOD,
+    },
     im_online::{MAX_KEYS, MAX_PEER_DATA_ENCODING_SIZE, MAX_PEER_IN_HEARTBEATS},
+    session_rewards::SESSION_REWARDS_SHARE,
 };
 use deauthentication_reason::DeauthenticationReason;
 use static_assertions::const_assert;
@@ -219,10 +236,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 115,
+    spec_version: 116,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     state_version: 1,
 };
 
@@ -318,7 +335,7 @@ impl pallet_babe::Config for Runtime {
         <Historical as KeyOwnerProofSystem<(KeyTypeId, pallet_babe::AuthorityId)>>::Proof;
     type EquivocationReportSystem = pallet_babe::EquivocationReportSystem<
         Self,
-        Offences,
+        pallet_humanode_offences::NoteOffenceReport<Self, Offences>,
         Historical,
         ConstU64<REPORT_LONGEVITY>,
     >;
@@ -340,9 +357,16 @@ impl pallet_session::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type ValidatorId = BioauthId;
     type ValidatorIdOf = IdentityValidatorIdOf;
+    // The sessions can not be rotated early on bioauth changes: with
+    // the `pallet_babe::ExternalTrigger` every session rotation enacts a BABE epoch change, and
+    // the BABE epochs are fixed slot ranges that the nodes track on their own, so they can not
+    // be shortened.
     type ShouldEndSession = Babe;
     type NextSessionRotation = Babe;
-    type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, HumanodeSession>;
+    type SessionManager = pallet_session::historical::NoteHistoricalRoot<
+        Self,
+        pallet_session_rewards::RewardingSessionManager<Self, HumanodeSession>,
+    >;
     type SessionHandler = <opaque::SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
     type Keys = opaque::SessionKeys;
     type WeightInfo = pallet_session::weights::SubstrateWeight<Runtime>;
@@ -359,7 +383,7 @@ impl pallet_grandpa::Config for Runtime {
     type KeyOwnerProof = <Historical as KeyOwnerProofSystem<(KeyTypeId, GrandpaId)>>::Proof;
     type EquivocationReportSystem = pallet_grandpa::EquivocationReportSystem<
         Self,
-        Offences,
+        pallet_humanode_offences::NoteOffenceReport<Self, Offences>,
         Historical,
         ConstU64<REPORT_LONGEVITY>,
     >;
@@ -385,7 +409,7 @@ impl pallet_chain_start_moment::Config for Runtime {
 
 impl pallet_authorship::Config for Runtime {
     type FindAuthor = find_author::FindAuthorFromSession<find_author::FindAuthorBabe, BabeId>;
-    type EventHandler = (ImOnline,);
+    type EventHandler = (ImOnline, SessionRewards);
 }
 
 parameter_types! {
@@ -526,11 +550,16 @@ impl pallet_bioauth::Config for Runtime {
     type Moment = UnixMilliseconds;
     type DisplayMoment = display_moment::DisplayMoment;
     type CurrentMoment = CurrentMoment;
-    type AuthenticationsExpireAfter = ConstU64<AUTHENTICATIONS_EXPIRE_AFTER>;
+    type ExpiryPolicy =
+        pallet_bioauth::StoredExpiryPeriod<Runtime, ConstU64<AUTHENTICATIONS_EXPIRE_AFTER>>;
+    type ExpiryWarningPeriod = ConstU64<AUTHENTICATIONS_EXPIRY_WARNING_PERIOD>;
     type WeightInfo = weights::pallet_bioauth::WeightInfo<Runtime>;
     type MaxAuthentications = ConstU32<MAX_AUTHENTICATIONS>;
     type MaxNonces = ConstU32<MAX_NONCES>;
-    type BeforeAuthHook = ();
+    type BeforeAuthHook = (
+        bioauth_hooks::RequireMinimumBalance,
+        pallet_humanode_offences::RejectBanned<Runtime>,
+    );
     type AfterAuthHook = ();
     type DeauthenticationReason = DeauthenticationReason;
 }
@@ -541,6 +570,18 @@ impl pallet_bioauth::benchmarking::AuthTicketBuilder for Runtime {
         public_key: Vec<u8>,
         authentication_nonce: Vec<u8>,
     ) -> <Self as pallet_bioauth::Config>::OpaqueAuthTicket {
+        // Make sure the account passes the before auth hook.
+        let account_id = AccountId::new(
+            public_key
+                .as_slice()
+                .try_into()
+                .expect("benchmarks use 32 bytes public keys"),
+        );
+        let _ = <Balances as frame_support::traits::Currency<AccountId>>::deposit_creating(
+            &account_id,
+            constants::bioauth::MIN_AUTHENTICATION_BALANCE,
+        );
+
         OpaqueAuthTicket::from(&primitives_auth_ticket::AuthTicket {
             public_key,
             authentication_nonce,
@@ -549,15 +590,75 @@ impl pallet_bioauth::benchmarking::AuthTicketBuilder for Runtime {
 }
 
 impl pallet_bootnodes::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type BootnodeId = AccountId;
     type MaxBootnodes = ConstU32<16>;
+    type UpdateBootnodesOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = weights::pallet_bootnodes::WeightInfo<Runtime>;
 }
 
 impl pallet_humanode_session::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type ValidatorPublicKeyOf = IdentityValidatorIdOf;
     type BootnodeIdOf = sp_runtime::traits::Identity;
     type MaxBootnodeValidators = <Runtime as pallet_bootnodes::Config>::MaxBootnodes;
     type MaxBioauthValidators = <Runtime as pallet_bioauth::Config>::MaxAuthentications;
+    type ValidatorSelector = pallet_humanode_session::selector::RandomShuffle<
+        pallet_babe::RandomnessFromOneEpochAgo<Runtime>,
+        Hash,
+        BlockNumber,
+    >;
+    type SessionIdentitiesRetention = ConstU32<SESSION_IDENTITIES_RETENTION>;
+    type MinValidatorSetSize = ConstU32<MIN_VALIDATOR_SET_SIZE>;
+}
+
+parameter_types! {
+    pub FeesPotAccountId: AccountId = FeesPot::account_id();
+    pub const SessionRewardsShare: Perbill = SESSION_REWARDS_SHARE;
+}
+
+impl pallet_session_rewards::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type RewardsPotAccountId = FeesPotAccountId;
+    type ValidatorSet = Session;
+    type RewardFormula = pallet_session_rewards::formula::EqualShare<SessionRewardsShare>;
+    type RewardSplit = HumanodeStaking;
+    type WeightInfo = weights::pallet_session_rewards::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    pub StakingLockId: LockIdentifier = *b"hmnd/st1";
+    pub const MaxCommission: Perbill = MAX_COMMISSION;
+}
+
+impl pallet_humanode_staking::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type LockId = StakingLockId;
+    type AuthenticatedValidators =
+        pallet_humanode_staking::BioauthAuthenticated<Self, IdentityValidatorIdOf>;
+    type MaxCommission = MaxCommission;
+    type MinDelegation = ConstU128<MIN_DELEGATION>;
+    type MaxDelegatorsPerValidator = ConstU32<MAX_DELEGATORS_PER_VALIDATOR>;
+    type MaxUnbondingChunks = ConstU32<MAX_UNBONDING_CHUNKS>;
+    type UnbondingPeriod = ConstU32<UNBONDING_PERIOD>;
+    type RewardsPotAccountId = FeesPotAccountId;
+    type WeightInfo = weights::pallet_humanode_staking::WeightInfo<Runtime>;
+}
+
+impl pallet_humanode_offences::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorPublicKey = BioauthId;
+    type Moment = UnixMilliseconds;
+    type CurrentMoment = CurrentMoment;
+    type OffenceSeverity = offence_severity::OffenceSeverity;
+    type ScoreDecayPerSession = ConstU32<SCORE_DECAY_PER_SESSION>;
+    type DisableThreshold = ConstU32<DISABLE_THRESHOLD>;
+    type DeauthenticationThreshold = ConstU32<DEAUTHENTICATION_THRESHOLD>;
+    type BanThreshold = ConstU32<BAN_THRESHOLD>;
+    type BanDuration = ConstU64<BAN_DURATION>;
+    type MaxOffenceLogEntries = ConstU32<MAX_OFFENCE_LOG_ENTRIES>;
 }
 
 pub struct OffenceSlasher;
@@ -574,21 +675,55 @@ impl
             AccountId,
             <Runtime as pallet_offences::Config>::IdentificationTuple,
         >],
-        _slash_fraction: &[Perbill],
-        _session: sp_staking::SessionIndex,
+        slash_fraction: &[Perbill],
+        session: sp_staking::SessionIndex,
         disable_strategy: sp_staking::offence::DisableStrategy,
     ) -> Weight {
-        if disable_strategy == sp_staking::offence::DisableStrategy::Never {
-            return Weight::zero();
-        }
+        let allow_sanctions = disable_strategy != sp_staking::offence::DisableStrategy::Never;
+        let pending_report = HumanodeOffences::pending_report();
+        let kind = pending_report.as_ref().map(|report| report.kind);
+        let time_slot = pending_report
+            .as_ref()
+            .map(|report| report.time_slot.as_slice())
+            .unwrap_or_default();
+
         let mut weight: Weight = Weight::zero();
         let weights = <Runtime as frame_system::Config>::DbWeight::get();
         let mut should_be_deauthenticated = Vec::with_capacity(offenders.len());
-        for details in offenders {
-            let (_offender, identity) = &details.offender;
+        for (details, slash_fraction) in offenders.iter().zip(slash_fraction) {
+            let (offender, identity) = &details.offender;
+
+            // The concurrent offenders that were already reported for this offence have already
+            // been sanctioned.
+            if let Some(ref report) = pending_report {
+                if !report.offenders.contains(offender) {
+                    continue;
+                }
+            }
+
             match identity {
                 pallet_humanode_session::Identification::Bioauth(authentication) => {
-                    should_be_deauthenticated.push(authentication.public_key.clone());
+                    let sanction = HumanodeOffences::note_offence(
+                        authentication.public_key.clone(),
+                        kind,
+                        time_slot,
+                        session,
+                        *slash_fraction,
+                        allow_sanctions,
+                    );
+                    weight = weight.saturating_add(weights.reads_writes(4, 3));
+
+                    match sanction {
+                        pallet_humanode_offences::Sanction::Warning => {}
+                        pallet_humanode_offences::Sanction::Disable => {
+                            Session::disable(offender);
+                            weight = weight.saturating_add(weights.reads_writes(2, 1));
+                        }
+                        pallet_humanode_offences::Sanction::Deauthenticate
+                        | pallet_humanode_offences::Sanction::Ban { .. } => {
+                            should_be_deauthenticated.push(authentication.public_key.clone());
+                        }
+                    }
                 }
                 pallet_humanode_session::Identification::Bootnode(..) => {
                     // Never slash the bootnodes.
@@ -617,7 +752,10 @@ impl pallet_im_online::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type NextSessionRotation = Babe;
     type ValidatorSet = Historical;
-    type ReportUnresponsiveness = Offences;
+    type ReportUnresponsiveness = pallet_session_rewards::NoteUnresponsiveness<
+        Self,
+        pallet_humanode_offences::NoteOffenceReport<Self, Offences>,
+    >;
     type UnsignedPriority = ConstU64<{ TransactionPriority::MAX }>;
     type WeightInfo = weights::pallet_im_online::WeightInfo<Runtime>;
     type MaxKeys = ConstU32<MAX_KEYS>;
@@ -879,6 +1017,9 @@ construct_runtime!(
         BalancedCurrencySwapBridgesInitializer: pallet_balanced_currency_swap_bridges_initializer = 36,
         EvmBalancesErc20Support: pallet_erc20_support = 37,
         DummyPrecompilesCode: pallet_dummy_precompiles_code = 38,
+        SessionRewards: pallet_session_rewards = 39,
+        HumanodeOffences: pallet_humanode_offences = 40,
+        HumanodeStaking: pallet_humanode_staking = 41,
     }
 );
 
@@ -1045,10 +1186,13 @@ mod benches {
         [pallet_babe, Babe]
         [pallet_balances, Balances]
         [pallet_bioauth, Bioauth]
+        [pallet_bootnodes, Bootnodes]
         [pallet_evm_accounts_mapping, EvmAccountsMapping]
         [pallet_grandpa, Grandpa]
+        [pallet_humanode_staking, HumanodeStaking]
         [pallet_im_online, ImOnline]
         [pallet_multisig, Multisig]
+        [pallet_session_rewards, SessionRewards]
         [pallet_timestamp, Timestamp]
         [pallet_token_claims, TokenClaims]
         [pallet_utility, Utility]
@@ -1140,6 +1284,27 @@ impl_runtime_apis! {
 
             Ok(<Block as BlockT>::Extrinsic::new_signed(call, address, signature, extra))
         }
+
+        fn next_session_keys(id: &KeystoreBioauthAccountId) -> Option<Vec<u8>> {
+            let validator_id =
+                AccountId::new(<KeystoreBioauthAccountId as sp_application_crypto::AppKey>::UntypedGeneric::from(id.clone()).0);
+            pallet_session::NextKeys::<Runtime>::get(validator_id).map(|keys| keys.encode())
+        }
+
+        fn create_set_keys_signing_payload(
+            id: &KeystoreBioauthAccountId,
+            session_keys: Vec<u8>
+        ) -> Result<author_ext_api::SetKeysSigningPayload, author_ext_api::CreateSignedSetKeysExtrinsicError> {
+            author_ext::create_set_keys_signing_payload(id, session_keys)
+        }
+
+        fn create_set_keys_extrinsic_with_signature(
+            id: &KeystoreBioauthAccountId,
+            unsigned_extrinsic: Vec<u8>,
+            signature: Vec<u8>
+        ) -> Result<<Block as BlockT>::Extrinsic, author_ext_api::CreateSignedSetKeysExtrinsicError> {
+            author_ext::create_set_keys_extrinsic_with_signature(id, unsigned_extrinsic, signature)
+        }
     }
 
     impl bioauth_flow_api::BioauthFlowApi<Block, KeystoreBioauthAccountId, UnixMilliseconds> for Runtime {
@@ -1171,6 +1336,138 @@ impl_runtime_apis! {
 
             <Block as BlockT>::Extrinsic::new_unsigned(call.into())
         }
+
+        fn active_authentications() -> Vec<bioauth_flow_api::ActiveAuthentication<KeystoreBioauthAccountId, UnixMilliseconds>> {
+            Bioauth::active_authentications()
+                .into_inner()
+                .into_iter()
+                .map(|authentication| {
+                    let id: [u8; 32] = authentication.public_key.into();
+                    bioauth_flow_api::ActiveAuthentication {
+                        id: <KeystoreBioauthAccountId as sp_application_crypto::AppKey>::UntypedGeneric::from_raw(id).into(),
+                        expires_at: authentication.expires_at,
+                    }
+                })
+                .collect()
+        }
+
+        fn consumed_auth_ticket_nonces_count() -> u32 {
+            Bioauth::consumed_auth_ticket_nonces()
+                .len()
+                .try_into()
+                .expect("the consumed nonces are bounded by u32")
+        }
+
+        fn robonode_public_key() -> Vec<u8> {
+            Bioauth::robonode_public_key().as_ref().to_vec()
+        }
+
+        fn authentications_expire_after() -> UnixMilliseconds {
+            use pallet_bioauth::ExpiryPolicy;
+            // The expiry period of the public keys that have not authenticated before.
+            <Runtime as pallet_bioauth::Config>::ExpiryPolicy::expire_after(
+                Bioauth::robonode_public_key_id(),
+                0,
+            )
+        }
+
+        fn validate_auth_ticket(
+            auth_ticket: Vec<u8>,
+            auth_ticket_signature: Vec<u8>
+        ) -> bioauth_flow_api::AuthTicketVerdict<KeystoreBioauthAccountId> {
+            let authenticate = pallet_bioauth::Authenticate {
+                ticket: auth_ticket.into(),
+                ticket_signature: auth_ticket_signature,
+            };
+
+            match Bioauth::validate_auth_ticket(authenticate) {
+                Ok(auth_ticket) => {
+                    let id: [u8; 32] = auth_ticket.public_key.into();
+                    bioauth_flow_api::AuthTicketVerdict::Valid {
+                        id: <KeystoreBioauthAccountId as sp_application_crypto::AppKey>::UntypedGeneric::from_raw(id).into(),
+                    }
+                }
+                Err(pallet_bioauth::AuthTicketValidationError::UnableToValidateSignature) => {
+                    bioauth_flow_api::AuthTicketVerdict::UnableToValidateSignature
+                }
+                Err(pallet_bioauth::AuthTicketValidationError::SignatureInvalid) => {
+                    bioauth_flow_api::AuthTicketVerdict::SignatureInvalid
+                }
+                Err(pallet_bioauth::AuthTicketValidationError::UnableToParse) => {
+                    bioauth_flow_api::AuthTicketVerdict::UnableToParse
+                }
+                Err(pallet_bioauth::AuthTicketValidationError::NonceConflict) => {
+                    bioauth_flow_api::AuthTicketVerdict::NonceAlreadyUsed
+                }
+                Err(pallet_bioauth::AuthTicketValidationError::AlreadyAuthenticated) => {
+                    bioauth_flow_api::AuthTicketVerdict::AlreadyAuthenticated
+                }
+                Err(pallet_bioauth::AuthTicketValidationError::RejectedByBeforeAuthHook(error)) => {
+                    bioauth_hooks::rejection_verdict(error)
+                }
+            }
+        }
+
+        fn offence_log(id: &KeystoreBioauthAccountId) -> Vec<bioauth_flow_api::OffenceRecord<UnixMilliseconds>> {
+            let id =
+                AccountId::new(<KeystoreBioauthAccountId as sp_application_crypto::AppKey>::UntypedGeneric::from(id.clone()).0);
+            HumanodeOffences::offence_log(id)
+                .into_inner()
+                .into_iter()
+                .map(|entry| bioauth_flow_api::OffenceRecord {
+                    kind: entry.kind,
+                    session_index: entry.session_index,
+                    time_slot: entry.time_slot.into_inner(),
+                    slash_fraction: entry.slash_fraction,
+                    sanction: match entry.sanction {
+                        pallet_humanode_offences::Sanction::Warning => {
+                            bioauth_flow_api::Sanction::Warning
+                        }
+                        pallet_humanode_offences::Sanction::Disable => {
+                            bioauth_flow_api::Sanction::Disable
+                        }
+                        pallet_humanode_offences::Sanction::Deauthenticate => {
+                            bioauth_flow_api::Sanction::Deauthenticate
+                        }
+                        pallet_humanode_offences::Sanction::Ban { until } => {
+                            bioauth_flow_api::Sanction::Ban { until }
+                        }
+                    },
+                })
+                .collect()
+        }
+    }
+
+    impl humanode_session_api::HumanodeSessionApi<
+        Block,
+        AccountId,
+        pallet_humanode_session::BootnodeIdFor<Runtime>,
+        pallet_humanode_session::BioauthAuthenticationFor<Runtime>,
+    > for Runtime {
+        fn session_identities(
+            session_index: u32,
+        ) -> Vec<
+            humanode_session_api::SessionAuthority<
+                AccountId,
+                pallet_humanode_session::BootnodeIdFor<Runtime>,
+                pallet_humanode_session::BioauthAuthenticationFor<Runtime>,
+            >,
+        > {
+            HumanodeSession::session_identities(session_index)
+                .into_iter()
+                .map(|(account_id, identification)| humanode_session_api::SessionAuthority {
+                    account_id,
+                    identity: match identification {
+                        pallet_humanode_session::Identification::Bootnode(bootnode_id) => {
+                            humanode_session_api::SessionIdentity::Bootnode(bootnode_id)
+                        }
+                        pallet_humanode_session::Identification::Bioauth(authentication) => {
+                            humanode_session_api::SessionIdentity::Bioauth(authentication)
+                        }
+                    },
+                })
+                .collect()
+        }
     }
 
     impl fp_rpc::ConvertTransactionRuntimeApi<Block> for Runtime {
@@ -0,0 +1,31 @@
+//! The severity of the offences we sanction the validators for.
+
+use sp_runtime::Perbill;
+use sp_staking::offence::Kind;
+
+use crate::constants::humanode_offences::{
+    EQUIVOCATION_SEVERITY, FULL_SLASH_SEVERITY, UNKNOWN_OFFENCE_SEVERITY, UNRESPONSIVENESS_SEVERITY,
+};
+
+/// The kind of the ImOnline unresponsiveness offence.
+const UNRESPONSIVENESS: Kind = *b"im-online:offlin";
+
+/// The kind of the BABE equivocation offence.
+const BABE_EQUIVOCATION: Kind = *b"babe:equivocatio";
+
+/// The kind of the GRANDPA equivocation offence.
+const GRANDPA_EQUIVOCATION: Kind = *b"grandpa:equivoca";
+
+/// Rates the offences by their kind, and adds up the slash fraction on top.
+pub struct OffenceSeverity;
+
+impl pallet_humanode_offences::OffenceSeverity for OffenceSeverity {
+    fn severity(kind: Option<&Kind>, slash_fraction: Perbill) -> u32 {
+        let base = match kind {
+            Some(&UNRESPONSIVENESS) => UNRESPONSIVENESS_SEVERITY,
+            Some(&BABE_EQUIVOCATION) | Some(&GRANDPA_EQUIVOCATION) => EQUIVOCATION_SEVERITY,
+            _ => UNKNOWN_OFFENCE_SEVERITY,
+        };
+        base.saturating_add(slash_fraction.mul_floor(FULL_SLASH_SEVERITY))
+    }
+}
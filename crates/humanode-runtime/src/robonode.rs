@@ -21,6 +21,12 @@ impl PublicKey {
     }
 }
 
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 /// The error that can occur during robonode signature validation.
 pub enum PublicKeyError {
     UnableToParseKey,
@@ -0,0 +1,117 @@
+//! Tests to verify the author extension logic.
+
+use std::sync::Arc;
+
+use frame_support::traits::GenesisBuild;
+use sp_core::{sr25519, Pair};
+use sp_keystore::{testing::KeyStore, KeystoreExt, SyncCryptoStore};
+use sp_runtime::{
+    traits::Checkable,
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+use super::*;
+use crate::dev_utils::*;
+use crate::opaque::SessionKeys;
+
+/// Build test externalities with the minimal genesis to sign the transactions against.
+fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::default()
+        .build_storage::<Runtime>()
+        .unwrap();
+    sp_io::TestExternalities::new(storage)
+}
+
+/// The encoded session keys to set in tests.
+fn session_keys() -> Vec<u8> {
+    let (_, babe, grandpa, im_online) = authority_keys("Alice");
+    SessionKeys {
+        babe,
+        grandpa,
+        im_online,
+    }
+    .encode()
+}
+
+/// Create the `set_keys` extrinsic for the validator with the given seed, signed outside of
+/// the runtime by the signer with the given seed.
+fn set_keys_signed_externally(
+    validator_seed: &str,
+    signer_seed: &str,
+) -> <Block as BlockT>::Extrinsic {
+    let validator_id = KeystoreBioauthAccountId::from(
+        sr25519::Pair::from_string(validator_seed, None)
+            .unwrap()
+            .public(),
+    );
+    let signer = sr25519::Pair::from_string(signer_seed, None).unwrap();
+
+    let signing_payload =
+        author_ext::create_set_keys_signing_payload(&validator_id, session_keys()).unwrap();
+
+    let signature = signer.sign(&signing_payload.payload);
+
+    author_ext::create_set_keys_extrinsic_with_signature(
+        &validator_id,
+        signing_payload.unsigned_extrinsic,
+        signature.0.to_vec(),
+    )
+    .unwrap()
+}
+
+/// This test verifies that the session keys are generated into the keystore registered as
+/// the extension, as the session keys rotation relies on it.
+#[test]
+fn generated_session_keys_are_put_into_the_keystore() {
+    // Prepare the externalities with the keystore.
+    let keystore = Arc::new(KeyStore::new());
+    let mut ext = sp_io::TestExternalities::new_empty();
+    ext.register_extension(KeystoreExt(keystore.clone()));
+
+    // Invoke the session keys generation.
+    let session_keys = ext.execute_with(|| SessionKeys::generate(None));
+
+    // Assert the state.
+    let raw_public_keys = SessionKeys::decode_into_raw_public_keys(&session_keys).unwrap();
+    assert_eq!(raw_public_keys.len(), 3);
+    assert!(SyncCryptoStore::has_keys(&*keystore, &raw_public_keys));
+}
+
+/// This test verifies that the `set_keys` extrinsic made from the signing payload signed outside
+/// of the node passes the signature check.
+#[test]
+fn set_keys_signed_externally_is_valid() {
+    new_test_ext().execute_with(|| {
+        // Prepare the extrinsic.
+        let extrinsic = set_keys_signed_externally("//Alice", "//Alice");
+
+        // Invoke the signature check.
+        let checked =
+            Checkable::check(extrinsic, &frame_system::ChainContext::<Runtime>::default());
+
+        // Assert the result.
+        assert!(checked.is_ok());
+    });
+}
+
+/// This test verifies that the `set_keys` extrinsic signed outside of the node with a key other
+/// than the validator key does not pass the signature check.
+#[test]
+fn set_keys_signed_externally_by_other_key_is_invalid() {
+    new_test_ext().execute_with(|| {
+        // Prepare the extrinsic.
+        let extrinsic = set_keys_signed_externally("//Alice", "//Bob");
+
+        // Invoke the signature check.
+        let checked =
+            Checkable::check(extrinsic, &frame_system::ChainContext::<Runtime>::default());
+
+        // Assert the result.
+        assert!(matches!(
+            checked,
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::BadProof
+            ))
+        ));
+    });
+}
@@ -0,0 +1,355 @@
+//! Tests to verify the bioauth integration with the rest of the runtime.
+
+// Allow simple integer arithmetic in tests.
+#![allow(clippy::arithmetic_side_effects)]
+
+use frame_support::{assert_noop, assert_ok};
+use pallet_bioauth::WeightInfo as _;
+use pallet_session::ShouldEndSession;
+use robonode_crypto::Signer;
+
+use super::*;
+use crate::dev_utils::*;
+use crate::opaque::SessionKeys;
+
+const INIT_BALANCE: Balance = 10u128.pow(18 + 6);
+
+const ROBONODE_SECRET_KEY: [u8; 32] =
+    hex_literal::hex!("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60");
+
+/// Build test externalities from the custom genesis with the given endowed accounts.
+fn new_test_ext_with(endowed_accounts: Vec<AccountId>) -> sp_io::TestExternalities {
+    let authorities = vec![authority_keys("Alice")];
+    let bootnodes = vec![account_id("Alice")];
+    let robonode_public_key =
+        robonode::PublicKey::from_bytes(robonode_keypair().verifying_key().as_bytes()).unwrap();
+    // Build test genesis.
+    let config = GenesisConfig {
+        balances: BalancesConfig {
+            balances: {
+                endowed_accounts
+                    .into_iter()
+                    .map(|k| (k, INIT_BALANCE))
+                    .chain(
+                        [
+                            (
+                                TreasuryPot::account_id(),
+                                <Balances as frame_support::traits::Currency<AccountId>>::minimum_balance(),
+                            ),
+                            (
+                                FeesPot::account_id(),
+                                <Balances as frame_support::traits::Currency<AccountId>>::minimum_balance(),
+                            ),
+                            (
+                                TokenClaimsPot::account_id(),
+                                <Balances as frame_support::traits::Currency<AccountId>>::minimum_balance(),
+                            ),
+                            (
+                                NativeToEvmSwapBridgePot::account_id(),
+                                <Balances as frame_support::traits::Currency<AccountId>>::minimum_balance(),
+                            ),
+                        ],
+                    )
+                    .collect()
+            },
+        },
+        session: SessionConfig {
+            keys: authorities
+                .iter()
+                .map(|x| {
+                    (
+                        x.0.clone(),
+                        x.0.clone(),
+                        SessionKeys {
+                            babe: x.1.clone(),
+                            grandpa: x.2.clone(),
+                            im_online: x.3.clone(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>(),
+        },
+        babe: BabeConfig {
+            authorities: vec![],
+            epoch_config: Some(BABE_GENESIS_EPOCH_CONFIG),
+        },
+        bootnodes: BootnodesConfig {
+            bootnodes: bootnodes.try_into().unwrap(),
+        },
+        bioauth: BioauthConfig {
+            robonode_public_key,
+            ..Default::default()
+        },
+        evm: EVMConfig {
+            accounts: {
+                let evm_accounts = vec![(
+                    EvmToNativeSwapBridgePot::account_id(),
+                    <EvmBalances as frame_support::traits::Currency<EvmAccountId>>::minimum_balance(
+                    )
+                    .into(),
+                )];
+
+                evm_accounts
+                    .into_iter()
+                    .map(|(account_id, balance)| {
+                        (
+                            account_id,
+                            fp_evm::GenesisAccount {
+                                balance,
+                                code: Default::default(),
+                                nonce: Default::default(),
+                                storage: Default::default(),
+                            },
+                        )
+                    })
+                    .collect()
+            },
+        },
+        ..Default::default()
+    };
+    let storage = config.build_storage().unwrap();
+
+    // Make test externalities from the storage.
+    sp_io::TestExternalities::new(storage)
+}
+
+/// The robonode keypair used to sign the auth tickets in tests.
+fn robonode_keypair() -> robonode_crypto::Keypair {
+    robonode_crypto::Keypair::from_bytes(&ROBONODE_SECRET_KEY)
+}
+
+/// Prepare a signed authenticate request for a given validator and nonce.
+fn make_authenticate_request(
+    validator: &AccountId,
+    nonce: &[u8],
+) -> pallet_bioauth::Authenticate<OpaqueAuthTicket, Vec<u8>> {
+    let ticket = OpaqueAuthTicket::from(&primitives_auth_ticket::AuthTicket {
+        public_key: validator.as_ref().to_vec(),
+        authentication_nonce: nonce.to_vec(),
+    });
+    let ticket_signature = robonode_keypair().sign(ticket.as_ref()).to_bytes().to_vec();
+    pallet_bioauth::Authenticate {
+        ticket,
+        ticket_signature,
+    }
+}
+
+/// This test verifies that the authentication is rejected when the validator account does not
+/// have the required minimum balance.
+#[test]
+fn authentication_requires_minimum_balance() {
+    // Build the state from the config.
+    new_test_ext_with(vec![account_id("Alice")]).execute_with(move || {
+        let poor_validator = account_id("Bob");
+
+        // Invoke the function under test.
+        assert_noop!(
+            Bioauth::authenticate(
+                RuntimeOrigin::none(),
+                make_authenticate_request(&poor_validator, b"nonce"),
+            ),
+            sp_runtime::DispatchError::Other("insufficient balance for the authentication")
+        );
+
+        // Assert state changes.
+        assert!(Bioauth::active_authentications().is_empty());
+    })
+}
+
+/// This test verifies that the authentication succeeds when the validator account has
+/// the required minimum balance.
+#[test]
+fn authentication_with_minimum_balance_succeeds() {
+    // Build the state from the config.
+    new_test_ext_with(vec![account_id("Alice")]).execute_with(move || {
+        let validator = account_id("Alice");
+
+        // Invoke the function under test.
+        assert_ok!(Bioauth::authenticate(
+            RuntimeOrigin::none(),
+            make_authenticate_request(&validator, b"nonce"),
+        ));
+
+        // Assert state changes.
+        let active_authentications = Bioauth::active_authentications();
+        assert_eq!(active_authentications.len(), 1);
+        assert_eq!(active_authentications[0].public_key, validator);
+    })
+}
+
+/// This test verifies that the auth ticket validation reports the insufficient balance.
+#[test]
+fn auth_ticket_validation_reports_insufficient_balance() {
+    // Build the state from the config.
+    new_test_ext_with(vec![account_id("Alice")]).execute_with(move || {
+        let poor_validator = account_id("Bob");
+
+        // Invoke the function under test.
+        let result =
+            Bioauth::validate_auth_ticket(make_authenticate_request(&poor_validator, b"nonce"));
+
+        // Assert the verdict.
+        let error = match result {
+            Err(pallet_bioauth::AuthTicketValidationError::RejectedByBeforeAuthHook(error)) => {
+                error
+            }
+            other => panic!("unexpected validation result: {other:?}"),
+        };
+        assert_eq!(
+            crate::bioauth_hooks::rejection_verdict::<KeystoreBioauthAccountId>(error),
+            bioauth_flow_api::AuthTicketVerdict::InsufficientBalance
+        );
+    })
+}
+
+/// This test verifies that the auth ticket validation reports the ban.
+#[test]
+fn auth_ticket_validation_reports_ban() {
+    // Build the state from the config.
+    new_test_ext_with(vec![account_id("Alice")]).execute_with(move || {
+        // Prepare the test state.
+        let validator = account_id("Alice");
+        pallet_humanode_offences::Bans::<Runtime>::insert(&validator, UnixMilliseconds::MAX);
+
+        // Invoke the function under test.
+        let result = Bioauth::validate_auth_ticket(make_authenticate_request(&validator, b"nonce"));
+
+        // Assert the verdict.
+        let error = match result {
+            Err(pallet_bioauth::AuthTicketValidationError::RejectedByBeforeAuthHook(error)) => {
+                error
+            }
+            other => panic!("unexpected validation result: {other:?}"),
+        };
+        assert_eq!(
+            crate::bioauth_hooks::rejection_verdict::<KeystoreBioauthAccountId>(error),
+            bioauth_flow_api::AuthTicketVerdict::Banned
+        );
+    })
+}
+
+/// This test verifies that issuing the expiry warnings is accounted for in the weight of
+/// the bioauth block initialization.
+#[test]
+fn expiry_warnings_are_weighed() {
+    // Build the state from the config.
+    new_test_ext_with(vec![account_id("Alice")]).execute_with(move || {
+        // Prepare the test state.
+        let validator = account_id("Alice");
+        assert_ok!(Bioauth::authenticate(
+            RuntimeOrigin::none(),
+            make_authenticate_request(&validator, b"nonce"),
+        ));
+        let expected_base_weight =
+            <Runtime as pallet_bioauth::Config>::WeightInfo::on_initialize(1);
+        let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+
+        // Invoke the function under test outside of the expiry warning period.
+        let weight = <Bioauth as frame_support::traits::Hooks<BlockNumber>>::on_initialize(
+            System::block_number(),
+        );
+
+        // Assert only the warnings mark read is added.
+        assert!(Bioauth::expiry_warnings_issued_until().is_none());
+        assert_eq!(weight, expected_base_weight + db_weight.reads(1));
+
+        // Move into the expiry warning period.
+        pallet_timestamp::Now::<Runtime>::put(
+            AUTHENTICATIONS_EXPIRE_AFTER - AUTHENTICATIONS_EXPIRY_WARNING_PERIOD,
+        );
+
+        // Invoke the function under test within the expiry warning period.
+        let weight = <Bioauth as frame_support::traits::Hooks<BlockNumber>>::on_initialize(
+            System::block_number(),
+        );
+
+        // Assert the warnings mark and the event writes are added.
+        assert!(Bioauth::expiry_warnings_issued_until().is_some());
+        assert_eq!(
+            weight,
+            expected_base_weight + db_weight.reads(1) + db_weight.writes(2)
+        );
+    })
+}
+
+/// Plan the next session with the runtime session manager, as the session pallet would.
+fn plan_session(new_index: u32) -> Vec<AccountId> {
+    <<Runtime as pallet_session::Config>::SessionManager as pallet_session::SessionManager<
+        AccountId,
+    >>::new_session(new_index)
+    .unwrap()
+}
+
+/// This test verifies that the validator set does not collapse when all of the bioauth
+/// authentications expire at once.
+#[test]
+fn mass_expiry_keeps_previous_validator_set() {
+    let validators = (0..MIN_VALIDATOR_SET_SIZE)
+        .map(|index| account_id(&format!("Validator{index}")))
+        .collect::<Vec<_>>();
+
+    // Build the state from the config.
+    new_test_ext_with(validators.clone()).execute_with(move || {
+        // Events are not recorded at genesis.
+        System::set_block_number(1);
+
+        // Prepare the test state.
+        for (index, validator) in validators.iter().enumerate() {
+            assert_ok!(Bioauth::authenticate(
+                RuntimeOrigin::none(),
+                make_authenticate_request(validator, format!("nonce{index}").as_bytes()),
+            ));
+        }
+        let mut authorities = plan_session(2);
+        authorities.sort();
+        assert_eq!(authorities.len(), validators.len() + 1);
+
+        // Expire all of the authentications.
+        pallet_timestamp::Now::<Runtime>::put(AUTHENTICATIONS_EXPIRE_AFTER);
+        <Bioauth as frame_support::traits::Hooks<BlockNumber>>::on_initialize(
+            System::block_number(),
+        );
+        assert!(Bioauth::active_authentications().is_empty());
+
+        // Invoke the function under test.
+        let mut next_authorities = plan_session(3);
+        next_authorities.sort();
+
+        // Assert state changes.
+        assert_eq!(next_authorities, authorities);
+        System::assert_has_event(RuntimeEvent::HumanodeSession(
+            pallet_humanode_session::Event::ValidatorSetBelowMinimum {
+                session_index: 3,
+                computed_size: 1,
+                minimum: MIN_VALIDATOR_SET_SIZE,
+                resolution:
+                    pallet_humanode_session::BelowMinimumResolution::KeptPreviousAuthorities,
+            },
+        ));
+    })
+}
+
+// The sessions are ended exactly at the BABE epoch changes, as the epochs can not be shortened.
+static_assertions::assert_type_eq_all!(<Runtime as pallet_session::Config>::ShouldEndSession, Babe);
+
+/// This test verifies that the session is ended only at the BABE epoch change.
+#[test]
+fn session_ends_at_epoch_change() {
+    // Build the state from the config.
+    new_test_ext_with(vec![]).execute_with(|| {
+        // Prepare the test state.
+        System::set_block_number(2);
+
+        for slot in [1, EPOCH_DURATION_IN_SLOTS - 1, EPOCH_DURATION_IN_SLOTS] {
+            pallet_babe::CurrentSlot::<Runtime>::put(sp_consensus_babe::Slot::from(slot));
+
+            // Invoke the function under test.
+            let should_end_session =
+                <Runtime as pallet_session::Config>::ShouldEndSession::should_end_session(2);
+
+            // Assert the session is ended only at the epoch change.
+            assert_eq!(should_end_session, Babe::should_epoch_change(2));
+            assert_eq!(should_end_session, slot == EPOCH_DURATION_IN_SLOTS);
+        }
+    })
+}
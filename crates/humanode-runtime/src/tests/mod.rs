@@ -1,7 +1,10 @@
 use super::*;
 
+mod author_ext;
+mod bioauth;
 mod claims_and_vesting;
 mod currency_swap;
 mod fees;
 mod fixed_supply;
 mod genesis_config;
+mod validator_selection;
@@ -0,0 +1,101 @@
+//! Tests to verify the selection of the bioauth validators when there are more candidates than
+//! the validator slots.
+
+// Allow simple integer arithmetic in tests.
+#![allow(clippy::arithmetic_side_effects)]
+
+use std::collections::BTreeSet;
+
+use frame_support::traits::Get;
+use pallet_humanode_session::ValidatorSelector;
+
+use super::*;
+
+/// The amount of the candidates that do not fit into the bioauth validator slots.
+const EXTRA_CANDIDATES: u32 = 1024;
+
+/// The amount of sessions to rotate in tests.
+const SESSIONS: u32 = 24;
+
+/// The maximum amount of the bioauth validators.
+fn max_bioauth_validators() -> u32 {
+    <Runtime as pallet_humanode_session::Config>::MaxBioauthValidators::get()
+}
+
+/// The candidates, more than the bioauth validator slots.
+fn candidates() -> Vec<u32> {
+    (0..max_bioauth_validators() + EXTRA_CANDIDATES).collect()
+}
+
+/// Select the bioauth validators for the given session with the runtime validator selector.
+fn select(session_index: u32) -> Vec<u32> {
+    <<Runtime as pallet_humanode_session::Config>::ValidatorSelector as ValidatorSelector<u32>>::select(
+        session_index,
+        candidates(),
+        max_bioauth_validators().try_into().unwrap(),
+    )
+}
+
+/// Assert the selections over the sessions are valid and give every candidate a turn.
+fn assert_fair_selections(selections: &[Vec<u32>]) {
+    let mut ever_selected = BTreeSet::new();
+    for selection in selections {
+        let unique = selection.iter().copied().collect::<BTreeSet<_>>();
+        assert_eq!(selection.len(), max_bioauth_validators() as usize);
+        assert_eq!(unique.len(), selection.len());
+        ever_selected.extend(unique);
+    }
+    assert_eq!(ever_selected, candidates().into_iter().collect());
+
+    for window in selections.windows(2) {
+        assert_ne!(window[0], window[1]);
+    }
+}
+
+/// This test verifies that the validators are selected fairly over many sessions in the early
+/// epochs, where the BABE randomness is not yet available and is all zeroes.
+#[test]
+fn selection_rotates_with_early_epochs_randomness() {
+    sp_io::TestExternalities::new_empty().execute_with(|| {
+        // Assert the randomness is at its early epochs value.
+        assert_eq!(pallet_babe::Randomness::<Runtime>::get(), [0; 32]);
+
+        // Invoke the function under test.
+        let selections = (0..SESSIONS).map(select).collect::<Vec<_>>();
+
+        // Assert the selections.
+        assert_fair_selections(&selections);
+    })
+}
+
+/// This test verifies that the validators are selected fairly over many sessions with
+/// the constant BABE randomness, as it is within a single epoch.
+#[test]
+fn selection_rotates_with_constant_randomness() {
+    sp_io::TestExternalities::new_empty().execute_with(|| {
+        // Prepare the test state.
+        pallet_babe::Randomness::<Runtime>::put([42; 32]);
+
+        // Invoke the function under test.
+        let selections = (0..SESSIONS).map(select).collect::<Vec<_>>();
+
+        // Assert the selections.
+        assert_fair_selections(&selections);
+    })
+}
+
+/// This test verifies that the validators selection is seeded by the BABE randomness.
+#[test]
+fn selection_depends_on_babe_randomness() {
+    sp_io::TestExternalities::new_empty().execute_with(|| {
+        // Invoke the function under test with the early epochs randomness.
+        let early_selection = select(1);
+
+        // Invoke the function under test with the updated randomness.
+        pallet_babe::Randomness::<Runtime>::put([42; 32]);
+        let selection = select(1);
+
+        // Assert the selections differ.
+        assert_ne!(early_selection, selection);
+    })
+}
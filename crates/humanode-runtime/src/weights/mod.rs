@@ -5,9 +5,12 @@
 pub mod frame_system;
 pub mod pallet_balances;
 pub mod pallet_bioauth;
+pub mod pallet_bootnodes;
 pub mod pallet_evm_accounts_mapping;
+pub mod pallet_humanode_staking;
 pub mod pallet_im_online;
 pub mod pallet_multisig;
+pub mod pallet_session_rewards;
 pub mod pallet_timestamp;
 pub mod pallet_token_claims;
 pub mod pallet_utility;
@@ -18,14 +18,20 @@ impl<T: frame_system::Config> pallet_bioauth::WeightInfo for WeightInfo<T> {
     Weight::from_parts(227_889_999_931, 0)
       // Standard Error: 9_401
       .saturating_add(Weight::from_parts(132_701, 0).saturating_mul(n.into()))
-      .saturating_add(T::DbWeight::get().reads(4))
+      .saturating_add(T::DbWeight::get().reads(5))
       .saturating_add(T::DbWeight::get().writes(2))
   }
   /// The range of component `a` is `[0, 3072]`.
   fn set_robonode_public_key(_a: u32, ) -> Weight {
     // Minimum execution time: 6_000 nanoseconds.
     Weight::from_parts(7_000_000, 0)
-      .saturating_add(T::DbWeight::get().writes(2))
+      .saturating_add(T::DbWeight::get().reads(1))
+      .saturating_add(T::DbWeight::get().writes(3))
+  }
+  fn set_expiry_period() -> Weight {
+    // Minimum execution time: 5_000 nanoseconds.
+    Weight::from_parts(6_000_000, 0)
+      .saturating_add(T::DbWeight::get().writes(1))
   }
   /// The range of component `a` is `[0, 3072]`.
   fn on_initialize(_a: u32, ) -> Weight {
@@ -0,0 +1,40 @@
+// DO NOT EDIT!
+//! Autogenerated weights for `pallet_bootnodes`
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_bootnodes`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_bootnodes::WeightInfo for WeightInfo<T> {
+  /// The range of component `b` is `[0, 15]`.
+  fn add_bootnode(b: u32, ) -> Weight {
+    // Minimum execution time: 12_000 nanoseconds.
+    Weight::from_parts(12_954_000, 0)
+      // Standard Error: 1_204
+      .saturating_add(Weight::from_parts(98_612, 0).saturating_mul(b.into()))
+      .saturating_add(T::DbWeight::get().reads(1))
+      .saturating_add(T::DbWeight::get().writes(2))
+  }
+  /// The range of component `b` is `[1, 16]`.
+  fn remove_bootnode(b: u32, ) -> Weight {
+    // Minimum execution time: 12_000 nanoseconds.
+    Weight::from_parts(12_731_000, 0)
+      // Standard Error: 1_187
+      .saturating_add(Weight::from_parts(104_385, 0).saturating_mul(b.into()))
+      .saturating_add(T::DbWeight::get().reads(1))
+      .saturating_add(T::DbWeight::get().writes(2))
+  }
+  /// The range of component `b` is `[0, 16]`.
+  fn set_bootnodes(b: u32, ) -> Weight {
+    // Minimum execution time: 8_000 nanoseconds.
+    Weight::from_parts(9_212_000, 0)
+      // Standard Error: 2_391
+      .saturating_add(Weight::from_parts(431_072, 0).saturating_mul(b.into()))
+      .saturating_add(T::DbWeight::get().writes(2))
+  }
+}
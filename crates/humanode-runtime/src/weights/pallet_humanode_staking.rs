@@ -0,0 +1,50 @@
+// DO NOT EDIT!
+//! Autogenerated weights for `pallet_humanode_staking`
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_humanode_staking`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_humanode_staking::WeightInfo for WeightInfo<T> {
+  fn set_commission() -> Weight {
+    // Minimum execution time: 1_214_000 nanoseconds.
+    Weight::from_parts(1_231_000_000, 0)
+      .saturating_add(T::DbWeight::get().reads(1))
+      .saturating_add(T::DbWeight::get().writes(2))
+  }
+  fn delegate() -> Weight {
+    // Minimum execution time: 1_297_000 nanoseconds.
+    Weight::from_parts(1_326_000_000, 0)
+      .saturating_add(T::DbWeight::get().reads(10))
+      .saturating_add(T::DbWeight::get().writes(7))
+  }
+  fn undelegate() -> Weight {
+    // Minimum execution time: 60_000 nanoseconds.
+    Weight::from_parts(62_000_000, 0)
+      .saturating_add(T::DbWeight::get().reads(8))
+      .saturating_add(T::DbWeight::get().writes(7))
+  }
+  fn withdraw_unbonded() -> Weight {
+    // Minimum execution time: 47_000 nanoseconds.
+    Weight::from_parts(49_000_000, 0)
+      .saturating_add(T::DbWeight::get().reads(5))
+      .saturating_add(T::DbWeight::get().writes(4))
+  }
+  fn claim_rewards() -> Weight {
+    // Minimum execution time: 49_000 nanoseconds.
+    Weight::from_parts(51_000_000, 0)
+      .saturating_add(T::DbWeight::get().reads(7))
+      .saturating_add(T::DbWeight::get().writes(5))
+  }
+  fn split_reward() -> Weight {
+    // Minimum execution time: 14_000 nanoseconds.
+    Weight::from_parts(15_000_000, 0)
+      .saturating_add(T::DbWeight::get().reads(4))
+      .saturating_add(T::DbWeight::get().writes(2))
+  }
+}
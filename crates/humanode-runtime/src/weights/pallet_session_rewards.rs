@@ -0,0 +1,31 @@
+// DO NOT EDIT!
+//! Autogenerated weights for `pallet_session_rewards`
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_session_rewards`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_session_rewards::WeightInfo for WeightInfo<T> {
+  /// The range of component `v` is `[1, 3072]`.
+  fn end_session(v: u32, ) -> Weight {
+    // Minimum execution time: 31_000 nanoseconds.
+    Weight::from_parts(24_318_000, 0)
+      // Standard Error: 2_913
+      .saturating_add(Weight::from_parts(11_734_512, 0).saturating_mul(v.into()))
+      .saturating_add(T::DbWeight::get().reads(4))
+      .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(v.into())))
+      .saturating_add(T::DbWeight::get().writes(1))
+      .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(v.into())))
+  }
+  fn claim_rewards() -> Weight {
+    // Minimum execution time: 37_000 nanoseconds.
+    Weight::from_parts(38_000_000, 0)
+      .saturating_add(T::DbWeight::get().reads(4))
+      .saturating_add(T::DbWeight::get().writes(4))
+  }
+}
@@ -0,0 +1,41 @@
+//! The runtime API for the humanode session.
+//!
+//! Intended for resolving the identities of the authorities of the recent sessions, for instance
+//! for processing the late-arriving offence reports or for the explorers.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::prelude::*;
+
+/// The origin of the session authority.
+#[derive(Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+pub enum SessionIdentity<BootnodeId, Authentication> {
+    /// The authority is a bootnode.
+    Bootnode(BootnodeId),
+    /// The authority is bioauthenticated.
+    Bioauth(Authentication),
+}
+
+/// An authority of the session.
+#[derive(Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+pub struct SessionAuthority<AccountId, BootnodeId, Authentication> {
+    /// The account id of the authority.
+    pub account_id: AccountId,
+    /// The identity of the authority.
+    pub identity: SessionIdentity<BootnodeId, Authentication>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for the humanode session.
+    pub trait HumanodeSessionApi<AccountId: Decode, BootnodeId: Decode, Authentication: Decode> {
+        /// Get the authorities of the given session along with their identities.
+        ///
+        /// Only the current, the next and a limited amount of the past sessions are known,
+        /// the list is empty for the rest of them.
+        fn session_identities(
+            session_index: u32
+        ) -> Vec<SessionAuthority<AccountId, BootnodeId, Authentication>>;
+    }
+}
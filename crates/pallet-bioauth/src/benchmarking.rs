@@ -61,11 +61,11 @@ pub trait AuthTicketSigner: pallet::Config {
 
 /// Convenient function to generate an [`Authentication`] struct in bulk with custom prefix
 /// for public key.
-fn make_authentications<Pubkey: From<[u8; 32]>, Moment: Copy>(
+fn make_authentications<Pubkey: From<[u8; 32]>, Moment: Copy + Default, BlockNumber: Default>(
     prefix: &str,
     count: usize,
     expires_at: Moment,
-) -> Vec<Authentication<Pubkey, Moment>> {
+) -> Vec<Authentication<Pubkey, Moment, BlockNumber>> {
     (0..count)
         .into_iter()
         .map(|i| {
@@ -73,6 +73,9 @@ fn make_authentications<Pubkey: From<[u8; 32]>, Moment: Copy>(
             Authentication {
                 public_key: public_key.into(),
                 expires_at,
+                authenticated_at: Default::default(),
+                authenticated_at_block: Default::default(),
+                robonode_public_key_id: Default::default(),
             }
         })
         .collect()
@@ -85,11 +88,11 @@ where
     <Runtime as pallet::Config>::Moment: From<u64>,
 {
     let expiry = Runtime::CurrentMoment::now() + (10u64).into();
-    let active_auths = make_authentications::<Runtime::ValidatorPublicKey, Runtime::Moment>(
-        "active",
-        count as usize,
-        expiry,
-    );
+    let active_auths = make_authentications::<
+        Runtime::ValidatorPublicKey,
+        Runtime::Moment,
+        Runtime::BlockNumber,
+    >("active", count as usize, expiry);
 
     let bounded_active_auths =
         BoundedVec::<_, Runtime::MaxAuthentications>::try_from(active_auths).unwrap();
@@ -178,12 +181,19 @@ benchmarks! {
         assert!(consumed_auth_ticket_nonces_after == consumed_auth_ticket_nonces_before);
     }
 
+    set_expiry_period {
+        let expiry_period: T::Moment = 1000u64.into();
+    }: _(RawOrigin::Root, Some(expiry_period))
+    verify {
+        assert_eq!(ExpiryPeriod::<T>::get(), Some(expiry_period));
+    }
+
     on_initialize {
         let a in 0 .. (T::MaxAuthentications::get());
         let active_auth_count: u32 = a / 2;
         let expiring_auth_count: u32 = a - active_auth_count;
 
-        let mut auths: Vec<Authentication<T::ValidatorPublicKey, T::Moment>> = vec![];
+        let mut auths: Vec<AuthenticationFor<T>> = vec![];
         // Populate with expired authentications.
         let mut expiring_auths = make_authentications("expired", expiring_auth_count as usize, T::CurrentMoment::now());
         auths.append(&mut expiring_auths);
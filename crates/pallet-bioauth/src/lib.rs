@@ -7,7 +7,7 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
     dispatch::DispatchInfo,
-    traits::{ConstU32, IsSubType, StorageVersion},
+    traits::{ConstU32, Get, IsSubType, StorageVersion},
     BoundedVec,
 };
 pub use pallet::*;
@@ -15,7 +15,7 @@ use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
-    traits::{DispatchInfoOf, Dispatchable, SignedExtension},
+    traits::{CheckedAdd, DispatchInfoOf, Dispatchable, SignedExtension},
     transaction_validity::{TransactionValidity, TransactionValidityError},
 };
 use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
@@ -23,6 +23,7 @@ pub use weights::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
+mod migrations;
 pub mod weights;
 
 #[cfg(test)]
@@ -90,6 +91,49 @@ pub trait CurrentMoment<Moment> {
     fn now() -> Moment;
 }
 
+/// Determines for how long a new authentication stays active.
+pub trait ExpiryPolicy<Moment> {
+    /// Compute the amount of time after which the authentication vetted with the given robonode
+    /// public key expires, given the number of the past authentications of the same public key.
+    fn expire_after(
+        robonode_public_key_id: RobonodePublicKeyId,
+        past_authentications: u32,
+    ) -> Moment;
+}
+
+/// The expiry policy that expires every authentication after the same fixed amount of time.
+pub struct ExpireAfter<Period>(PhantomData<Period>);
+
+impl<Moment, Period> ExpiryPolicy<Moment> for ExpireAfter<Period>
+where
+    Period: Get<Moment>,
+{
+    fn expire_after(
+        _robonode_public_key_id: RobonodePublicKeyId,
+        _past_authentications: u32,
+    ) -> Moment {
+        Period::get()
+    }
+}
+
+/// The expiry policy that expires every authentication after the period set via
+/// the [`Pallet::set_expiry_period`] call, falling back to the `DefaultPeriod` when it
+/// was never set.
+pub struct StoredExpiryPeriod<T, DefaultPeriod>(PhantomData<(T, DefaultPeriod)>);
+
+impl<T, DefaultPeriod> ExpiryPolicy<T::Moment> for StoredExpiryPeriod<T, DefaultPeriod>
+where
+    T: Config,
+    DefaultPeriod: Get<T::Moment>,
+{
+    fn expire_after(
+        _robonode_public_key_id: RobonodePublicKeyId,
+        _past_authentications: u32,
+    ) -> T::Moment {
+        <ExpiryPeriod<T>>::get().unwrap_or_else(DefaultPeriod::get)
+    }
+}
+
 /// Authentication extrinsic playload.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Default, Clone, Encode, Decode, Hash, Debug, TypeInfo)]
@@ -119,18 +163,35 @@ pub struct AuthTicket<PublicKey> {
     pub nonce: AuthTicketNonce,
 }
 
+/// The identifier of the robonode public key.
+/// Assigned sequentially, every time the robonode public key is changed.
+pub type RobonodePublicKeyId = u32;
+
 /// The state that we keep in the blockchain for an active authentication.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Default, Clone, Encode, Decode, Hash, Debug, TypeInfo, MaxEncodedLen)]
-pub struct Authentication<PublicKey, Moment> {
+pub struct Authentication<PublicKey, Moment, BlockNumber> {
     /// The public key of a validator.
     pub public_key: PublicKey,
     /// The moment at which the authentication becomes expired.
     pub expires_at: Moment,
+    /// The moment at which the authentication has happened.
+    pub authenticated_at: Moment,
+    /// The number of the block at which the authentication has happened.
+    pub authenticated_at_block: BlockNumber,
+    /// The identifier of the robonode public key that has vetted the authentication.
+    pub robonode_public_key_id: RobonodePublicKeyId,
 }
 
+/// The authentication type for a given config.
+pub type AuthenticationFor<T> = Authentication<
+    <T as Config>::ValidatorPublicKey,
+    <T as Config>::Moment,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 /// Custom invalid transaction error codes.
 #[repr(u8)]
@@ -138,33 +199,53 @@ pub enum CustomInvalidTransactionCodes {
     /// We were unable to parse the auth ticket.
     /// This happens after the signature has already been verified.
     UnableToParseAuthTicket = b't',
+    /// The authentication was rejected by the before auth hook.
+    /// This happens after the auth ticket has already been validated against the state.
+    RejectedByBeforeAuthHook = b'h',
 }
 
 /// A hook that runs before the bioauth.
 /// You can abort the bioauth here (if needed) by returning an error from the hook.
 ///
 /// This hook runs when we have already verified the auth ticket.
-pub trait BeforeAuthHook<PublicKey, Moment> {
+/// It also runs at the transaction validation, so it must not alter the state.
+pub trait BeforeAuthHook<PublicKey, Moment, BlockNumber> {
     /// The data that this hook want to keep around.
     /// The [`AfterAuthHook`] can later use them.
     type Data;
 
     /// The hook to run.
     fn hook(
-        authentication: &Authentication<PublicKey, Moment>,
+        authentication: &Authentication<PublicKey, Moment, BlockNumber>,
     ) -> Result<Self::Data, sp_runtime::DispatchError>;
 }
 
-impl<PublicKey, Moment> BeforeAuthHook<PublicKey, Moment> for () {
+impl<PublicKey, Moment, BlockNumber> BeforeAuthHook<PublicKey, Moment, BlockNumber> for () {
     type Data = ();
 
     fn hook(
-        _authentication: &Authentication<PublicKey, Moment>,
+        _authentication: &Authentication<PublicKey, Moment, BlockNumber>,
     ) -> Result<Self::Data, sp_runtime::DispatchError> {
         Ok(())
     }
 }
 
+impl<PublicKey, Moment, BlockNumber, A, B> BeforeAuthHook<PublicKey, Moment, BlockNumber> for (A, B)
+where
+    A: BeforeAuthHook<PublicKey, Moment, BlockNumber>,
+    B: BeforeAuthHook<PublicKey, Moment, BlockNumber>,
+{
+    type Data = (A::Data, B::Data);
+
+    fn hook(
+        authentication: &Authentication<PublicKey, Moment, BlockNumber>,
+    ) -> Result<Self::Data, sp_runtime::DispatchError> {
+        let a = A::hook(authentication)?;
+        let b = B::hook(authentication)?;
+        Ok((a, b))
+    }
+}
+
 /// A hook that runs after the bioauth.
 ///
 /// Can't abort the bioauth, as it executes after bioauth has already happened.
@@ -190,11 +271,7 @@ pub mod pallet {
     use codec::MaxEncodedLen;
     use frame_support::{pallet_prelude::*, sp_tracing, storage::types::ValueQuery, BoundedVec};
     use frame_system::pallet_prelude::*;
-    use sp_runtime::{
-        app_crypto::MaybeHash,
-        traits::{AtLeast32Bit, CheckedAdd},
-        DispatchError,
-    };
+    use sp_runtime::{app_crypto::MaybeHash, traits::AtLeast32Bit, DispatchError};
 
     use super::*;
     use crate::weights::WeightInfo;
@@ -246,8 +323,12 @@ pub mod pallet {
         /// The getter for the current moment.
         type CurrentMoment: CurrentMoment<Self::Moment>;
 
-        /// The amount of time (in moments) after which the authentications expire.
-        type AuthenticationsExpireAfter: Get<Self::Moment>;
+        /// The policy determining when the new authentications expire.
+        type ExpiryPolicy: ExpiryPolicy<Self::Moment>;
+
+        /// The amount of time (in moments) before the expiration at which we warn about
+        /// the authentications expiring soon.
+        type ExpiryWarningPeriod: Get<Self::Moment>;
 
         /// The validator set updater to invoke at auth the ticket acceptace.
         type ValidatorSetUpdater: ValidatorSetUpdater<Self::ValidatorPublicKey>;
@@ -262,11 +343,19 @@ pub mod pallet {
         type MaxNonces: Get<u32>;
 
         /// Before authentication hook.
-        type BeforeAuthHook: BeforeAuthHook<Self::ValidatorPublicKey, Self::Moment>;
+        type BeforeAuthHook: BeforeAuthHook<
+            Self::ValidatorPublicKey,
+            Self::Moment,
+            Self::BlockNumber,
+        >;
 
         /// After authentication hook.
         type AfterAuthHook: AfterAuthHook<
-            <Self::BeforeAuthHook as BeforeAuthHook<Self::ValidatorPublicKey, Self::Moment>>::Data,
+            <Self::BeforeAuthHook as BeforeAuthHook<
+                Self::ValidatorPublicKey,
+                Self::Moment,
+                Self::BlockNumber,
+            >>::Data,
         >;
 
         /// Possible deauthentication reason.
@@ -282,6 +371,11 @@ pub mod pallet {
     #[pallet::getter(fn robonode_public_key)]
     pub type RobonodePublicKey<T> = StorageValue<_, <T as Config>::RobonodePublicKey, ValueQuery>;
 
+    /// The identifier of the current robonode public key.
+    #[pallet::storage]
+    #[pallet::getter(fn robonode_public_key_id)]
+    pub type CurrentRobonodePublicKeyId<T> = StorageValue<_, RobonodePublicKeyId, ValueQuery>;
+
     /// A list of all consumed nonces.
     #[pallet::storage]
     #[pallet::getter(fn consumed_auth_ticket_nonces)]
@@ -291,18 +385,30 @@ pub mod pallet {
     /// A list of all active authentications.
     #[pallet::storage]
     #[pallet::getter(fn active_authentications)]
-    pub type ActiveAuthentications<T: Config> = StorageValue<
-        _,
-        BoundedVec<Authentication<T::ValidatorPublicKey, T::Moment>, T::MaxAuthentications>,
-        ValueQuery,
-    >;
+    pub type ActiveAuthentications<T: Config> =
+        StorageValue<_, BoundedVec<AuthenticationFor<T>, T::MaxAuthentications>, ValueQuery>;
+
+    /// The moment up to which the expiry warnings have already been issued.
+    #[pallet::storage]
+    #[pallet::getter(fn expiry_warnings_issued_until)]
+    pub type ExpiryWarningsIssuedUntil<T: Config> = StorageValue<_, T::Moment, OptionQuery>;
+
+    /// The amount of time after which the authentications expire, as set by the governance.
+    #[pallet::storage]
+    #[pallet::getter(fn expiry_period)]
+    pub type ExpiryPeriod<T: Config> = StorageValue<_, T::Moment, OptionQuery>;
+
+    /// The number of the past authentications of each public key.
+    #[pallet::storage]
+    #[pallet::getter(fn past_authentications)]
+    pub type PastAuthentications<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::ValidatorPublicKey, u32, ValueQuery>;
 
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub robonode_public_key: T::RobonodePublicKey,
         pub consumed_auth_ticket_nonces: BoundedVec<BoundedAuthTicketNonce, T::MaxNonces>,
-        pub active_authentications:
-            BoundedVec<Authentication<T::ValidatorPublicKey, T::Moment>, T::MaxAuthentications>,
+        pub active_authentications: BoundedVec<AuthenticationFor<T>, T::MaxAuthentications>,
     }
 
     // The default value for the genesis config type.
@@ -336,9 +442,14 @@ pub mod pallet {
         /// New authentication was added to the state.
         NewAuthentication {
             validator_public_key: T::ValidatorPublicKey,
+            expires_at: T::Moment,
         },
         /// The authentications has been expired.
         AuthenticationsExpired { expired: Vec<T::ValidatorPublicKey> },
+        /// The authentications are going to expire within the expiry warning period.
+        AuthenticationsExpiringSoon {
+            expiring: Vec<T::ValidatorPublicKey>,
+        },
         /// The authentications has been removed from the state for some reason.
         AuthenticationsRemoved {
             removed: Vec<T::ValidatorPublicKey>,
@@ -378,7 +489,7 @@ pub mod pallet {
     /// the passed input.
     fn validate_authentication_attempt<'a, T: Config>(
         consumed_auth_ticket_nonces: &'a [BoundedAuthTicketNonce],
-        active_authentications: &'a [Authentication<T::ValidatorPublicKey, T::Moment>],
+        active_authentications: &'a [AuthenticationFor<T>],
         auth_ticket: &AuthTicket<T::ValidatorPublicKey>,
     ) -> Result<(), AuthenticationAttemptValidationError> {
         for consumed_auth_ticket_nonce in consumed_auth_ticket_nonces {
@@ -416,6 +527,10 @@ pub mod pallet {
                     }
                     true
                 });
+                if !removed_public_keys.is_empty() {
+                    // Issue an update to the external validators set.
+                    Self::issue_validators_set_update(active_authentications.as_slice());
+                }
             });
             if !removed_public_keys.is_empty() {
                 // Emit an event.
@@ -426,6 +541,40 @@ pub mod pallet {
             }
             removed_public_keys
         }
+
+        /// Emit a warning for the authentications expiring within the expiry warning period,
+        /// unless they have already been warned about.
+        ///
+        /// Returns the weight consumed on top of the regular expiration processing.
+        fn issue_expiry_warnings(
+            current_moment: T::Moment,
+            active_authentications: &[AuthenticationFor<T>],
+        ) -> Weight {
+            let warn_until = current_moment.saturating_add(T::ExpiryWarningPeriod::get());
+            let already_warned_until = <ExpiryWarningsIssuedUntil<T>>::get();
+            let mut weight = T::DbWeight::get().reads(1);
+
+            let expiring: Vec<_> = active_authentications
+                .iter()
+                .filter(|authentication| authentication.expires_at <= warn_until)
+                .filter(|authentication| match already_warned_until {
+                    Some(already_warned_until) => authentication.expires_at > already_warned_until,
+                    None => true,
+                })
+                .map(|authentication| authentication.public_key.clone())
+                .collect();
+
+            if expiring.is_empty() {
+                return weight;
+            }
+
+            <ExpiryWarningsIssuedUntil<T>>::put(warn_until);
+            Self::deposit_event(Event::AuthenticationsExpiringSoon { expiring });
+
+            // Write the warnings mark and the event.
+            weight = weight.saturating_add(T::DbWeight::get().writes(2));
+            weight
+        }
     }
 
     #[pallet::call]
@@ -438,7 +587,9 @@ pub mod pallet {
             <ActiveAuthentications<T>>::get().len().try_into()
             .expect("u32 is big enough for this oveflow to be practicly impossible"),
             <ConsumedAuthTicketNonces<T>>::get().len().try_into()
-            .expect("u32 is big enough for this oveflow to be practicly impossible")))]
+            .expect("u32 is big enough for this oveflow to be practicly impossible"))
+            // Read and update the past authentications count of the public key.
+            .saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
         pub fn authenticate(
             origin: OriginFor<T>,
             req: Authenticate<T::OpaqueAuthTicket, T::RobonodeSignature>,
@@ -486,12 +637,9 @@ pub mod pallet {
                                 )
                                 .map_err(|_| Error::<T>::TooManyNonces)?;
 
-                            let authentication = Authentication {
-                                public_key: public_key.clone(),
-                                expires_at: current_moment
-                                    .checked_add(&T::AuthenticationsExpireAfter::get())
-                                    .expect("32 bits should be enough for this overflow to be practicly impossible"),
-                            };
+                            let authentication =
+                                Self::new_authentication(public_key.clone(), current_moment);
+                            let expires_at = authentication.expires_at;
 
                             // Run the before hook, abort if needed.
                             let before_hook_data =
@@ -501,6 +649,10 @@ pub mod pallet {
                                 .try_push(authentication)
                                 .map_err(|_| Error::<T>::TooManyAuthentications)?;
 
+                            <PastAuthentications<T>>::mutate(&public_key, |count| {
+                                *count = count.saturating_add(1)
+                            });
+
                             // Issue an update to the external validators set.
                             Self::issue_validators_set_update(active_authentications.as_slice());
 
@@ -509,8 +661,19 @@ pub mod pallet {
 
                             // Emit an event.
                             Self::deposit_event(Event::NewAuthentication {
-                                validator_public_key: public_key,
+                                validator_public_key: public_key.clone(),
+                                expires_at,
                             });
+
+                            // Warn right away if the authentication expires within the already
+                            // warned about period, as the regular warnings skip it.
+                            if Self::expiry_warnings_issued_until()
+                                .map_or(false, |warned_until| expires_at <= warned_until)
+                            {
+                                Self::deposit_event(Event::AuthenticationsExpiringSoon {
+                                    expiring: vec![public_key],
+                                });
+                            }
                             Ok(())
                         },
                     )?;
@@ -531,9 +694,25 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure_root(origin)?;
             <RobonodePublicKey<T>>::put(&robonode_public_key);
+            <CurrentRobonodePublicKeyId<T>>::mutate(|id| *id = id.saturating_add(1));
             <ActiveAuthentications<T>>::put(BoundedVec::default());
             Ok(())
         }
+
+        /// Set the amount of time after which the new authentications expire, or reset it to
+        /// the default one.
+        ///
+        /// Only affects the authentications happening after the change.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_expiry_period())]
+        pub fn set_expiry_period(
+            origin: OriginFor<T>,
+            expiry_period: Option<T::Moment>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            <ExpiryPeriod<T>>::set(expiry_period);
+            Ok(())
+        }
     }
 
     #[pallet::hooks]
@@ -574,6 +753,10 @@ pub mod pallet {
                 });
             }
 
+            // Warn about the authentications that are about to expire.
+            let expiry_warnings_weight =
+                Self::issue_expiry_warnings(current_moment, &active_authentications);
+
             // Weight: O(M) where M is the number of auths.
             // Cost incurred from decoding vec of length M. Charged as maximum.
             T::WeightInfo::on_initialize(
@@ -582,6 +765,21 @@ pub mod pallet {
                     .try_into()
                     .expect("u32 is big enough for this oveflow to be practicly impossible"),
             )
+            .saturating_add(expiry_warnings_weight)
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            migrations::v1::migrate::<T>()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            migrations::v1::pre_migrate::<T>()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            migrations::v1::post_migrate::<T>(state)
         }
     }
 
@@ -592,7 +790,48 @@ pub mod pallet {
         UnableToParse,
     }
 
+    /// The reason an auth ticket would not be accepted by the chain.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AuthTicketValidationError {
+        /// The robonode signature of the auth ticket could not be validated.
+        UnableToValidateSignature,
+        /// The robonode signature of the auth ticket is invalid.
+        SignatureInvalid,
+        /// The auth ticket could not be parsed.
+        UnableToParse,
+        /// The nonce of the auth ticket has already been used.
+        NonceConflict,
+        /// The public key of the auth ticket is already authenticated.
+        AlreadyAuthenticated,
+        /// The authentication was rejected by the [`Config::BeforeAuthHook`].
+        RejectedByBeforeAuthHook(DispatchError),
+    }
+
     impl<T: Config> Pallet<T> {
+        /// Make the authentication of the given public key happening at the given moment,
+        /// at the current block and with the current robonode public key.
+        fn new_authentication(
+            public_key: T::ValidatorPublicKey,
+            current_moment: T::Moment,
+        ) -> AuthenticationFor<T> {
+            let robonode_public_key_id = <CurrentRobonodePublicKeyId<T>>::get();
+            let past_authentications = <PastAuthentications<T>>::get(&public_key);
+            let expires_at = current_moment
+                .checked_add(&T::ExpiryPolicy::expire_after(
+                    robonode_public_key_id,
+                    past_authentications,
+                ))
+                .expect("32 bits should be enough for this overflow to be practicly impossible");
+
+            Authentication {
+                public_key,
+                expires_at,
+                authenticated_at: current_moment,
+                authenticated_at_block: <frame_system::Pallet<T>>::block_number(),
+                robonode_public_key_id,
+            }
+        }
+
         fn extract_auth_ticket_checked(
             req: Authenticate<T::OpaqueAuthTicket, T::RobonodeSignature>,
         ) -> Result<AuthTicket<T::ValidatorPublicKey>, AuthTicketExtractionError> {
@@ -612,30 +851,24 @@ pub mod pallet {
             Ok(auth_ticket)
         }
 
-        pub fn check_tx(call: &Call<T>) -> TransactionValidity {
-            let transaction = match call {
-                Call::authenticate { req: transaction } => transaction,
-                // Deny all unknown transactions.
-                _ => {
-                    // The only supported transaction by this pallet is `authenticate`, so anything
-                    // else is illegal.
-                    return Err(TransactionValidityError::Invalid(InvalidTransaction::Call));
-                }
-            };
-
+        /// Validate the authentication request against the current state, without applying it.
+        ///
+        /// Performs the same checks as the transaction validation does, and returns
+        /// the parsed auth ticket if the authentication would be accepted.
+        pub fn validate_auth_ticket(
+            req: Authenticate<T::OpaqueAuthTicket, T::RobonodeSignature>,
+        ) -> Result<AuthTicket<T::ValidatorPublicKey>, AuthTicketValidationError> {
             let auth_ticket =
-                Self::extract_auth_ticket_checked(transaction.clone()).map_err(|error| {
-                    sp_tracing::error!(message = "Auth Ticket could not be extracted", ?error);
-                    // Use bad proof error code, as the extraction.
-                    TransactionValidityError::Invalid(match error {
-                        AuthTicketExtractionError::UnableToValidateSignature
-                        | AuthTicketExtractionError::SignatureInvalid => {
-                            InvalidTransaction::BadProof
-                        }
-                        AuthTicketExtractionError::UnableToParse => InvalidTransaction::Custom(
-                            CustomInvalidTransactionCodes::UnableToParseAuthTicket as u8,
-                        ),
-                    })
+                Self::extract_auth_ticket_checked(req).map_err(|error| match error {
+                    AuthTicketExtractionError::UnableToValidateSignature => {
+                        AuthTicketValidationError::UnableToValidateSignature
+                    }
+                    AuthTicketExtractionError::SignatureInvalid => {
+                        AuthTicketValidationError::SignatureInvalid
+                    }
+                    AuthTicketExtractionError::UnableToParse => {
+                        AuthTicketValidationError::UnableToParse
+                    }
                 })?;
 
             let consumed_auth_ticket_nonces = ConsumedAuthTicketNonces::<T>::get();
@@ -646,18 +879,53 @@ pub mod pallet {
                 &active_authentications,
                 &auth_ticket,
             )
-            .map_err(|err| {
-                sp_tracing::error!(message = "Authentication attemption failed", error = ?err);
+            .map_err(|error| match error {
+                AuthenticationAttemptValidationError::NonceConflict => {
+                    AuthTicketValidationError::NonceConflict
+                }
+                AuthenticationAttemptValidationError::AlreadyAuthenticated => {
+                    AuthTicketValidationError::AlreadyAuthenticated
+                }
+            })?;
 
-                TransactionValidityError::Invalid(match err {
-                    AuthenticationAttemptValidationError::NonceConflict => {
+            // Run the before hook to check that it would not abort the authentication.
+            let authentication =
+                Self::new_authentication(auth_ticket.public_key.clone(), T::CurrentMoment::now());
+            <T as Config>::BeforeAuthHook::hook(&authentication)
+                .map_err(AuthTicketValidationError::RejectedByBeforeAuthHook)?;
+
+            Ok(auth_ticket)
+        }
+
+        pub fn check_tx(call: &Call<T>) -> TransactionValidity {
+            let transaction = match call {
+                Call::authenticate { req: transaction } => transaction,
+                // Deny all unknown transactions.
+                _ => {
+                    // The only supported transaction by this pallet is `authenticate`, so anything
+                    // else is illegal.
+                    return Err(TransactionValidityError::Invalid(InvalidTransaction::Call));
+                }
+            };
+
+            let auth_ticket = Self::validate_auth_ticket(transaction.clone()).map_err(|error| {
+                sp_tracing::error!(message = "Auth Ticket validation failed", ?error);
+
+                TransactionValidityError::Invalid(match error {
+                    // Use bad proof error code, as the extraction.
+                    AuthTicketValidationError::UnableToValidateSignature
+                    | AuthTicketValidationError::SignatureInvalid => InvalidTransaction::BadProof,
+                    AuthTicketValidationError::UnableToParse => InvalidTransaction::Custom(
+                        CustomInvalidTransactionCodes::UnableToParseAuthTicket as u8,
+                    ),
+                    AuthTicketValidationError::NonceConflict => {
                         // The transaction renders nonce conflict if we have already seen this nonce
                         // before, so in practice, we will most likely observe this with auth ticket
                         // replays. We can sort of say the auth ticket is stale if it has already
                         // been consumed.
                         InvalidTransaction::Stale
                     }
-                    AuthenticationAttemptValidationError::AlreadyAuthenticated => {
+                    AuthTicketValidationError::AlreadyAuthenticated => {
                         // Technically, we can't know if the transaction is from the future, but we
                         // know for sure it's not a replay, since the nonce didn't conflict;
                         // The way it usually observed to happen is when someone authenticates
@@ -665,6 +933,11 @@ pub mod pallet {
                         // transaction would've been valid if sent in the future.
                         InvalidTransaction::Future
                     }
+                    AuthTicketValidationError::RejectedByBeforeAuthHook(_) => {
+                        InvalidTransaction::Custom(
+                            CustomInvalidTransactionCodes::RejectedByBeforeAuthHook as u8,
+                        )
+                    }
                 })
             })?;
 
@@ -678,24 +951,20 @@ pub mod pallet {
         }
 
         fn map_active_authentications_to_validators_set(
-            active_authentications: &[Authentication<T::ValidatorPublicKey, T::Moment>],
+            active_authentications: &[AuthenticationFor<T>],
         ) -> impl Iterator<Item = &T::ValidatorPublicKey> {
             active_authentications
                 .iter()
                 .map(|active_authentication| &active_authentication.public_key)
         }
 
-        fn issue_validators_set_update(
-            active_authentications: &[Authentication<T::ValidatorPublicKey, T::Moment>],
-        ) {
+        fn issue_validators_set_update(active_authentications: &[AuthenticationFor<T>]) {
             let validator_public_keys =
                 Self::map_active_authentications_to_validators_set(active_authentications);
             T::ValidatorSetUpdater::update_validators_set(validator_public_keys);
         }
 
-        fn issue_validators_set_init(
-            active_authentications: &[Authentication<T::ValidatorPublicKey, T::Moment>],
-        ) {
+        fn issue_validators_set_init(active_authentications: &[AuthenticationFor<T>]) {
             let validator_public_keys =
                 Self::map_active_authentications_to_validators_set(active_authentications);
             T::ValidatorSetUpdater::init_validators_set(validator_public_keys);
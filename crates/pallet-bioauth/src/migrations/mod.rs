@@ -0,0 +1,3 @@
+//! State migrations.
+
+pub mod v1;
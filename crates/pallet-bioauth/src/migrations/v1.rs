@@ -0,0 +1,238 @@
+//! Migration to Version 1.
+
+use frame_support::pallet_prelude::*;
+use frame_support::{
+    dispatch::GetStorageVersion,
+    sp_tracing::{error, info},
+    traits::Get,
+    weights::Weight,
+};
+use sp_runtime::traits::Saturating;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use crate::{ActiveAuthentications, AuthenticationFor, Config, ExpiryPolicy, Pallet};
+
+/// The Version 0 authentication.
+#[derive(Encode, Decode)]
+pub struct OldAuthentication<PublicKey, Moment> {
+    /// The public key of a validator.
+    pub public_key: PublicKey,
+    /// The moment at which the authentication becomes expired.
+    pub expires_at: Moment,
+}
+
+/// The Version 0 authentication type for a given config.
+type OldAuthenticationFor<T> =
+    OldAuthentication<<T as Config>::ValidatorPublicKey, <T as Config>::Moment>;
+
+/// The Version 0 active authentications type for a given config.
+type OldActiveAuthenticationsFor<T> =
+    BoundedVec<OldAuthenticationFor<T>, <T as Config>::MaxAuthentications>;
+
+/// Migrate from version 0 to 1.
+///
+/// If the old active authentications can not be decoded, the storage and the version are left
+/// untouched and an error is logged. A runtime upgrade hitting this must not be enacted: with
+/// the `try-runtime` feature on, the migration panics in this case, failing the upgrade check.
+pub fn migrate<T: Config>() -> Weight {
+    let current = <Pallet<T>>::current_storage_version();
+    let onchain = <Pallet<T>>::on_chain_storage_version();
+
+    // Read the onchain version.
+    let mut weight: Weight = T::DbWeight::get().reads(1);
+
+    info!(message = "Running migration to v1", from = ?onchain);
+
+    if onchain >= 1 {
+        info!(message = "Already at version 1, nothing to do");
+        return weight;
+    }
+
+    // We don't know at which block the old authentications have happened, so we use
+    // the current one as the closest known estimation.
+    let current_block = <frame_system::Pallet<T>>::block_number();
+    // We don't know how many times the public keys were authenticated before, so we use
+    // the expiry period of the first authentication with the initial robonode key.
+    let expire_after = T::ExpiryPolicy::expire_after(0, 0);
+    // Read the expiry period.
+    weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+    let translated =
+        <ActiveAuthentications<T>>::translate::<OldActiveAuthenticationsFor<T>, _>(|maybe_old| {
+            maybe_old.map(|old| {
+                let new = old.into_iter().map(|old| AuthenticationFor::<T> {
+                    public_key: old.public_key,
+                    expires_at: old.expires_at,
+                    authenticated_at: old.expires_at.saturating_sub(expire_after),
+                    authenticated_at_block: current_block,
+                    // The old authentications could only be vetted by the initial robonode key.
+                    robonode_public_key_id: 0,
+                });
+                // The amount of items is not changed, so the bound holds.
+                BoundedVec::truncate_from(new.collect())
+            })
+        });
+    if translated.is_err() {
+        // Leave the storage and the version untouched rather than deauthenticating
+        // the whole validator set, the migration has to be fixed.
+        error!(message = "Unable to decode the old active authentications, aborting migration");
+        if cfg!(feature = "try-runtime") {
+            panic!("unable to decode the old active authentications");
+        }
+        // Read the current block number and the old authentications.
+        return weight.saturating_add(T::DbWeight::get().reads(2));
+    }
+    // Read the current block number and the old authentications, then write the new ones.
+    weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+
+    // Set new version.
+    current.put::<Pallet<T>>();
+
+    // Write the onchain version.
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    // Done.
+    weight
+}
+
+/// Check the state before the migration.
+///
+/// Returns an error if the old state can not be migrated.
+#[cfg(feature = "try-runtime")]
+pub fn pre_migrate<T: Config>() -> Result<Vec<u8>, &'static str> {
+    // Ensure the old active authentications can be decoded.
+    if <Pallet<T>>::on_chain_storage_version() < 1 {
+        let raw =
+            frame_support::storage::unhashed::get_raw(&<ActiveAuthentications<T>>::hashed_key());
+        if let Some(raw) = raw {
+            OldActiveAuthenticationsFor::<T>::decode(&mut &*raw)
+                .map_err(|_| "unable to decode the old active authentications")?;
+        }
+    }
+
+    // Record the count of active authentications.
+    let authentications_count: u64 = <ActiveAuthentications<T>>::decode_len()
+        .unwrap_or_default()
+        .try_into()
+        .map_err(|_| "too many active authentications")?;
+    Ok(authentications_count.encode())
+}
+
+/// Check the state after the migration.
+///
+/// Returns an error if the state is not migrated properly.
+#[cfg(feature = "try-runtime")]
+pub fn post_migrate<T: Config>(state: Vec<u8>) -> Result<(), &'static str> {
+    // Ensure version is updated correctly.
+    let onchain = <Pallet<T>>::on_chain_storage_version();
+    if onchain != 1 {
+        return Err("the storage version is not updated to 1");
+    }
+
+    // Ensure the active authentications count matches.
+    let new_authentications_count: u64 = <ActiveAuthentications<T>>::get()
+        .len()
+        .try_into()
+        .map_err(|_| "too many active authentications")?;
+    let old_authentications_count: u64 = codec::Decode::decode(&mut &*state)
+        .map_err(|_| "unable to decode the pre-migration state")?;
+    if new_authentications_count != old_authentications_count {
+        return Err("the active authentications count does not match");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use frame_support::{storage_alias, traits::ConstU32};
+
+    use super::*;
+    use crate::{mock::testing::*, Authentication};
+
+    /// The Version 0 active authentications storage.
+    #[storage_alias]
+    type ActiveAuthentications = StorageValue<
+        Bioauth,
+        BoundedVec<
+            OldAuthentication<ValidatorPublicKey, UnixMilliseconds>,
+            ConstU32<MAX_AUTHENTICATIONS>,
+        >,
+    >;
+
+    #[test]
+    fn migrates_active_authentications() {
+        new_test_ext().execute_with(|| {
+            // Prepare the old state.
+            StorageVersion::new(0).put::<Bioauth>();
+            System::set_block_number(10);
+            ActiveAuthentications::put(
+                BoundedVec::try_from(vec![OldAuthentication {
+                    public_key: [1; 32],
+                    expires_at: 2 * AUTHENTICATIONS_EXPIRE_AFTER,
+                }])
+                .unwrap(),
+            );
+
+            // Invoke the function under test.
+            migrate::<Test>();
+
+            // Assert state changes.
+            assert_eq!(Bioauth::on_chain_storage_version(), 1);
+            assert_eq!(
+                Bioauth::active_authentications().into_inner(),
+                vec![Authentication {
+                    public_key: [1; 32],
+                    expires_at: 2 * AUTHENTICATIONS_EXPIRE_AFTER,
+                    authenticated_at: AUTHENTICATIONS_EXPIRE_AFTER,
+                    authenticated_at_block: 10,
+                    robonode_public_key_id: 0,
+                }]
+            );
+        });
+    }
+
+    /// Put the undecodable Version 0 active authentications into the storage.
+    fn put_undecodable_active_authentications() -> Vec<u8> {
+        StorageVersion::new(0).put::<Bioauth>();
+        let raw = vec![4, 1];
+        frame_support::storage::unhashed::put_raw(&ActiveAuthentications::hashed_key(), &raw);
+        raw
+    }
+
+    #[test]
+    #[cfg(not(feature = "try-runtime"))]
+    fn leaves_undecodable_active_authentications_untouched() {
+        new_test_ext().execute_with(|| {
+            // Prepare the old state.
+            let raw = put_undecodable_active_authentications();
+
+            // Invoke the function under test.
+            migrate::<Test>();
+
+            // Assert the state is not changed.
+            assert_eq!(Bioauth::on_chain_storage_version(), 0);
+            assert_eq!(
+                frame_support::storage::unhashed::get_raw(&ActiveAuthentications::hashed_key()),
+                Some(raw)
+            );
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "try-runtime")]
+    fn undecodable_active_authentications_fail_the_upgrade() {
+        new_test_ext().execute_with(|| {
+            // Prepare the old state.
+            put_undecodable_active_authentications();
+
+            // Assert the pre-upgrade check fails.
+            assert!(pre_migrate::<Test>().is_err());
+
+            // Assert the migration fails.
+            let result = std::panic::catch_unwind(|| migrate::<Test>());
+            assert!(result.is_err());
+        });
+    }
+}
@@ -158,7 +158,7 @@ where
 
 mock! {
     pub BeforeAuthHookProvider {
-        pub fn hook(&self, authentication: &crate::Authentication<ValidatorPublicKey, UnixMilliseconds>) -> Result<(), sp_runtime::DispatchError>;
+        pub fn hook(&self, authentication: &crate::Authentication<ValidatorPublicKey, UnixMilliseconds, BlockNumber>) -> Result<(), sp_runtime::DispatchError>;
     }
 }
 mock! {
@@ -172,11 +172,13 @@ thread_local! {
     pub static MOCK_AFTER_AUTH_HOOK_PROVIDER: RefCell<MockAfterAuthHookProvider> = RefCell::new(MockAfterAuthHookProvider::new());
 }
 
-impl crate::BeforeAuthHook<ValidatorPublicKey, UnixMilliseconds> for MockBeforeAuthHookProvider {
+impl crate::BeforeAuthHook<ValidatorPublicKey, UnixMilliseconds, BlockNumber>
+    for MockBeforeAuthHookProvider
+{
     type Data = ();
 
     fn hook(
-        authentication: &crate::Authentication<ValidatorPublicKey, UnixMilliseconds>,
+        authentication: &crate::Authentication<ValidatorPublicKey, UnixMilliseconds, BlockNumber>,
     ) -> Result<Self::Data, sp_runtime::DispatchError> {
         MOCK_BEFORE_AUTH_HOOK_PROVIDER.with(|val| val.borrow().hook(authentication))
     }
@@ -238,6 +240,10 @@ pub const AUTHENTICATIONS_EXPIRE_AFTER: UnixMilliseconds = TIMESTAMP_MINUTE;
 pub const MAX_AUTHENTICATIONS: u32 = 512;
 pub const MAX_NONCES: u32 = 512;
 
+frame_support::parameter_types! {
+    pub static ExpiryWarningPeriod: UnixMilliseconds = 0;
+}
+
 pub struct DisplayMoment;
 
 impl From<UnixMilliseconds> for DisplayMoment {
@@ -263,7 +269,9 @@ impl pallet_bioauth::Config for Test {
     type Moment = UnixMilliseconds;
     type DisplayMoment = DisplayMoment;
     type CurrentMoment = MockCurrentMomentProvider;
-    type AuthenticationsExpireAfter = ConstU64<AUTHENTICATIONS_EXPIRE_AFTER>;
+    type ExpiryPolicy =
+        pallet_bioauth::StoredExpiryPeriod<Test, ConstU64<AUTHENTICATIONS_EXPIRE_AFTER>>;
+    type ExpiryWarningPeriod = ExpiryWarningPeriod;
     type WeightInfo = ();
     type MaxAuthentications = ConstU32<MAX_AUTHENTICATIONS>;
     type MaxNonces = ConstU32<MAX_NONCES>;
@@ -4,7 +4,9 @@
 use std::ops::Div;
 
 use frame_support::{
-    assert_err, assert_noop, assert_ok, assert_storage_noop, pallet_prelude::*, traits::ConstU32,
+    assert_err, assert_noop, assert_ok, assert_storage_noop,
+    pallet_prelude::*,
+    traits::{ConstU32, ConstU64},
     BoundedVec,
 };
 use mockall::predicate;
@@ -39,9 +41,11 @@ fn block_to_process_moment(moment: UnixMilliseconds) -> BlockNumber {
 }
 
 fn make_bounded_active_authentications(
-    authentications: Vec<Authentication<ValidatorPublicKey, UnixMilliseconds>>,
-) -> BoundedVec<Authentication<ValidatorPublicKey, UnixMilliseconds>, ConstU32<MAX_AUTHENTICATIONS>>
-{
+    authentications: Vec<Authentication<ValidatorPublicKey, UnixMilliseconds, BlockNumber>>,
+) -> BoundedVec<
+    Authentication<ValidatorPublicKey, UnixMilliseconds, BlockNumber>,
+    ConstU32<MAX_AUTHENTICATIONS>,
+> {
     BoundedVec::<_, ConstU32<MAX_AUTHENTICATIONS>>::try_from(authentications).unwrap()
 }
 
@@ -80,6 +84,53 @@ fn test_block_to_process_moment_before_chain_start() {
     block_to_process_moment(CHAIN_START - 1);
 }
 
+/// This test verifies that the fixed period expiry policy expires every authentication after
+/// the same period.
+#[test]
+fn expire_after_policy() {
+    type Policy = ExpireAfter<ConstU64<AUTHENTICATIONS_EXPIRE_AFTER>>;
+
+    assert_eq!(
+        <Policy as ExpiryPolicy<UnixMilliseconds>>::expire_after(0, 0),
+        AUTHENTICATIONS_EXPIRE_AFTER
+    );
+    assert_eq!(
+        <Policy as ExpiryPolicy<UnixMilliseconds>>::expire_after(1, 5),
+        AUTHENTICATIONS_EXPIRE_AFTER
+    );
+}
+
+/// This test verifies that the storage-backed expiry policy uses the period from the storage,
+/// and falls back to the default one when it is not set.
+#[test]
+fn stored_expiry_period_policy() {
+    new_test_ext().execute_with(|| {
+        type Policy = StoredExpiryPeriod<Test, ConstU64<AUTHENTICATIONS_EXPIRE_AFTER>>;
+
+        // Check the test precondition.
+        assert_eq!(Bioauth::expiry_period(), None);
+
+        // Ensure the default period is used when the period is not set.
+        assert_eq!(
+            <Policy as ExpiryPolicy<UnixMilliseconds>>::expire_after(0, 0),
+            AUTHENTICATIONS_EXPIRE_AFTER
+        );
+
+        // Set the period.
+        <ExpiryPeriod<Test>>::put(1000);
+
+        // Ensure the stored period is used.
+        assert_eq!(
+            <Policy as ExpiryPolicy<UnixMilliseconds>>::expire_after(0, 0),
+            1000
+        );
+        assert_eq!(
+            <Policy as ExpiryPolicy<UnixMilliseconds>>::expire_after(1, 5),
+            1000
+        );
+    });
+}
+
 /// This test verifies that authentication call works correctly when the state of the chain is
 /// empty.
 #[test]
@@ -108,6 +159,9 @@ fn authentication_with_empty_state() {
                         == &Authentication {
                             public_key: bounded(b"qwe"),
                             expires_at,
+                            authenticated_at: current_moment,
+                            authenticated_at_block: 0,
+                            robonode_public_key_id: 0,
                         }
                 })
                 .return_const(Ok(()));
@@ -128,6 +182,9 @@ fn authentication_with_empty_state() {
             vec![Authentication {
                 public_key: bounded(b"qwe"),
                 expires_at,
+                authenticated_at: current_moment,
+                authenticated_at_block: 0,
+                robonode_public_key_id: 0,
             }]
         );
         // Ensure that the state of ConsumedAuthTicketNonces has been updated.
@@ -135,6 +192,60 @@ fn authentication_with_empty_state() {
             Bioauth::consumed_auth_ticket_nonces().into_inner(),
             vec![b"rty".to_vec()]
         );
+        // Ensure that the authentication has been counted.
+        assert_eq!(Bioauth::past_authentications(bounded(b"qwe")), 1);
+    });
+}
+
+/// This test verifies that authentication records the moment, the block and the robonode key
+/// it has happened with.
+#[test]
+fn authentication_records_metadata() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test preconditions.
+        System::set_block_number(5);
+        <CurrentRobonodePublicKeyId<Test>>::put(3);
+
+        // Prepare test input.
+        let input = make_input(bounded(b"qwe"), b"rty", b"should_be_valid");
+        let current_moment = CHAIN_START + 5 * SLOT_DURATION;
+        let expires_at = current_moment + AUTHENTICATIONS_EXPIRE_AFTER;
+
+        // Set up mock expectations.
+        with_mock_validator_set_updater(|mock| {
+            mock.expect_update_validators_set()
+                .once()
+                .with(predicate::eq(vec![bounded(b"qwe")]))
+                .return_const(());
+        });
+        with_mock_current_moment_provider(|mock| {
+            mock.expect_now().once().with().return_const(current_moment);
+        });
+        with_mock_before_auth_hook_provider(|mock| {
+            mock.expect_hook().once().return_const(Ok(()));
+        });
+        with_mock_after_auth_hook_provider(|mock| {
+            mock.expect_hook().once().return_const(());
+        });
+
+        // Invoke the function under test.
+        assert_ok!(Bioauth::authenticate(RuntimeOrigin::none(), input));
+
+        // Assert state changes.
+        assert_eq!(
+            Bioauth::active_authentications(),
+            vec![Authentication {
+                public_key: bounded(b"qwe"),
+                expires_at,
+                authenticated_at: current_moment,
+                authenticated_at_block: 5,
+                robonode_public_key_id: 3,
+            }]
+        );
+        System::assert_has_event(RuntimeEvent::Bioauth(Event::NewAuthentication {
+            validator_public_key: bounded(b"qwe"),
+            expires_at,
+        }));
     });
 }
 
@@ -150,6 +261,7 @@ fn authentication_expires_exactly_at_the_moment() {
             make_bounded_active_authentications(vec![Authentication {
                 public_key: bounded(b"alice_pk"),
                 expires_at,
+                ..Default::default()
             }]);
 
         let bounded_consumed_auth_ticket_nonces =
@@ -205,6 +317,7 @@ fn authentication_expires_in_successive_block() {
             make_bounded_active_authentications(vec![Authentication {
                 public_key: bounded(b"alice_pk"),
                 expires_at,
+                ..Default::default()
             }]);
 
         let bounded_consumed_auth_ticket_nonces =
@@ -259,6 +372,7 @@ fn authentication_expiration_lifecycle() {
         let authentication = Authentication {
             public_key: bounded(b"alice_pk"),
             expires_at,
+            ..Default::default()
         };
 
         let nonce = b"alice_auth_ticket_nonce".to_vec();
@@ -336,6 +450,166 @@ fn authentication_expiration_lifecycle() {
     });
 }
 
+/// This test verifies that the expiry warning is issued once the authentication enters
+/// the expiry warning period, and only once.
+#[test]
+fn authentication_expiry_warning_issued_once() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test preconditions.
+        ExpiryWarningPeriod::set(10 * SLOT_DURATION);
+        System::set_block_number(1);
+
+        let mut current_moment = CHAIN_START + 2 * SLOT_DURATION;
+        let expires_at = current_moment + 20 * SLOT_DURATION;
+
+        let authentication = Authentication {
+            public_key: bounded(b"alice_pk"),
+            expires_at,
+            ..Default::default()
+        };
+        <ActiveAuthentications<Test>>::put(make_bounded_active_authentications(vec![
+            authentication,
+        ]));
+
+        // Declare that before/after auth hooks must not run.
+        with_mock_before_auth_hook_provider(|mock| {
+            mock.expect_hook().never();
+        });
+        with_mock_after_auth_hook_provider(|mock| {
+            mock.expect_hook().never();
+        });
+
+        let expiring_soon_events_count = || {
+            System::events()
+                .into_iter()
+                .filter(|record| {
+                    matches!(
+                        record.event,
+                        RuntimeEvent::Bioauth(Event::AuthenticationsExpiringSoon { .. })
+                    )
+                })
+                .count()
+        };
+
+        // Process the blocks before the warning period.
+        while current_moment + 10 * SLOT_DURATION < expires_at {
+            with_mock_current_moment_provider(|mock| {
+                mock.expect_now().once().with().return_const(current_moment);
+            });
+            assert_storage_noop!(Bioauth::on_initialize(System::block_number()));
+            current_moment += SLOT_DURATION;
+        }
+
+        // Process the first block within the warning period.
+        with_mock_current_moment_provider(|mock| {
+            mock.expect_now().once().with().return_const(current_moment);
+        });
+        Bioauth::on_initialize(System::block_number());
+
+        // Ensure the warning has been issued.
+        System::assert_has_event(RuntimeEvent::Bioauth(Event::AuthenticationsExpiringSoon {
+            expiring: vec![bounded(b"alice_pk")],
+        }));
+        assert_eq!(
+            Bioauth::expiry_warnings_issued_until(),
+            Some(current_moment + 10 * SLOT_DURATION)
+        );
+
+        // Process the rest of the blocks up until the expiration.
+        current_moment += SLOT_DURATION;
+        while current_moment < expires_at {
+            with_mock_current_moment_provider(|mock| {
+                mock.expect_now().once().with().return_const(current_moment);
+            });
+            Bioauth::on_initialize(System::block_number());
+            current_moment += SLOT_DURATION;
+        }
+
+        // Ensure the warning was not repeated.
+        assert_eq!(expiring_soon_events_count(), 1);
+    });
+}
+
+/// This test verifies that the expiry warning is issued for the authentication expiring within
+/// the already warned about period, as it happens with the expiry period shorter than
+/// the expiry warning period, and only once.
+#[test]
+fn authentication_expiry_warning_issued_with_short_expiry_period() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test preconditions.
+        ExpiryWarningPeriod::set(10 * SLOT_DURATION);
+        System::set_block_number(1);
+
+        let current_moment = CHAIN_START + 2 * SLOT_DURATION;
+        <ActiveAuthentications<Test>>::put(make_bounded_active_authentications(vec![
+            Authentication {
+                public_key: bounded(b"alice_pk"),
+                expires_at: current_moment + 5 * SLOT_DURATION,
+                ..Default::default()
+            },
+        ]));
+        <ExpiryPeriod<Test>>::put(2 * SLOT_DURATION);
+
+        let expiring_soon_events = || {
+            System::events()
+                .into_iter()
+                .filter_map(|record| match record.event {
+                    RuntimeEvent::Bioauth(Event::AuthenticationsExpiringSoon { expiring }) => {
+                        Some(expiring)
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Issue the warning for the existing authentication.
+        with_mock_current_moment_provider(|mock| {
+            mock.expect_now().once().with().return_const(current_moment);
+        });
+        Bioauth::on_initialize(System::block_number());
+        assert_eq!(
+            Bioauth::expiry_warnings_issued_until(),
+            Some(current_moment + 10 * SLOT_DURATION)
+        );
+
+        // Set up mock expectations.
+        with_mock_validator_set_updater(|mock| {
+            mock.expect_update_validators_set().once().return_const(());
+        });
+        with_mock_current_moment_provider(|mock| {
+            mock.expect_now().once().with().return_const(current_moment);
+        });
+        with_mock_before_auth_hook_provider(|mock| {
+            mock.expect_hook().once().return_const(Ok(()));
+        });
+        with_mock_after_auth_hook_provider(|mock| {
+            mock.expect_hook().once().return_const(());
+        });
+
+        // Invoke the function under test.
+        let input = make_input(bounded(b"bob_pk"), b"bob_nonce", b"should_be_valid");
+        assert_ok!(Bioauth::authenticate(RuntimeOrigin::none(), input));
+
+        // Assert the warning has been issued right away.
+        assert_eq!(
+            expiring_soon_events(),
+            vec![vec![bounded(b"alice_pk")], vec![bounded(b"bob_pk")]]
+        );
+
+        // Process the next block.
+        with_mock_current_moment_provider(|mock| {
+            mock.expect_now()
+                .once()
+                .with()
+                .return_const(current_moment + SLOT_DURATION);
+        });
+        Bioauth::on_initialize(System::block_number());
+
+        // Assert the warnings were not repeated.
+        assert_eq!(expiring_soon_events().len(), 2);
+    });
+}
+
 /// This test verifies that authentication call works correctly when a previous
 /// authentication has been expired.
 #[test]
@@ -348,6 +622,7 @@ fn authentication_when_previous_one_has_been_expired() {
             make_bounded_active_authentications(vec![Authentication {
                 public_key: bounded(b"alice_pk"),
                 expires_at,
+                ..Default::default()
             }]);
 
         let bounded_consumed_auth_ticket_nonces =
@@ -355,6 +630,7 @@ fn authentication_when_previous_one_has_been_expired() {
 
         <ActiveAuthentications<Test>>::put(bounded_active_authentications);
         <ConsumedAuthTicketNonces<Test>>::put(bounded_consumed_auth_ticket_nonces);
+        <PastAuthentications<Test>>::insert(bounded(b"alice_pk"), 1);
 
         // Prepare the test input.
         let input = make_input(
@@ -401,6 +677,9 @@ fn authentication_when_previous_one_has_been_expired() {
                         == &Authentication {
                             public_key: bounded(b"alice_pk"),
                             expires_at: expires_at + AUTHENTICATIONS_EXPIRE_AFTER,
+                            authenticated_at: expires_at,
+                            authenticated_at_block: 0,
+                            robonode_public_key_id: 0,
                         }
                 }))
                 .return_const(Ok(()));
@@ -421,6 +700,9 @@ fn authentication_when_previous_one_has_been_expired() {
             vec![Authentication {
                 public_key: bounded(b"alice_pk"),
                 expires_at: expires_at + AUTHENTICATIONS_EXPIRE_AFTER,
+                authenticated_at: expires_at,
+                authenticated_at_block: 0,
+                robonode_public_key_id: 0,
             }]
         );
 
@@ -432,6 +714,9 @@ fn authentication_when_previous_one_has_been_expired() {
                 b"new_alice_auth_ticket_nonce".to_vec()
             ]
         );
+
+        // Ensure that both authentications have been counted.
+        assert_eq!(Bioauth::past_authentications(bounded(b"alice_pk")), 2);
     });
 }
 
@@ -449,6 +734,7 @@ fn too_many_authentications() {
             active_authentications.push(Authentication {
                 public_key,
                 expires_at,
+                ..Default::default()
             });
         }
 
@@ -481,6 +767,9 @@ fn too_many_authentications() {
                         == &Authentication {
                             public_key: bounded(b"alice_pk"),
                             expires_at: current_moment + AUTHENTICATIONS_EXPIRE_AFTER,
+                            authenticated_at: current_moment,
+                            authenticated_at_block: 0,
+                            robonode_public_key_id: 0,
                         }
                 }))
                 .return_const(Ok(()));
@@ -606,6 +895,7 @@ fn authentication_with_conlicting_nonce() {
             make_bounded_active_authentications(vec![Authentication {
                 public_key: bounded(b"pk1"),
                 expires_at,
+                ..Default::default()
             }]);
 
         let bounded_consumed_auth_ticket_nonces =
@@ -651,6 +941,7 @@ fn authentication_with_conlicting_nonce_after_expiration() {
             make_bounded_active_authentications(vec![Authentication {
                 public_key: bounded(b"alice_pk"),
                 expires_at,
+                ..Default::default()
             }]);
 
         let bounded_consumed_auth_ticket_nonces =
@@ -718,6 +1009,7 @@ fn authentication_with_concurrent_conflicting_public_keys() {
             make_bounded_active_authentications(vec![Authentication {
                 public_key: bounded(b"conflict!"),
                 expires_at,
+                ..Default::default()
             }]);
 
         let bounded_consumed_auth_ticket_nonces =
@@ -751,6 +1043,119 @@ fn authentication_with_concurrent_conflicting_public_keys() {
     });
 }
 
+/// This test verifies that auth ticket validation reports the same outcomes as the authentication
+/// would, without altering the state.
+#[test]
+fn auth_ticket_validation() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test precondition.
+        let expires_at = CHAIN_START + 2 * SLOT_DURATION;
+
+        let bounded_active_authentications =
+            make_bounded_active_authentications(vec![Authentication {
+                public_key: bounded(b"alice_pk"),
+                expires_at,
+                ..Default::default()
+            }]);
+
+        let bounded_consumed_auth_ticket_nonces =
+            make_bounded_consumed_auth_nonces(vec![b"alice_nonce".to_vec()]);
+
+        <ActiveAuthentications<Test>>::put(bounded_active_authentications);
+        <ConsumedAuthTicketNonces<Test>>::put(bounded_consumed_auth_ticket_nonces);
+
+        // Make test.
+        assert_storage_noop!({
+            assert_eq!(
+                Bioauth::validate_auth_ticket(make_input(
+                    bounded(b"bob_pk"),
+                    b"bob_nonce",
+                    b"invalid"
+                )),
+                Err(AuthTicketValidationError::SignatureInvalid),
+            );
+            assert_eq!(
+                Bioauth::validate_auth_ticket(make_input(
+                    bounded(b"bob_pk"),
+                    b"alice_nonce",
+                    b"should_be_valid"
+                )),
+                Err(AuthTicketValidationError::NonceConflict),
+            );
+            assert_eq!(
+                Bioauth::validate_auth_ticket(make_input(
+                    bounded(b"alice_pk"),
+                    b"bob_nonce",
+                    b"should_be_valid"
+                )),
+                Err(AuthTicketValidationError::AlreadyAuthenticated),
+            );
+
+            // Set up mock expectations for the before auth hook run.
+            with_mock_current_moment_provider(|mock| {
+                mock.expect_now().once().with().return_const(CHAIN_START);
+            });
+            with_mock_before_auth_hook_provider(|mock| {
+                mock.expect_hook()
+                    .once()
+                    .withf(|authentication| authentication.public_key == bounded(b"bob_pk"))
+                    .return_const(Ok(()));
+            });
+
+            assert_eq!(
+                Bioauth::validate_auth_ticket(make_input(
+                    bounded(b"bob_pk"),
+                    b"bob_nonce",
+                    b"should_be_valid"
+                )),
+                Ok(AuthTicket {
+                    public_key: bounded(b"bob_pk"),
+                    nonce: b"bob_nonce".to_vec(),
+                }),
+            );
+        });
+    });
+}
+
+/// This test verifies that the auth ticket validation runs the before auth hook, and reports
+/// its failure.
+#[test]
+fn auth_ticket_validation_runs_before_hook() {
+    new_test_ext().execute_with(|| {
+        // Set up mock expectations.
+        with_mock_current_moment_provider(|mock| {
+            mock.expect_now().once().with().return_const(CHAIN_START);
+        });
+        with_mock_before_auth_hook_provider(|mock| {
+            mock.expect_hook()
+                .once()
+                .withf(|authentication| {
+                    authentication
+                        == &Authentication {
+                            public_key: bounded(b"bob_pk"),
+                            expires_at: CHAIN_START + AUTHENTICATIONS_EXPIRE_AFTER,
+                            authenticated_at: CHAIN_START,
+                            authenticated_at_block: 0,
+                            robonode_public_key_id: 0,
+                        }
+                })
+                .return_const(Err(sp_runtime::DispatchError::Other("hook error")));
+        });
+
+        // Make test.
+        assert_storage_noop!(assert_eq!(
+            Bioauth::validate_auth_ticket(make_input(
+                bounded(b"bob_pk"),
+                b"bob_nonce",
+                b"should_be_valid"
+            )),
+            Err(AuthTicketValidationError::RejectedByBeforeAuthHook(
+                sp_runtime::DispatchError::Other("hook error")
+            )),
+        ));
+    });
+}
+
 /// This test verifies that before auth hook can deny the authentication
 /// and the resulting state is proper.
 #[test]
@@ -776,6 +1181,9 @@ fn authentication_denied_by_before_hook() {
                         == &Authentication {
                             public_key: bounded(b"qwe"),
                             expires_at,
+                            authenticated_at: current_moment,
+                            authenticated_at_block: 0,
+                            robonode_public_key_id: 0,
                         }
                 })
                 .return_const(Err(sp_runtime::DispatchError::CannotLookup));
@@ -798,6 +1206,72 @@ fn authentication_denied_by_before_hook() {
     });
 }
 
+/// This test verifies that deauthentication removes the authentications and issues
+/// the validators set update.
+#[test]
+fn deauthentication_updates_validators_set() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test preconditions.
+        let expires_at = CHAIN_START + 2 * SLOT_DURATION;
+
+        let bounded_active_authentications = make_bounded_active_authentications(vec![
+            Authentication {
+                public_key: bounded(b"alice_pk"),
+                expires_at,
+                ..Default::default()
+            },
+            Authentication {
+                public_key: bounded(b"bob_pk"),
+                expires_at,
+                ..Default::default()
+            },
+        ]);
+
+        <ActiveAuthentications<Test>>::put(bounded_active_authentications);
+
+        // Set up mock expectations.
+        with_mock_validator_set_updater(|mock| {
+            mock.expect_update_validators_set()
+                .once()
+                .with(predicate::eq(vec![bounded(b"bob_pk")]))
+                .return_const(());
+        });
+
+        // Deauthenticate one of the active and one of the unknown keys.
+        let removed = Bioauth::deauthenticate(vec![bounded(b"alice_pk"), bounded(b"carol_pk")], ());
+
+        // Ensure only the active key was removed.
+        assert_eq!(removed, vec![bounded(b"alice_pk")]);
+        assert_eq!(
+            Bioauth::active_authentications(),
+            vec![Authentication {
+                public_key: bounded(b"bob_pk"),
+                expires_at,
+                ..Default::default()
+            }]
+        );
+    });
+}
+
+/// This test verifies that deauthentication of the keys that are not authenticated does not
+/// issue the validators set update.
+#[test]
+fn deauthentication_of_unknown_keys_is_noop() {
+    new_test_ext().execute_with(|| {
+        // Set up mock expectations.
+        with_mock_validator_set_updater(|mock| {
+            mock.expect_update_validators_set().never();
+        });
+
+        // Deauthenticate an unknown key.
+        let removed = Bioauth::deauthenticate(vec![bounded(b"alice_pk")], ());
+
+        // Ensure nothing was removed.
+        assert_eq!(removed, vec![]);
+        assert_eq!(Bioauth::active_authentications(), vec![]);
+    });
+}
+
 /// This test verifies that the [`set_robonode_public_key`] updates the robonode public key properly.
 #[test]
 fn set_robonode_public_key_updates_key() {
@@ -808,6 +1282,7 @@ fn set_robonode_public_key_updates_key() {
             make_bounded_active_authentications(vec![Authentication {
                 public_key: bounded(b"key1"),
                 expires_at,
+                ..Default::default()
             }]);
 
         let bounded_consumed_auth_ticket_nonces =
@@ -818,6 +1293,7 @@ fn set_robonode_public_key_updates_key() {
 
         // Check the test precondition.
         assert_eq!(Bioauth::robonode_public_key(), MockVerifier::A);
+        assert_eq!(Bioauth::robonode_public_key_id(), 0);
 
         // Prepare test input.
         let input = MockVerifier::B;
@@ -831,6 +1307,9 @@ fn set_robonode_public_key_updates_key() {
         // Ensure the key has changed.
         assert_eq!(Bioauth::robonode_public_key(), MockVerifier::B);
 
+        // Ensure the key identifier has advanced.
+        assert_eq!(Bioauth::robonode_public_key_id(), 1);
+
         // Ensure the active authentications are cleared.
         assert_eq!(<ActiveAuthentications<Test>>::get(), vec![]);
 
@@ -852,6 +1331,7 @@ fn set_robonode_public_key_checks_the_origin() {
             make_bounded_active_authentications(vec![Authentication {
                 public_key: bounded(b"key1"),
                 expires_at,
+                ..Default::default()
             }]);
 
         let bounded_consumed_auth_ticket_nonces =
@@ -893,6 +1373,71 @@ fn set_robonode_public_key_checks_the_origin() {
     });
 }
 
+/// This test verifies that the [`set_expiry_period`] changes the expiration of the new
+/// authentications.
+#[test]
+fn set_expiry_period_applies_to_new_authentications() {
+    new_test_ext().execute_with(|| {
+        // Prepare test input.
+        let expiry_period = AUTHENTICATIONS_EXPIRE_AFTER * 2;
+        let input = make_input(bounded(b"qwe"), b"rty", b"should_be_valid");
+        let current_moment = CHAIN_START + 2 * SLOT_DURATION;
+
+        // Set up mock expectations.
+        with_mock_validator_set_updater(|mock| {
+            mock.expect_update_validators_set().once().return_const(());
+        });
+        with_mock_current_moment_provider(|mock| {
+            mock.expect_now().once().with().return_const(current_moment);
+        });
+        with_mock_before_auth_hook_provider(|mock| {
+            mock.expect_hook().once().return_const(Ok(()));
+        });
+        with_mock_after_auth_hook_provider(|mock| {
+            mock.expect_hook().once().return_const(());
+        });
+
+        // Set the expiry period.
+        assert_ok!(Bioauth::set_expiry_period(
+            RuntimeOrigin::root(),
+            Some(expiry_period)
+        ));
+        assert_eq!(Bioauth::expiry_period(), Some(expiry_period));
+
+        // Authenticate.
+        assert_ok!(Bioauth::authenticate(RuntimeOrigin::none(), input));
+
+        // Ensure the authentication expires after the new period.
+        assert_eq!(
+            Bioauth::active_authentications()[0].expires_at,
+            current_moment + expiry_period
+        );
+
+        // Reset the expiry period to the default one.
+        assert_ok!(Bioauth::set_expiry_period(RuntimeOrigin::root(), None));
+        assert_eq!(Bioauth::expiry_period(), None);
+    });
+}
+
+/// This test verifies that the [`set_expiry_period`] checks the origin.
+#[test]
+fn set_expiry_period_checks_the_origin() {
+    new_test_ext().execute_with(|| {
+        // Attempt to set the expiry period with various origins.
+        assert_noop!(
+            Bioauth::set_expiry_period(RuntimeOrigin::none(), Some(1000)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Bioauth::set_expiry_period(RuntimeOrigin::signed(123), Some(1000)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // Ensure that the expiry period has not changed.
+        assert_eq!(Bioauth::expiry_period(), None);
+    });
+}
+
 /// This test verifies `SignedExt` logic for transaction processing with empty state.
 #[test]
 fn signed_ext_check_bioauth_tx_permits_empty_state() {
@@ -904,6 +1449,14 @@ fn signed_ext_check_bioauth_tx_permits_empty_state() {
             nonce: b"rty".to_vec(),
         };
 
+        // Set up mock expectations.
+        with_mock_current_moment_provider(|mock| {
+            mock.expect_now().once().with().return_const(CHAIN_START);
+        });
+        with_mock_before_auth_hook_provider(|mock| {
+            mock.expect_hook().once().return_const(Ok(()));
+        });
+
         // Make test.
         let call = pallet_bioauth::Call::authenticate { req: input }.into();
         let info = DispatchInfo::default();
@@ -938,6 +1491,38 @@ fn signed_ext_check_bioauth_tx_deny_invalid_signature() {
     })
 }
 
+/// This test verifies `SignedExt` logic for transaction processing that is rejected by
+/// the before auth hook.
+#[test]
+fn signed_ext_check_bioauth_tx_denies_rejected_by_before_hook() {
+    new_test_ext().execute_with(|| {
+        // Prepare test input.
+        let input = make_input(bounded(b"qwe"), b"rty", b"should_be_valid");
+
+        // Set up mock expectations.
+        with_mock_current_moment_provider(|mock| {
+            mock.expect_now().once().with().return_const(CHAIN_START);
+        });
+        with_mock_before_auth_hook_provider(|mock| {
+            mock.expect_hook()
+                .once()
+                .return_const(Err(sp_runtime::DispatchError::Other("hook error")));
+        });
+
+        // Make test.
+        let call = pallet_bioauth::Call::authenticate { req: input }.into();
+        let info = DispatchInfo::default();
+
+        assert_eq!(
+            CheckBioauthTx::<Test>(PhantomData).validate(&1, &call, &info, 1),
+            InvalidTransaction::Custom(
+                CustomInvalidTransactionCodes::RejectedByBeforeAuthHook as u8
+            )
+            .into()
+        );
+    })
+}
+
 /// This test verifies `SignedExt` logic for transaction processing with conflicting nonce.
 #[test]
 fn signed_ext_check_bioauth_tx_denies_conlicting_nonce() {
@@ -949,6 +1534,7 @@ fn signed_ext_check_bioauth_tx_denies_conlicting_nonce() {
             make_bounded_active_authentications(vec![Authentication {
                 public_key: bounded(b"pk1"),
                 expires_at,
+                ..Default::default()
             }]);
 
         let bounded_consumed_auth_ticket_nonces =
@@ -996,6 +1582,7 @@ fn signed_ext_check_bioauth_tx_denies_conflicting_public_keys() {
             make_bounded_active_authentications(vec![Authentication {
                 public_key: bounded(b"conflict!"),
                 expires_at,
+                ..Default::default()
             }]);
 
         let bounded_consumed_auth_ticket_nonces =
@@ -1046,10 +1633,12 @@ fn genesis_build() {
         Authentication {
             public_key: bounded(b"key1"),
             expires_at: 123,
+            ..Default::default()
         },
         Authentication {
             public_key: bounded(b"key2"),
             expires_at: 456,
+            ..Default::default()
         },
     ])
     .unwrap();
@@ -8,6 +8,8 @@ pub trait WeightInfo {
     fn authenticate(authentications: u32, nonces: u32) -> Weight;
     /// A function to calculate required weights for `set_robonode_public_key` call.
     fn set_robonode_public_key(authentications: u32) -> Weight;
+    /// A function to calculate required weights for `set_expiry_period` call.
+    fn set_expiry_period() -> Weight;
     /// A function to calculate required weights for `on_initialize` hook.
     fn on_initialize(authentications: u32) -> Weight;
 }
@@ -21,6 +23,10 @@ impl WeightInfo for () {
         Weight::zero()
     }
 
+    fn set_expiry_period() -> Weight {
+        Weight::zero()
+    }
+
     fn on_initialize(_authentications: u32) -> Weight {
         Weight::zero()
     }
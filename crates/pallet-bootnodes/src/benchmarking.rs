@@ -0,0 +1,70 @@
+//! The benchmarks for the pallet.
+
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::{sp_std::prelude::*, traits::Get, BoundedVec};
+
+use crate::*;
+
+/// Make the given amount of distinct bootnodes.
+fn make_bootnodes<T: Config>(prefix: &'static str, count: u32) -> Vec<T::BootnodeId> {
+    (0..count).map(|index| account(prefix, index, 0)).collect()
+}
+
+/// Populate the bootnodes with the given amount of distinct bootnodes.
+fn populate_bootnodes<T: Config>(count: u32) -> Vec<T::BootnodeId> {
+    let bootnodes = make_bootnodes::<T>("bootnode", count);
+    <Bootnodes<T>>::put(BoundedVec::<_, T::MaxBootnodes>::try_from(bootnodes.clone()).unwrap());
+    bootnodes
+}
+
+benchmarks! {
+    add_bootnode {
+        // Vary the amount of pre-populated bootnodes, leaving the room for one more.
+        let b in 0 .. (T::MaxBootnodes::get().saturating_sub(1));
+        populate_bootnodes::<T>(b);
+
+        let new_bootnode: T::BootnodeId = account("new_bootnode", 0, 0);
+
+        let origin = T::UpdateBootnodesOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, new_bootnode.clone())
+    verify {
+        assert!(<Bootnodes<T>>::get().contains(&new_bootnode));
+    }
+
+    remove_bootnode {
+        // Vary the amount of pre-populated bootnodes, removing the last one as the worst case.
+        let b in 1 .. (T::MaxBootnodes::get());
+        let bootnodes = populate_bootnodes::<T>(b);
+
+        let bootnode = bootnodes.last().unwrap().clone();
+
+        let origin = T::UpdateBootnodesOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, bootnode.clone())
+    verify {
+        assert!(!<Bootnodes<T>>::get().contains(&bootnode));
+    }
+
+    set_bootnodes {
+        // Vary the amount of the new bootnodes, as the duplicates check is quadratic.
+        let b in 0 .. (T::MaxBootnodes::get());
+        populate_bootnodes::<T>(T::MaxBootnodes::get());
+
+        let new_bootnodes = BoundedVec::<_, T::MaxBootnodes>::try_from(
+            make_bootnodes::<T>("new_bootnode", b),
+        ).unwrap();
+
+        let origin = T::UpdateBootnodesOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, new_bootnodes.clone())
+    verify {
+        assert_eq!(<Bootnodes<T>>::get(), new_bootnodes);
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::mock::new_test_ext_with(Default::default()),
+        crate::mock::Test,
+    );
+}
@@ -4,6 +4,16 @@
 
 use frame_support::traits::StorageVersion;
 pub use pallet::*;
+pub use weights::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
 
 /// The current storage version.
 const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
@@ -14,17 +24,27 @@ const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
 
     use super::*;
 
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
     pub trait Config: frame_system::Config {
+        /// Overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
         /// The maximum number of bootnodes storage value.
         type MaxBootnodes: Get<u32>;
 
         /// The type of the bootnode.
         type BootnodeId: Member + Parameter + MaybeSerializeDeserialize + MaxEncodedLen;
+
+        /// The origin that is allowed to update the bootnodes.
+        type UpdateBootnodesOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
@@ -60,4 +80,111 @@ pub mod pallet {
             <Bootnodes<T>>::put(&self.bootnodes);
         }
     }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A bootnode was added.
+        BootnodeAdded {
+            /// The added bootnode.
+            bootnode_id: T::BootnodeId,
+        },
+        /// A bootnode was removed.
+        BootnodeRemoved {
+            /// The removed bootnode.
+            bootnode_id: T::BootnodeId,
+        },
+        /// The bootnodes were replaced.
+        BootnodesSet {
+            /// The new list of bootnodes.
+            bootnodes: BoundedVec<T::BootnodeId, T::MaxBootnodes>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The bootnode is already in the list.
+        AlreadyBootnode,
+        /// The bootnode is not in the list.
+        NotBootnode,
+        /// The list of bootnodes has reached the limit.
+        TooManyBootnodes,
+        /// The provided list of bootnodes contains duplicates.
+        DuplicateBootnodes,
+    }
+
+    // The updates to the bootnodes take effect when the next validator set is constructed,
+    // i.e. at the next session rotation.
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Add a new bootnode.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::add_bootnode(T::MaxBootnodes::get()))]
+        pub fn add_bootnode(origin: OriginFor<T>, bootnode_id: T::BootnodeId) -> DispatchResult {
+            T::UpdateBootnodesOrigin::ensure_origin(origin)?;
+
+            <Bootnodes<T>>::try_mutate::<_, DispatchError, _>(|bootnodes| {
+                if bootnodes.contains(&bootnode_id) {
+                    return Err(Error::<T>::AlreadyBootnode.into());
+                }
+
+                bootnodes
+                    .try_push(bootnode_id.clone())
+                    .map_err(|_| Error::<T>::TooManyBootnodes)?;
+
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::BootnodeAdded { bootnode_id });
+
+            Ok(())
+        }
+
+        /// Remove an existing bootnode.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_bootnode(T::MaxBootnodes::get()))]
+        pub fn remove_bootnode(origin: OriginFor<T>, bootnode_id: T::BootnodeId) -> DispatchResult {
+            T::UpdateBootnodesOrigin::ensure_origin(origin)?;
+
+            <Bootnodes<T>>::try_mutate::<_, DispatchError, _>(|bootnodes| {
+                let position = bootnodes
+                    .iter()
+                    .position(|bootnode| bootnode == &bootnode_id)
+                    .ok_or(Error::<T>::NotBootnode)?;
+
+                bootnodes.remove(position);
+
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::BootnodeRemoved { bootnode_id });
+
+            Ok(())
+        }
+
+        /// Replace the whole list of bootnodes.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_bootnodes(
+            bootnodes.len().try_into().unwrap_or(u32::MAX)
+        ))]
+        pub fn set_bootnodes(
+            origin: OriginFor<T>,
+            bootnodes: BoundedVec<T::BootnodeId, T::MaxBootnodes>,
+        ) -> DispatchResult {
+            T::UpdateBootnodesOrigin::ensure_origin(origin)?;
+
+            let has_duplicates = bootnodes.iter().enumerate().any(|(index, bootnode)| {
+                bootnodes.iter().take(index).any(|other| other == bootnode)
+            });
+            if has_duplicates {
+                return Err(Error::<T>::DuplicateBootnodes.into());
+            }
+
+            <Bootnodes<T>>::put(&bootnodes);
+
+            Self::deposit_event(Event::BootnodesSet { bootnodes });
+
+            Ok(())
+        }
+    }
 }
@@ -0,0 +1,82 @@
+use frame_support::traits::{ConstU32, ConstU64};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+
+use crate::{self as pallet_bootnodes};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// The maximum number of bootnodes in tests.
+pub const MAX_BOOTNODES: u32 = 3;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub struct Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Bootnodes: pallet_bootnodes::{Pallet, Call, Storage, Config<T>, Event<T>},
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_bootnodes::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxBootnodes = ConstU32<MAX_BOOTNODES>;
+    type BootnodeId = u64;
+    type UpdateBootnodesOrigin = EnsureRoot<u64>;
+    type WeightInfo = ();
+}
+
+/// Build test externalities from the custom genesis.
+/// Using this call requires manual assertions on the genesis init logic.
+pub fn new_test_ext_with(
+    config: pallet_bootnodes::GenesisConfig<Test>,
+) -> sp_io::TestExternalities {
+    // Build genesis.
+    let config = GenesisConfig {
+        bootnodes: config,
+        ..Default::default()
+    };
+    let storage = config.build_storage().unwrap();
+
+    // Make test externalities from the storage.
+    let mut ext: sp_io::TestExternalities = storage.into();
+    // Events are not deposited at the genesis block.
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,120 @@
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::DispatchError;
+
+use crate::{self as pallet_bootnodes, mock::*, Error, Event};
+
+/// Build test externalities with the given bootnodes at genesis.
+fn new_test_ext_with_bootnodes(bootnodes: Vec<u64>) -> sp_io::TestExternalities {
+    new_test_ext_with(pallet_bootnodes::GenesisConfig {
+        bootnodes: bootnodes.try_into().unwrap(),
+    })
+}
+
+/// This test verifies that genesis initialization properly assignes the state.
+#[test]
+fn genesis_build() {
+    new_test_ext_with_bootnodes(vec![1, 2]).execute_with(|| {
+        assert_eq!(Bootnodes::bootnodes(), vec![1, 2]);
+    });
+}
+
+/// This test verifies that adding a bootnode works in the happy path.
+#[test]
+fn add_bootnode_works() {
+    new_test_ext_with_bootnodes(vec![1]).execute_with(|| {
+        assert_ok!(Bootnodes::add_bootnode(RuntimeOrigin::root(), 2));
+
+        assert_eq!(Bootnodes::bootnodes(), vec![1, 2]);
+        System::assert_has_event(Event::BootnodeAdded { bootnode_id: 2 }.into());
+    });
+}
+
+/// This test verifies that adding a bootnode fails when it is already present.
+#[test]
+fn add_bootnode_already_present() {
+    new_test_ext_with_bootnodes(vec![1]).execute_with(|| {
+        assert_noop!(
+            Bootnodes::add_bootnode(RuntimeOrigin::root(), 1),
+            Error::<Test>::AlreadyBootnode
+        );
+    });
+}
+
+/// This test verifies that adding a bootnode fails when the limit is reached.
+#[test]
+fn add_bootnode_too_many() {
+    new_test_ext_with_bootnodes(vec![1, 2, 3]).execute_with(|| {
+        assert_noop!(
+            Bootnodes::add_bootnode(RuntimeOrigin::root(), 4),
+            Error::<Test>::TooManyBootnodes
+        );
+    });
+}
+
+/// This test verifies that removing a bootnode works in the happy path.
+#[test]
+fn remove_bootnode_works() {
+    new_test_ext_with_bootnodes(vec![1, 2, 3]).execute_with(|| {
+        assert_ok!(Bootnodes::remove_bootnode(RuntimeOrigin::root(), 2));
+
+        assert_eq!(Bootnodes::bootnodes(), vec![1, 3]);
+        System::assert_has_event(Event::BootnodeRemoved { bootnode_id: 2 }.into());
+    });
+}
+
+/// This test verifies that removing a bootnode fails when it is not present.
+#[test]
+fn remove_bootnode_not_present() {
+    new_test_ext_with_bootnodes(vec![1]).execute_with(|| {
+        assert_noop!(
+            Bootnodes::remove_bootnode(RuntimeOrigin::root(), 2),
+            Error::<Test>::NotBootnode
+        );
+    });
+}
+
+/// This test verifies that replacing the bootnodes works in the happy path.
+#[test]
+fn set_bootnodes_works() {
+    new_test_ext_with_bootnodes(vec![1, 2]).execute_with(|| {
+        let bootnodes: BoundedVec<_, _> = vec![3, 4, 5].try_into().unwrap();
+
+        assert_ok!(Bootnodes::set_bootnodes(
+            RuntimeOrigin::root(),
+            bootnodes.clone()
+        ));
+
+        assert_eq!(Bootnodes::bootnodes(), bootnodes);
+        System::assert_has_event(Event::BootnodesSet { bootnodes }.into());
+    });
+}
+
+/// This test verifies that replacing the bootnodes fails when the new list has duplicates.
+#[test]
+fn set_bootnodes_duplicates() {
+    new_test_ext_with_bootnodes(vec![1, 2]).execute_with(|| {
+        assert_noop!(
+            Bootnodes::set_bootnodes(RuntimeOrigin::root(), vec![3, 4, 3].try_into().unwrap()),
+            Error::<Test>::DuplicateBootnodes
+        );
+    });
+}
+
+/// This test verifies that the bootnodes can only be updated by the configured origin.
+#[test]
+fn bad_origin() {
+    new_test_ext_with_bootnodes(vec![1]).execute_with(|| {
+        assert_noop!(
+            Bootnodes::add_bootnode(RuntimeOrigin::signed(1), 2),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Bootnodes::remove_bootnode(RuntimeOrigin::signed(1), 1),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Bootnodes::set_bootnodes(RuntimeOrigin::signed(1), Default::default()),
+            DispatchError::BadOrigin
+        );
+    });
+}
@@ -0,0 +1,29 @@
+//! Weights definition for pallet-bootnodes.
+
+use frame_support::weights::Weight;
+
+/// Weight functions needed for pallet-bootnodes.
+pub trait WeightInfo {
+    /// A function to calculate required weights for `add_bootnode` call.
+    fn add_bootnode(b: u32) -> Weight;
+
+    /// A function to calculate required weights for `remove_bootnode` call.
+    fn remove_bootnode(b: u32) -> Weight;
+
+    /// A function to calculate required weights for `set_bootnodes` call.
+    fn set_bootnodes(b: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    fn add_bootnode(_b: u32) -> Weight {
+        Weight::zero()
+    }
+
+    fn remove_bootnode(_b: u32) -> Weight {
+        Weight::zero()
+    }
+
+    fn set_bootnodes(_b: u32) -> Weight {
+        Weight::zero()
+    }
+}
@@ -0,0 +1,405 @@
+//! A substrate pallet that applies graduated sanctions to the offending bioauth validators.
+//!
+//! Every offence adds to the offence score of the validator according to the offence kind and
+//! the slash fraction, and the score decays with the sessions passed since the last offence.
+//! The sanction escalates with the score: a warning, disabling for the rest of the session,
+//! deauthentication, and, finally, a ban on re-authenticating for a while.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Encode;
+use frame_support::{
+    traits::{ConstU32, Get, StorageVersion},
+    BoundedVec,
+};
+pub use pallet::*;
+use pallet_bioauth::{Authentication, BeforeAuthHook, CurrentMoment};
+use sp_runtime::{traits::Saturating, DispatchError, Perbill};
+use sp_staking::{
+    offence::{Kind, Offence, OffenceError, ReportOffence},
+    SessionIndex,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+/// The severity of the offences, in the offence score points.
+pub trait OffenceSeverity {
+    /// Compute the severity of the offence of the given kind, if known, and slash fraction.
+    fn severity(kind: Option<&Kind>, slash_fraction: Perbill) -> u32;
+}
+
+/// The offence score of a validator.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    scale_info::TypeInfo,
+    codec::MaxEncodedLen,
+    codec::Encode,
+    codec::Decode,
+)]
+pub struct OffenceScore {
+    /// The score as of the last offence, before the decay.
+    pub score: u32,
+    /// The total amount of the offences committed.
+    pub offences: u32,
+    /// The index of the session of the last offence.
+    pub last_session: SessionIndex,
+}
+
+/// The sanction applied for an offence.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    scale_info::TypeInfo,
+    codec::MaxEncodedLen,
+    codec::Encode,
+    codec::Decode,
+)]
+pub enum Sanction<Moment> {
+    /// The offence is only noted.
+    Warning,
+    /// The validator is disabled for the rest of the session.
+    Disable,
+    /// The validator is deauthenticated.
+    Deauthenticate,
+    /// The validator is deauthenticated and banned from authenticating again.
+    Ban {
+        /// The moment until which the validator is banned.
+        until: Moment,
+    },
+}
+
+/// The max size of the encoded time slot kept in the offence log.
+pub const MAX_TIME_SLOT_SIZE: u32 = 32;
+
+/// The encoded time slot of an offence.
+pub type OpaqueTimeSlot = BoundedVec<u8, ConstU32<MAX_TIME_SLOT_SIZE>>;
+
+/// An offence committed by a validator, as kept in the offence log.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    scale_info::TypeInfo,
+    codec::MaxEncodedLen,
+    codec::Encode,
+    codec::Decode,
+)]
+pub struct OffenceLogEntry<Moment> {
+    /// The kind of the offence, if known.
+    pub kind: Option<Kind>,
+    /// The index of the session the offence was committed at.
+    pub session_index: SessionIndex,
+    /// The encoded time slot of the offence, empty if unknown.
+    pub time_slot: OpaqueTimeSlot,
+    /// The slash fraction of the offence.
+    pub slash_fraction: Perbill,
+    /// The sanction applied for the offence.
+    pub sanction: Sanction<Moment>,
+}
+
+/// The offence log entry type for a given config.
+pub type OffenceLogEntryFor<T> = OffenceLogEntry<<T as Config>::Moment>;
+
+/// The offence report that is currently being processed.
+#[derive(Debug, Clone, PartialEq, Eq, scale_info::TypeInfo, codec::Encode, codec::Decode)]
+pub struct PendingReport<AccountId> {
+    /// The kind of the offence.
+    pub kind: Kind,
+    /// The encoded time slot of the offence.
+    pub time_slot: Vec<u8>,
+    /// The offenders that were not yet reported for this offence.
+    pub offenders: Vec<AccountId>,
+}
+
+// We have to temporarily allow some clippy lints. Later on we'll send patches to substrate to
+// fix them at their end.
+#[allow(clippy::missing_docs_in_private_items)]
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::pallet_prelude::*;
+    use sp_runtime::traits::AtLeast32Bit;
+
+    use super::*;
+
+    /// Configure the pallet by specifying the parameters and types on which it depends.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The public key of the validator.
+        type ValidatorPublicKey: Member + Parameter + MaxEncodedLen;
+
+        /// Type used for expressing timestamp.
+        type Moment: Parameter + AtLeast32Bit + Copy + MaxEncodedLen;
+
+        /// The getter for the current moment.
+        type CurrentMoment: CurrentMoment<Self::Moment>;
+
+        /// The severity of the offences.
+        type OffenceSeverity: OffenceSeverity;
+
+        /// The amount of the offence score points that decay every session.
+        type ScoreDecayPerSession: Get<u32>;
+
+        /// The offence score at which the validator is disabled for the rest of the session.
+        type DisableThreshold: Get<u32>;
+
+        /// The offence score at which the validator is deauthenticated.
+        type DeauthenticationThreshold: Get<u32>;
+
+        /// The offence score at which the validator is banned from authenticating.
+        type BanThreshold: Get<u32>;
+
+        /// The amount of time (in moments) the ban on authenticating lasts for.
+        type BanDuration: Get<Self::Moment>;
+
+        /// The max amount of the latest offences kept in the offence log of a validator.
+        type MaxOffenceLogEntries: Get<u32>;
+    }
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    /// The offence scores of the validators.
+    #[pallet::storage]
+    #[pallet::getter(fn offence_scores)]
+    pub type OffenceScores<T: Config> =
+        StorageMap<_, Twox64Concat, T::ValidatorPublicKey, OffenceScore, OptionQuery>;
+
+    /// The moments until which the validators are banned from authenticating.
+    #[pallet::storage]
+    #[pallet::getter(fn bans)]
+    pub type Bans<T: Config> =
+        StorageMap<_, Twox64Concat, T::ValidatorPublicKey, T::Moment, OptionQuery>;
+
+    /// The logs of the latest offences of the validators.
+    #[pallet::storage]
+    #[pallet::getter(fn offence_log)]
+    pub type OffenceLogs<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::ValidatorPublicKey,
+        BoundedVec<OffenceLogEntryFor<T>, T::MaxOffenceLogEntries>,
+        ValueQuery,
+    >;
+
+    /// The offence report that is currently being processed.
+    ///
+    /// Only present while the report is being processed, and never persists across the blocks.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    #[pallet::getter(fn pending_report)]
+    pub type PendingOffenceReport<T: Config> =
+        StorageValue<_, PendingReport<T::AccountId>, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The validator was sanctioned for an offence.
+        OffenceSanctioned {
+            /// The offending validator.
+            public_key: T::ValidatorPublicKey,
+            /// The kind of the offence, if known.
+            kind: Option<Kind>,
+            /// The index of the session the offence was committed at.
+            session_index: SessionIndex,
+            /// The offence score of the validator after the offence.
+            score: u32,
+            /// The sanction applied.
+            sanction: Sanction<T::Moment>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The validator is banned from authenticating.
+        Banned,
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// The offence score of the validator at the given session, with the decay applied.
+    pub fn current_score(public_key: &T::ValidatorPublicKey, session_index: SessionIndex) -> u32 {
+        <OffenceScores<T>>::get(public_key)
+            .map(|record| Self::decayed_score(&record, session_index))
+            .unwrap_or_default()
+    }
+
+    /// The moment until which the validator is banned from authenticating, if the ban is active.
+    pub fn banned_until(public_key: &T::ValidatorPublicKey) -> Option<T::Moment> {
+        let until = <Bans<T>>::get(public_key)?;
+        if until <= T::CurrentMoment::now() {
+            return None;
+        }
+        Some(until)
+    }
+
+    /// Account for the offence of the validator, and determine the sanction to apply.
+    ///
+    /// When the sanctions are not allowed, the offence is still accounted for, but only
+    /// the warning is issued.
+    pub fn note_offence(
+        public_key: T::ValidatorPublicKey,
+        kind: Option<Kind>,
+        time_slot: &[u8],
+        session_index: SessionIndex,
+        slash_fraction: Perbill,
+        allow_sanctions: bool,
+    ) -> Sanction<T::Moment> {
+        let severity = T::OffenceSeverity::severity(kind.as_ref(), slash_fraction);
+        let record = <OffenceScores<T>>::get(&public_key).unwrap_or_default();
+        let score = Self::decayed_score(&record, session_index).saturating_add(severity);
+
+        <OffenceScores<T>>::insert(
+            &public_key,
+            OffenceScore {
+                score,
+                offences: record.offences.saturating_add(1),
+                last_session: session_index.max(record.last_session),
+            },
+        );
+
+        let sanction = if allow_sanctions {
+            Self::sanction_for(score)
+        } else {
+            Sanction::Warning
+        };
+
+        if let Sanction::Ban { until } = sanction {
+            <Bans<T>>::insert(&public_key, until);
+        }
+
+        Self::log_offence(
+            &public_key,
+            OffenceLogEntry {
+                kind,
+                session_index,
+                time_slot: OpaqueTimeSlot::truncate_from(time_slot.to_vec()),
+                slash_fraction,
+                sanction: sanction.clone(),
+            },
+        );
+
+        Self::deposit_event(Event::OffenceSanctioned {
+            public_key,
+            kind,
+            session_index,
+            score,
+            sanction: sanction.clone(),
+        });
+
+        sanction
+    }
+
+    /// Append the entry to the offence log of the validator, dropping the oldest entry if
+    /// the log is full.
+    fn log_offence(public_key: &T::ValidatorPublicKey, entry: OffenceLogEntryFor<T>) {
+        <OffenceLogs<T>>::mutate(public_key, |log| {
+            if log.try_push(entry.clone()).is_err() && !log.is_empty() {
+                log.remove(0);
+                let _ = log.try_push(entry);
+            }
+        });
+    }
+
+    /// The score with the decay for the sessions passed since the last offence applied.
+    fn decayed_score(record: &OffenceScore, session_index: SessionIndex) -> u32 {
+        let sessions_passed = session_index.saturating_sub(record.last_session);
+        let decay = sessions_passed.saturating_mul(T::ScoreDecayPerSession::get());
+        record.score.saturating_sub(decay)
+    }
+
+    /// The sanction for the given offence score.
+    fn sanction_for(score: u32) -> Sanction<T::Moment> {
+        if score >= T::BanThreshold::get() {
+            let until = T::CurrentMoment::now().saturating_add(T::BanDuration::get());
+            return Sanction::Ban { until };
+        }
+        if score >= T::DeauthenticationThreshold::get() {
+            return Sanction::Deauthenticate;
+        }
+        if score >= T::DisableThreshold::get() {
+            return Sanction::Disable;
+        }
+        Sanction::Warning
+    }
+}
+
+/// The offence reporter that keeps the kind of the offence and the newly reported offenders
+/// around while the `Inner` reporter processes the report.
+///
+/// The `Inner` reporter is expected to invoke the offence handler, that can then get the report
+/// details via [`Pallet::pending_report`].
+pub struct NoteOffenceReport<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T, Inner, Reporter, FullIdentification, O>
+    ReportOffence<Reporter, (T::AccountId, FullIdentification), O> for NoteOffenceReport<T, Inner>
+where
+    T: Config,
+    O: Offence<(T::AccountId, FullIdentification)>,
+    Inner: ReportOffence<Reporter, (T::AccountId, FullIdentification), O>,
+{
+    fn report_offence(reporters: Vec<Reporter>, offence: O) -> Result<(), OffenceError> {
+        let time_slot = offence.time_slot();
+        let offenders = offence
+            .offenders()
+            .into_iter()
+            .filter(|offender| {
+                !Inner::is_known_offence(sp_std::slice::from_ref(offender), &time_slot)
+            })
+            .map(|(account_id, _)| account_id)
+            .collect();
+
+        <PendingOffenceReport<T>>::put(PendingReport {
+            kind: O::ID,
+            time_slot: time_slot.encode(),
+            offenders,
+        });
+        let result = Inner::report_offence(reporters, offence);
+        <PendingOffenceReport<T>>::kill();
+
+        result
+    }
+
+    fn is_known_offence(
+        offenders: &[(T::AccountId, FullIdentification)],
+        time_slot: &O::TimeSlot,
+    ) -> bool {
+        Inner::is_known_offence(offenders, time_slot)
+    }
+}
+
+/// The bioauth hook that rejects the authentications of the banned validators.
+pub struct RejectBanned<T>(PhantomData<T>);
+
+impl<T: Config, BlockNumber> BeforeAuthHook<T::ValidatorPublicKey, T::Moment, BlockNumber>
+    for RejectBanned<T>
+{
+    type Data = ();
+
+    fn hook(
+        authentication: &Authentication<T::ValidatorPublicKey, T::Moment, BlockNumber>,
+    ) -> Result<Self::Data, DispatchError> {
+        if <Pallet<T>>::banned_until(&authentication.public_key).is_some() {
+            return Err(<Error<T>>::Banned.into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+//! The mock for the pallet.
+
+// Allow simple integer arithmetic in tests.
+#![allow(clippy::arithmetic_side_effects)]
+
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, ConstU64},
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, Perbill,
+};
+use sp_staking::{
+    offence::{Kind, Offence, OffenceError, ReportOffence},
+    SessionIndex,
+};
+
+use crate::{self as pallet_humanode_offences, OffenceSeverity, PendingReport};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type AccountId = u64;
+
+/// The kind of the minor offence in tests.
+pub(crate) const MINOR: Kind = *b"test:minor______";
+
+/// The kind of the major offence in tests.
+pub(crate) const MAJOR: Kind = *b"test:major______";
+
+/// The duration of the ban in tests.
+pub(crate) const BAN_DURATION: u64 = 100;
+
+/// The max amount of the offence log entries in tests.
+pub(crate) const MAX_OFFENCE_LOG_ENTRIES: u32 = 2;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub struct Test
+    where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        HumanodeOffences: pallet_humanode_offences,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+    pub static Now: u64 = 0;
+    pub static KnownOffenders: Vec<AccountId> = vec![];
+    pub static ObservedReport: Option<PendingReport<AccountId>> = None;
+}
+
+/// The current moment that reports [`Now`].
+pub struct TestCurrentMoment;
+
+impl pallet_bioauth::CurrentMoment<u64> for TestCurrentMoment {
+    fn now() -> u64 {
+        Now::get()
+    }
+}
+
+/// The severity of the offences in tests.
+pub struct TestOffenceSeverity;
+
+impl OffenceSeverity for TestOffenceSeverity {
+    fn severity(kind: Option<&Kind>, slash_fraction: Perbill) -> u32 {
+        let base = match kind {
+            Some(&MINOR) => 1,
+            Some(&MAJOR) => 5,
+            _ => 3,
+        };
+        base + slash_fraction.mul_floor(10u32)
+    }
+}
+
+impl pallet_humanode_offences::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorPublicKey = AccountId;
+    type Moment = u64;
+    type CurrentMoment = TestCurrentMoment;
+    type OffenceSeverity = TestOffenceSeverity;
+    type ScoreDecayPerSession = ConstU32<1>;
+    type DisableThreshold = ConstU32<2>;
+    type DeauthenticationThreshold = ConstU32<5>;
+    type BanThreshold = ConstU32<10>;
+    type BanDuration = ConstU64<BAN_DURATION>;
+    type MaxOffenceLogEntries = ConstU32<MAX_OFFENCE_LOG_ENTRIES>;
+}
+
+/// A minor offence to report in tests.
+pub struct TestOffence {
+    /// The offenders.
+    pub offenders: Vec<(AccountId, ())>,
+}
+
+impl Offence<(AccountId, ())> for TestOffence {
+    const ID: Kind = MINOR;
+    type TimeSlot = u64;
+
+    fn offenders(&self) -> Vec<(AccountId, ())> {
+        self.offenders.clone()
+    }
+
+    fn session_index(&self) -> SessionIndex {
+        0
+    }
+
+    fn validator_set_count(&self) -> u32 {
+        0
+    }
+
+    fn time_slot(&self) -> Self::TimeSlot {
+        0
+    }
+
+    fn slash_fraction(&self, _offenders_count: u32) -> Perbill {
+        Perbill::zero()
+    }
+}
+
+/// The reporter that considers the [`KnownOffenders`] as already reported, and observes
+/// the pending report into the [`ObservedReport`].
+pub struct TestReporter;
+
+impl ReportOffence<AccountId, (AccountId, ()), TestOffence> for TestReporter {
+    fn report_offence(
+        _reporters: Vec<AccountId>,
+        _offence: TestOffence,
+    ) -> Result<(), OffenceError> {
+        ObservedReport::set(HumanodeOffences::pending_report());
+        Ok(())
+    }
+
+    fn is_known_offence(offenders: &[(AccountId, ())], _time_slot: &u64) -> bool {
+        let known_offenders = KnownOffenders::get();
+        offenders
+            .iter()
+            .all(|(offender, _)| known_offenders.contains(offender))
+    }
+}
+
+/// Build test externalities from the default genesis.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = GenesisConfig::default().build_storage().unwrap();
+
+    // Make test externalities from the storage.
+    let mut ext: sp_io::TestExternalities = storage.into();
+    // Events are not deposited at the genesis block.
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,231 @@
+//! The tests for the pallet.
+
+// Allow simple integer arithmetic in tests.
+#![allow(clippy::arithmetic_side_effects)]
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Perbill;
+
+use crate::{mock::*, *};
+
+/// Build an authentication for the given validator.
+fn authentication(public_key: AccountId) -> Authentication<AccountId, u64, u64> {
+    Authentication {
+        public_key,
+        expires_at: 1000,
+        authenticated_at: 0,
+        authenticated_at_block: 0,
+        robonode_public_key_id: Default::default(),
+    }
+}
+
+/// This test verifies that a single minor offence only results in a warning.
+#[test]
+fn minor_offence_is_warned() {
+    new_test_ext().execute_with(|| {
+        // Invoke the function under test.
+        let sanction =
+            HumanodeOffences::note_offence(1, Some(MINOR), &[], 0, Perbill::zero(), true);
+
+        // Assert state changes.
+        assert_eq!(sanction, Sanction::Warning);
+        assert_eq!(
+            HumanodeOffences::offence_scores(1),
+            Some(OffenceScore {
+                score: 1,
+                offences: 1,
+                last_session: 0,
+            })
+        );
+        System::assert_has_event(RuntimeEvent::HumanodeOffences(Event::OffenceSanctioned {
+            public_key: 1,
+            kind: Some(MINOR),
+            session_index: 0,
+            score: 1,
+            sanction: Sanction::Warning,
+        }));
+    });
+}
+
+/// This test verifies that the repeated offences escalate the sanctions up to the ban.
+#[test]
+fn repeated_offences_escalate() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        Now::set(50);
+
+        // Invoke the function under test and assert the sanctions.
+        assert_eq!(
+            HumanodeOffences::note_offence(1, Some(MINOR), &[], 0, Perbill::zero(), true),
+            Sanction::Warning
+        );
+        assert_eq!(
+            HumanodeOffences::note_offence(1, Some(MINOR), &[], 0, Perbill::zero(), true),
+            Sanction::Disable
+        );
+        assert_eq!(
+            HumanodeOffences::note_offence(1, Some(MAJOR), &[], 0, Perbill::zero(), true),
+            Sanction::Deauthenticate
+        );
+        assert_eq!(
+            HumanodeOffences::note_offence(1, Some(MAJOR), &[], 0, Perbill::zero(), true),
+            Sanction::Ban {
+                until: 50 + BAN_DURATION
+            }
+        );
+
+        // Assert state changes.
+        assert_eq!(HumanodeOffences::current_score(&1, 0), 12);
+        assert_eq!(HumanodeOffences::bans(1), Some(50 + BAN_DURATION));
+        assert_eq!(HumanodeOffences::banned_until(&1), Some(50 + BAN_DURATION));
+    });
+}
+
+/// This test verifies that the slash fraction contributes to the severity of the offence.
+#[test]
+fn slash_fraction_increases_severity() {
+    new_test_ext().execute_with(|| {
+        // Invoke the function under test.
+        let sanction =
+            HumanodeOffences::note_offence(1, Some(MINOR), &[], 0, Perbill::from_percent(50), true);
+
+        // Assert state changes.
+        assert_eq!(sanction, Sanction::Deauthenticate);
+        assert_eq!(HumanodeOffences::current_score(&1, 0), 6);
+    });
+}
+
+/// This test verifies that the offence score decays with the sessions passed.
+#[test]
+fn offence_score_decays() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        HumanodeOffences::note_offence(1, Some(MINOR), &[], 0, Perbill::zero(), true);
+        HumanodeOffences::note_offence(1, Some(MINOR), &[], 0, Perbill::zero(), true);
+        assert_eq!(HumanodeOffences::current_score(&1, 0), 2);
+        assert_eq!(HumanodeOffences::current_score(&1, 1), 1);
+        assert_eq!(HumanodeOffences::current_score(&1, 5), 0);
+
+        // Invoke the function under test.
+        let sanction =
+            HumanodeOffences::note_offence(1, Some(MINOR), &[], 5, Perbill::zero(), true);
+
+        // Assert state changes.
+        assert_eq!(sanction, Sanction::Warning);
+        assert_eq!(
+            HumanodeOffences::offence_scores(1),
+            Some(OffenceScore {
+                score: 1,
+                offences: 3,
+                last_session: 5,
+            })
+        );
+    });
+}
+
+/// This test verifies that only the warning is issued when the sanctions are not allowed, but
+/// the offence is still accounted for.
+#[test]
+fn sanctions_not_allowed() {
+    new_test_ext().execute_with(|| {
+        // Invoke the function under test.
+        let sanction = HumanodeOffences::note_offence(1, None, &[], 0, Perbill::one(), false);
+
+        // Assert state changes.
+        assert_eq!(sanction, Sanction::Warning);
+        assert_eq!(HumanodeOffences::current_score(&1, 0), 13);
+        assert_eq!(HumanodeOffences::bans(1), None);
+    });
+}
+
+/// This test verifies that the banned validators can not authenticate until the ban expires.
+#[test]
+fn banned_validator_is_rejected() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        HumanodeOffences::note_offence(1, None, &[], 0, Perbill::one(), true);
+        assert_eq!(HumanodeOffences::bans(1), Some(BAN_DURATION));
+
+        // Assert the ban is in effect.
+        assert_noop!(
+            <RejectBanned<Test> as BeforeAuthHook<_, _, _>>::hook(&authentication(1)),
+            Error::<Test>::Banned
+        );
+        assert_ok!(<RejectBanned<Test> as BeforeAuthHook<_, _, _>>::hook(
+            &authentication(2)
+        ));
+
+        // Assert the ban expires.
+        Now::set(BAN_DURATION);
+        assert_eq!(HumanodeOffences::banned_until(&1), None);
+        assert_ok!(<RejectBanned<Test> as BeforeAuthHook<_, _, _>>::hook(
+            &authentication(1)
+        ));
+    });
+}
+
+/// This test verifies that the offence report details are available while the report is
+/// being processed, and only include the newly reported offenders.
+#[test]
+fn pending_report_is_noted() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        KnownOffenders::set(vec![1]);
+
+        // Invoke the function under test.
+        let offence = TestOffence {
+            offenders: vec![(1, ()), (2, ())],
+        };
+        assert_ok!(<NoteOffenceReport<Test, TestReporter> as ReportOffence<
+            AccountId,
+            _,
+            _,
+        >>::report_offence(vec![], offence));
+
+        // Assert state changes.
+        assert_eq!(
+            ObservedReport::get(),
+            Some(PendingReport {
+                kind: MINOR,
+                time_slot: 0u64.encode(),
+                offenders: vec![2],
+            })
+        );
+        assert_eq!(HumanodeOffences::pending_report(), None);
+    });
+}
+
+/// This test verifies that the offences are logged, and only the latest ones are kept.
+#[test]
+fn offences_are_logged() {
+    new_test_ext().execute_with(|| {
+        // Invoke the function under test.
+        HumanodeOffences::note_offence(1, Some(MINOR), &[1], 0, Perbill::zero(), true);
+        HumanodeOffences::note_offence(1, Some(MINOR), &[2], 0, Perbill::zero(), true);
+        HumanodeOffences::note_offence(1, Some(MAJOR), &[3], 1, Perbill::one(), true);
+
+        // Assert state changes.
+        assert_eq!(
+            HumanodeOffences::offence_log(1).into_inner(),
+            vec![
+                OffenceLogEntry {
+                    kind: Some(MINOR),
+                    session_index: 0,
+                    time_slot: OpaqueTimeSlot::truncate_from(vec![2]),
+                    slash_fraction: Perbill::zero(),
+                    sanction: Sanction::Disable,
+                },
+                OffenceLogEntry {
+                    kind: Some(MAJOR),
+                    session_index: 1,
+                    time_slot: OpaqueTimeSlot::truncate_from(vec![3]),
+                    slash_fraction: Perbill::one(),
+                    sanction: Sanction::Ban {
+                        until: BAN_DURATION
+                    },
+                },
+            ]
+        );
+        assert!(HumanodeOffences::offence_log(2).is_empty());
+    });
+}
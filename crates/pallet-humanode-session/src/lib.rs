@@ -8,12 +8,21 @@ use sp_runtime::traits::Convert;
 use sp_std::prelude::*;
 
 mod migrations;
+pub mod selector;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub use selector::ValidatorSelector;
 
 /// The type representing the session index in our chain.
 type SessionIndex = u32;
 
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 // We have to temporarily allow some clippy lints. Later on we'll send patches to substrate to
 // fix them at their end.
@@ -34,6 +43,9 @@ pub mod pallet {
         + pallet_bioauth::Config
         + pallet_bootnodes::Config
     {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
         /// The type for converting the key that `pallet_bioauth` uses into the key that session
         /// requires.
         /// Typically it will be a no-op, or an identity converter.
@@ -50,6 +62,21 @@ pub mod pallet {
 
         /// The max amount of bioauth-powered session validators.
         type MaxBioauthValidators: Get<u32>;
+
+        /// The selector of the bioauth-powered session validators, used when there are more
+        /// authenticated candidates than [`Config::MaxBioauthValidators`].
+        type ValidatorSelector: ValidatorSelector<IdentificationTupleFor<Self>>;
+
+        /// The amount of the past sessions to keep the [`SessionIdentities`] for, in addition to
+        /// the current and the next ones.
+        type SessionIdentitiesRetention: Get<u32>;
+
+        /// The minimum size of the session validator set.
+        ///
+        /// When the planned set falls below it, the set is padded with the bootnodes left out
+        /// due to the [`Config::MaxBootnodeValidators`], or the authorities of the previous
+        /// session are kept.
+        type MinValidatorSetSize: Get<u32>;
     }
 
     #[pallet::pallet]
@@ -72,20 +99,39 @@ pub mod pallet {
     #[pallet::storage]
     pub type CurrentSessionIndex<T: Config> = StorageValue<_, SessionIndex, OptionQuery>;
 
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The planned validator set has fallen below the minimum size.
+        ValidatorSetBelowMinimum {
+            /// The index of the planned session.
+            session_index: SessionIndex,
+            /// The size of the validator set as computed from the bootnodes and
+            /// the authentications.
+            computed_size: u32,
+            /// The minimum size of the validator set.
+            minimum: u32,
+            /// How the validator set was dealt with.
+            resolution: BelowMinimumResolution,
+        },
+    }
+
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_runtime_upgrade() -> Weight {
-            migrations::v1::migrate::<T>()
+            let mut weight = migrations::v1::migrate::<T>();
+            weight.saturating_accrue(migrations::v2::migrate::<T>());
+            weight
         }
 
         #[cfg(feature = "try-runtime")]
         fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
-            Ok(migrations::v1::pre_migrate::<T>())
+            Ok(migrations::v2::pre_migrate::<T>())
         }
 
         #[cfg(feature = "try-runtime")]
         fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
-            migrations::v1::post_migrate::<T>(state);
+            migrations::v2::post_migrate::<T>(state);
             Ok(())
         }
     }
@@ -110,11 +156,29 @@ pub enum Identification<Bootnode, Bioauth> {
     Bioauth(Bioauth),
 }
 
+/// The way the planned validator set falling below the minimum size was dealt with.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    scale_info::TypeInfo,
+    codec::MaxEncodedLen,
+    codec::Encode,
+    codec::Decode,
+)]
+pub enum BelowMinimumResolution {
+    /// The validator set was padded with the bootnodes left out due to the bootnode validators
+    /// cap.
+    PaddedWithBootnodes,
+    /// The authorities of the previous session were kept.
+    KeptPreviousAuthorities,
+    /// Nothing could make the validator set bigger, so it was left as is.
+    Unresolved,
+}
+
 /// The bioauth authentication type for a given config.
-pub type BioauthAuthenticationFor<T> = pallet_bioauth::Authentication<
-    <T as pallet_bioauth::Config>::ValidatorPublicKey,
-    <T as pallet_bioauth::Config>::Moment,
->;
+pub type BioauthAuthenticationFor<T> = pallet_bioauth::AuthenticationFor<T>;
 
 /// The bootnode id type for a given config.
 pub type BootnodeIdFor<T> = <T as pallet_bootnodes::Config>::BootnodeId;
@@ -127,7 +191,9 @@ pub type IdentificationTupleFor<T> = (<T as frame_system::Config>::AccountId, Id
 
 impl<T: Config> Pallet<T> {
     /// Compute the list of the authorities, for use at new session planning.
-    fn next_authorities() -> impl Iterator<Item = IdentificationTupleFor<T>> {
+    fn next_authorities(
+        session_index: SessionIndex,
+    ) -> impl Iterator<Item = IdentificationTupleFor<T>> {
         let bootnodes = <pallet_bootnodes::Pallet<T>>::bootnodes()
             .into_iter()
             .take(T::MaxBootnodeValidators::get().try_into().unwrap())
@@ -138,16 +204,77 @@ impl<T: Config> Pallet<T> {
                 )
             });
 
-        let bioauth_active_authentications = <pallet_bioauth::Pallet<T>>::active_authentications()
+        let bioauth_candidates = <pallet_bioauth::Pallet<T>>::active_authentications()
             .into_inner()
             .into_iter()
-            .take(T::MaxBioauthValidators::get().try_into().unwrap())
             .filter_map(|authentication| {
                 T::ValidatorPublicKeyOf::convert(authentication.public_key.clone())
                     .map(|account_id| (account_id, Identification::Bioauth(authentication)))
-            });
+            })
+            .collect::<Vec<_>>();
 
-        bootnodes.chain(bioauth_active_authentications)
+        let bioauth_validators = T::ValidatorSelector::select(
+            session_index,
+            bioauth_candidates,
+            T::MaxBioauthValidators::get().try_into().unwrap(),
+        );
+
+        bootnodes.chain(bioauth_validators)
+    }
+
+    /// Compute the list of the authorities for a new session, making sure it does not fall below
+    /// the [`Config::MinValidatorSetSize`] whenever possible.
+    fn plan_authorities(session_index: SessionIndex) -> Vec<IdentificationTupleFor<T>> {
+        let mut authorities = Self::next_authorities(session_index).collect::<Vec<_>>();
+
+        let minimum = T::MinValidatorSetSize::get();
+        let minimum_len: usize = minimum.try_into().unwrap();
+        let computed_len = authorities.len();
+        if computed_len >= minimum_len {
+            return authorities;
+        }
+
+        // Pad with the bootnodes that did not fit into the bootnode validators cap.
+        let padding = <pallet_bootnodes::Pallet<T>>::bootnodes()
+            .into_iter()
+            .skip(T::MaxBootnodeValidators::get().try_into().unwrap())
+            .take(minimum_len.saturating_sub(computed_len))
+            .map(|id| {
+                (
+                    T::BootnodeIdOf::convert(id.clone()),
+                    Identification::Bootnode(id),
+                )
+            });
+        authorities.extend(padding);
+
+        let resolution = if authorities.len() >= minimum_len {
+            BelowMinimumResolution::PaddedWithBootnodes
+        } else {
+            // The previous session is always retained, as it is the next one to start.
+            let previous_authorities = session_index
+                .checked_sub(1)
+                .map(Self::session_identities)
+                .unwrap_or_default();
+
+            if previous_authorities.len() > authorities.len() {
+                authorities = previous_authorities;
+                BelowMinimumResolution::KeptPreviousAuthorities
+            } else if authorities.len() > computed_len {
+                BelowMinimumResolution::PaddedWithBootnodes
+            } else {
+                BelowMinimumResolution::Unresolved
+            }
+        };
+
+        Self::deposit_event(Event::ValidatorSetBelowMinimum {
+            session_index,
+            // The authorities are bounded by `u32`, so this never saturates in practice.
+            computed_size: computed_len.try_into().unwrap_or(u32::MAX),
+            minimum,
+            resolution,
+        });
+
+        authorities
     }
 
     /// Clears and re-populates the [`SessionIdentities`] for a given session with the entries.
@@ -162,6 +289,22 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Get the identities of the authorities of a given session.
+    ///
+    /// Only the current, the next and the retained past sessions are known, for the rest of them
+    /// the list is empty.
+    pub fn session_identities(session_index: SessionIndex) -> Vec<IdentificationTupleFor<T>> {
+        <SessionIdentities<T>>::iter_prefix(session_index).collect()
+    }
+
+    /// Get the identification of a given account at a given session.
+    pub fn session_identification(
+        session_index: SessionIndex,
+        account_id: &T::AccountId,
+    ) -> Option<IdentificationFor<T>> {
+        <SessionIdentities<T>>::get(session_index, account_id)
+    }
+
     /// Clears the [`SessionIdentities`] for a given session.
     fn clear_session_identities(session_index: u32) {
         // TODO(#388): switch to `clear_prefix` after the API is fixed.
@@ -175,7 +318,7 @@ impl<T: Config> pallet_session::historical::SessionManager<T::AccountId, Identif
 {
     fn new_session(new_index: u32) -> Option<Vec<IdentificationTupleFor<T>>> {
         // Compute the next list of the authorities.
-        let next_authorities = Self::next_authorities().collect::<Vec<_>>();
+        let next_authorities = Self::plan_authorities(new_index);
 
         // Set the list of authorities for the current session.
         Self::update_session_identities(new_index, next_authorities.iter());
@@ -188,25 +331,34 @@ impl<T: Config> pallet_session::historical::SessionManager<T::AccountId, Identif
     }
 
     fn end_session(end_index: u32) {
-        Self::clear_session_identities(end_index);
+        // Drop the identities of the session that has just left the retention window.
+        if let Some(expired_index) = end_index.checked_sub(T::SessionIdentitiesRetention::get()) {
+            Self::clear_session_identities(expired_index);
+        }
         <CurrentSessionIndex<T>>::kill();
     }
 }
 
-// In fact, the [`pallet_session::historical::SessionManager`] should not require the
-// [`pallet_session::SessionManager`] implementation - this is a substrate design error.
-// So, we implement it as unreachable.
+// The plain session manager, for use without the historical sessions.
+// Behaves exactly like the historical one, but only reports the account ids of the authorities.
 impl<T: Config> pallet_session::SessionManager<T::AccountId> for Pallet<T> {
-    fn new_session(_new_index: u32) -> Option<Vec<T::AccountId>> {
-        unreachable!()
+    fn new_session(new_index: u32) -> Option<Vec<T::AccountId>> {
+        <Self as pallet_session::historical::SessionManager<_, _>>::new_session(new_index).map(
+            |authorities| {
+                authorities
+                    .into_iter()
+                    .map(|(account_id, _)| account_id)
+                    .collect()
+            },
+        )
     }
 
-    fn end_session(_end_index: u32) {
-        unreachable!()
+    fn end_session(end_index: u32) {
+        <Self as pallet_session::historical::SessionManager<_, _>>::end_session(end_index)
     }
 
-    fn start_session(_start_index: u32) {
-        unreachable!()
+    fn start_session(start_index: u32) {
+        <Self as pallet_session::historical::SessionManager<_, _>>::start_session(start_index)
     }
 }
 
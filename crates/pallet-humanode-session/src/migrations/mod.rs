@@ -1,3 +1,4 @@
 //! State migrations.
 
 pub mod v1;
+pub mod v2;
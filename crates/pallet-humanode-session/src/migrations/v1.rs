@@ -21,7 +21,6 @@ pub type CurrentSessionIdentities<T: Config> = StorageMap<
 
 /// Migrate from version 0 to 1.
 pub fn migrate<T: Config>() -> Weight {
-    let current = <Pallet<T>>::current_storage_version();
     let onchain = <Pallet<T>>::on_chain_storage_version();
 
     // Read the onchain version.
@@ -29,7 +28,7 @@ pub fn migrate<T: Config>() -> Weight {
 
     info!(message = "Running migration to v1", from = ?onchain);
 
-    if onchain == 1 {
+    if onchain >= 1 {
         info!(message = "Already at version 1, nothing to do");
         return weight;
     }
@@ -50,7 +49,7 @@ pub fn migrate<T: Config>() -> Weight {
     });
 
     // Set new version.
-    current.put::<Pallet<T>>();
+    StorageVersion::new(1).put::<Pallet<T>>();
 
     // Write the onchain version.
     weight = weight.saturating_add(T::DbWeight::get().writes(1));
@@ -0,0 +1,191 @@
+//! Migration to Version 2.
+
+use frame_support::pallet_prelude::*;
+use frame_support::{dispatch::GetStorageVersion, sp_tracing::info, traits::Get, weights::Weight};
+use pallet_bioauth::ExpiryPolicy;
+use sp_runtime::traits::Saturating;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use crate::{BootnodeIdFor, Config, Identification, IdentificationFor, Pallet, SessionIdentities};
+
+/// The Version 1 bioauth authentication, before the authentication metadata was introduced.
+#[derive(Encode, Decode)]
+pub struct OldBioauthAuthentication<PublicKey, Moment> {
+    /// The public key of a validator.
+    pub public_key: PublicKey,
+    /// The moment at which the authentication becomes expired.
+    pub expires_at: Moment,
+}
+
+/// The Version 1 identification type for a given config.
+type OldIdentificationFor<T> = Identification<
+    BootnodeIdFor<T>,
+    OldBioauthAuthentication<
+        <T as pallet_bioauth::Config>::ValidatorPublicKey,
+        <T as pallet_bioauth::Config>::Moment,
+    >,
+>;
+
+/// Migrate from version 1 to 2.
+pub fn migrate<T: Config>() -> Weight {
+    let current = <Pallet<T>>::current_storage_version();
+    let onchain = <Pallet<T>>::on_chain_storage_version();
+
+    // Read the onchain version.
+    let mut weight: Weight = T::DbWeight::get().reads(1);
+
+    info!(message = "Running migration to v2", from = ?onchain);
+
+    if onchain >= 2 {
+        info!(message = "Already at version 2, nothing to do");
+        return weight;
+    }
+
+    // We don't know at which block the old authentications have happened, so we use
+    // the current one as the closest known estimation.
+    let current_block = <frame_system::Pallet<T>>::block_number();
+    // We don't know how many times the public keys were authenticated before, so we use
+    // the expiry period of the first authentication with the initial robonode key.
+    let expire_after = <T as pallet_bioauth::Config>::ExpiryPolicy::expire_after(0, 0);
+    // Read the expiry period.
+    weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+    // Extend the bioauth identities with the authentication metadata.
+    <SessionIdentities<T>>::translate_values(|old: OldIdentificationFor<T>| {
+        // Read the old value and write the new one.
+        weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+        let new: IdentificationFor<T> = match old {
+            Identification::Bootnode(bootnode_id) => Identification::Bootnode(bootnode_id),
+            Identification::Bioauth(old) => {
+                Identification::Bioauth(pallet_bioauth::Authentication {
+                    public_key: old.public_key,
+                    expires_at: old.expires_at,
+                    authenticated_at: old.expires_at.saturating_sub(expire_after),
+                    authenticated_at_block: current_block,
+                    // The old authentications could only be vetted by the initial robonode key.
+                    robonode_public_key_id: 0,
+                })
+            }
+        };
+        Some(new)
+    });
+
+    // Set new version.
+    current.put::<Pallet<T>>();
+
+    // Write the onchain version.
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    // Done.
+    weight
+}
+
+/// Check the state before the migration.
+///
+/// Panics if anything goes wrong.
+#[cfg(feature = "try-runtime")]
+pub fn pre_migrate<T: Config>() -> Vec<u8> {
+    // Record the count of identities.
+    let identities_count: u64 = <SessionIdentities<T>>::iter_keys()
+        .count()
+        .try_into()
+        .unwrap();
+    identities_count.encode()
+}
+
+/// Check the state after the migration.
+///
+/// Panics if anything goes wrong.
+#[cfg(feature = "try-runtime")]
+pub fn post_migrate<T: Config>(state: Vec<u8>) {
+    // Ensure version is updated correctly.
+    let onchain = <Pallet<T>>::on_chain_storage_version();
+    assert_eq!(onchain, 2);
+
+    // Ensure the identities count matches.
+    let new_identities_count: u64 = <SessionIdentities<T>>::iter().count().try_into().unwrap();
+    let old_identities_count: u64 = codec::Decode::decode(&mut &*state).unwrap();
+    assert_eq!(new_identities_count, old_identities_count);
+}
+
+#[cfg(test)]
+mod tests {
+    use frame_support::storage_alias;
+
+    use super::*;
+    use crate::mock::*;
+
+    /// The Version 1 session identities storage.
+    #[storage_alias]
+    type SessionIdentities = StorageDoubleMap<
+        HumanodeSession,
+        Twox64Concat,
+        u32,
+        Twox64Concat,
+        AccountId,
+        Identification<AccountId, OldBioauthAuthentication<AccountId, u64>>,
+        OptionQuery,
+    >;
+
+    #[test]
+    fn migrates_session_identities() {
+        new_test_ext_with(true, vec![1], vec![10]).execute_with(|| {
+            // Prepare the old state.
+            StorageVersion::new(1).put::<HumanodeSession>();
+            System::set_block_number(10);
+            HumanodeSession::clear_session_identities(0);
+            HumanodeSession::clear_session_identities(1);
+            SessionIdentities::insert(0, 1, Identification::Bootnode(1));
+            SessionIdentities::insert(
+                0,
+                10,
+                Identification::Bioauth(OldBioauthAuthentication {
+                    public_key: 10,
+                    expires_at: 1500,
+                }),
+            );
+
+            // Invoke the function under test.
+            migrate::<Test>();
+
+            // Assert state changes.
+            assert_eq!(HumanodeSession::on_chain_storage_version(), 2);
+            assert_eq!(
+                HumanodeSession::session_identities(0).len(),
+                2,
+                "the identities must not be lost"
+            );
+            assert_eq!(
+                HumanodeSession::session_identification(0, &1),
+                Some(Identification::Bootnode(1))
+            );
+            assert_eq!(
+                HumanodeSession::session_identification(0, &10),
+                Some(Identification::Bioauth(pallet_bioauth::Authentication {
+                    public_key: 10,
+                    expires_at: 1500,
+                    authenticated_at: 500,
+                    authenticated_at_block: 10,
+                    robonode_public_key_id: 0,
+                }))
+            );
+        });
+    }
+
+    #[test]
+    fn skips_when_already_migrated() {
+        new_test_ext_with(true, vec![1], vec![10]).execute_with(|| {
+            // Prepare the state.
+            let identities = HumanodeSession::session_identities(0);
+
+            // Invoke the function under test.
+            migrate::<Test>();
+
+            // Assert the state is not changed.
+            assert_eq!(HumanodeSession::on_chain_storage_version(), 2);
+            assert_eq!(HumanodeSession::session_identities(0), identities);
+        });
+    }
+}
@@ -0,0 +1,294 @@
+//! The mock for the pallet.
+
+// Allow simple integer arithmetic in tests.
+#![allow(clippy::arithmetic_side_effects)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, ConstU64},
+    BoundedVec,
+};
+use frame_system::EnsureRoot;
+use pallet_bioauth::{AuthTicket, Authentication, TryConvert};
+use pallet_session::historical as pallet_session_historical;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_core::{crypto::Infallible, H256};
+use sp_runtime::{
+    testing::{Header, UintAuthorityId},
+    traits::{BlakeTwo256, ConvertInto, IdentityLookup, OpaqueKeys},
+    BuildStorage, KeyTypeId,
+};
+
+use crate::{self as pallet_humanode_session, selector::FirstCandidates};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type AccountId = u64;
+
+/// The max amount of bootnodes contributing to the session validators in tests.
+pub(crate) const MAX_BOOTNODE_VALIDATORS: u32 = 1;
+
+/// The max amount of bioauth-powered session validators in tests.
+pub(crate) const MAX_BIOAUTH_VALIDATORS: u32 = 3;
+
+/// The regular session period in tests.
+pub(crate) const SESSION_PERIOD: u64 = 10;
+
+/// The amount of the past sessions to keep the identities for in tests.
+pub(crate) const SESSION_IDENTITIES_RETENTION: u32 = 2;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub struct Test
+    where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Bootnodes: pallet_bootnodes,
+        Bioauth: pallet_bioauth,
+        Session: pallet_session,
+        Historical: pallet_session_historical,
+        HumanodeSession: pallet_humanode_session,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_bootnodes::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxBootnodes = ConstU32<3>;
+    type BootnodeId = AccountId;
+    type UpdateBootnodesOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
+#[derive(PartialEq, Eq, Default, Clone, Encode, Decode, MaxEncodedLen, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MockVerifier;
+
+impl pallet_bioauth::Verifier<Vec<u8>> for MockVerifier {
+    type Error = Infallible;
+
+    fn verify<'a, D>(&self, _data: D, _signature: Vec<u8>) -> Result<bool, Self::Error>
+    where
+        D: AsRef<[u8]> + Send + 'a,
+    {
+        panic!("should be unused in tests")
+    }
+}
+
+pub struct MockAuthTicketConverter;
+
+impl TryConvert<Vec<u8>, AuthTicket<AccountId>> for MockAuthTicketConverter {
+    type Error = Infallible;
+
+    fn try_convert(_value: Vec<u8>) -> Result<AuthTicket<AccountId>, Self::Error> {
+        panic!("should be unused in tests")
+    }
+}
+
+parameter_types! {
+    pub static CurrentMoment: u64 = 0;
+}
+
+pub struct MockCurrentMoment;
+
+impl pallet_bioauth::CurrentMoment<u64> for MockCurrentMoment {
+    fn now() -> u64 {
+        CurrentMoment::get()
+    }
+}
+
+impl pallet_bioauth::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RobonodePublicKey = MockVerifier;
+    type RobonodeSignature = Vec<u8>;
+    type ValidatorPublicKey = AccountId;
+    type OpaqueAuthTicket = Vec<u8>;
+    type AuthTicketCoverter = MockAuthTicketConverter;
+    type ValidatorSetUpdater = ();
+    type Moment = u64;
+    type DisplayMoment = u64;
+    type CurrentMoment = MockCurrentMoment;
+    type ExpiryPolicy = pallet_bioauth::ExpireAfter<ConstU64<1000>>;
+    type ExpiryWarningPeriod = ConstU64<0>;
+    type WeightInfo = ();
+    type MaxAuthentications = ConstU32<8>;
+    type MaxNonces = ConstU32<8>;
+    type BeforeAuthHook = ();
+    type AfterAuthHook = ();
+    type DeauthenticationReason = ();
+}
+
+parameter_types! {
+    pub static HistoricalWiring: bool = true;
+    pub static MinValidatorSetSize: u32 = 0;
+}
+
+/// The regular periodic sessions.
+type PeriodicSessions = pallet_session::PeriodicSessions<ConstU64<SESSION_PERIOD>, ConstU64<0>>;
+
+/// The session manager that wires the pallet either with or without the historical sessions,
+/// depending on the [`HistoricalWiring`].
+pub struct TestSessionManager;
+
+/// The pallet wired with the historical sessions.
+type HistoricalSessionManager =
+    pallet_session_historical::NoteHistoricalRoot<Test, HumanodeSession>;
+
+impl pallet_session::SessionManager<AccountId> for TestSessionManager {
+    fn new_session(new_index: u32) -> Option<Vec<AccountId>> {
+        if HistoricalWiring::get() {
+            <HistoricalSessionManager as pallet_session::SessionManager<_>>::new_session(new_index)
+        } else {
+            <HumanodeSession as pallet_session::SessionManager<_>>::new_session(new_index)
+        }
+    }
+
+    fn new_session_genesis(new_index: u32) -> Option<Vec<AccountId>> {
+        if HistoricalWiring::get() {
+            <HistoricalSessionManager as pallet_session::SessionManager<_>>::new_session_genesis(
+                new_index,
+            )
+        } else {
+            <HumanodeSession as pallet_session::SessionManager<_>>::new_session_genesis(new_index)
+        }
+    }
+
+    fn end_session(end_index: u32) {
+        if HistoricalWiring::get() {
+            <HistoricalSessionManager as pallet_session::SessionManager<_>>::end_session(end_index)
+        } else {
+            <HumanodeSession as pallet_session::SessionManager<_>>::end_session(end_index)
+        }
+    }
+
+    fn start_session(start_index: u32) {
+        if HistoricalWiring::get() {
+            <HistoricalSessionManager as pallet_session::SessionManager<_>>::start_session(
+                start_index,
+            )
+        } else {
+            <HumanodeSession as pallet_session::SessionManager<_>>::start_session(start_index)
+        }
+    }
+}
+
+/// The session handler that does nothing.
+pub struct TestSessionHandler;
+
+impl pallet_session::SessionHandler<AccountId> for TestSessionHandler {
+    const KEY_TYPE_IDS: &'static [KeyTypeId] = &[sp_runtime::key_types::DUMMY];
+
+    fn on_genesis_session<Ks: OpaqueKeys>(_validators: &[(AccountId, Ks)]) {}
+
+    fn on_new_session<Ks: OpaqueKeys>(
+        _changed: bool,
+        _validators: &[(AccountId, Ks)],
+        _queued_validators: &[(AccountId, Ks)],
+    ) {
+    }
+
+    fn on_disabled(_validator_index: u32) {}
+}
+
+impl pallet_session::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorId = AccountId;
+    type ValidatorIdOf = ConvertInto;
+    type ShouldEndSession = PeriodicSessions;
+    type NextSessionRotation = PeriodicSessions;
+    type SessionManager = TestSessionManager;
+    type SessionHandler = TestSessionHandler;
+    type Keys = UintAuthorityId;
+    type WeightInfo = ();
+}
+
+impl pallet_session_historical::Config for Test {
+    type FullIdentification = crate::IdentificationFor<Self>;
+    type FullIdentificationOf = crate::CurrentSessionIdentificationOf<Self>;
+}
+
+impl pallet_humanode_session::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorPublicKeyOf = ConvertInto;
+    type BootnodeIdOf = sp_runtime::traits::Identity;
+    type MaxBootnodeValidators = ConstU32<MAX_BOOTNODE_VALIDATORS>;
+    type MaxBioauthValidators = ConstU32<MAX_BIOAUTH_VALIDATORS>;
+    type ValidatorSelector = FirstCandidates;
+    type SessionIdentitiesRetention = ConstU32<SESSION_IDENTITIES_RETENTION>;
+    type MinValidatorSetSize = MinValidatorSetSize;
+}
+
+/// Build an authentication for the given validator.
+pub fn authentication(public_key: AccountId) -> Authentication<AccountId, u64, u64> {
+    Authentication {
+        public_key,
+        expires_at: 1000,
+        authenticated_at: 0,
+        authenticated_at_block: 0,
+        robonode_public_key_id: Default::default(),
+    }
+}
+
+/// Build test externalities with the given bootnodes and bioauth validators, wiring the pallet
+/// with or without the historical sessions.
+pub fn new_test_ext_with(
+    historical: bool,
+    bootnodes: Vec<AccountId>,
+    bioauth_validators: Vec<AccountId>,
+) -> sp_io::TestExternalities {
+    HistoricalWiring::set(historical);
+
+    // Build genesis.
+    let config = GenesisConfig {
+        bootnodes: pallet_bootnodes::GenesisConfig {
+            bootnodes: BoundedVec::truncate_from(bootnodes),
+        },
+        bioauth: pallet_bioauth::GenesisConfig {
+            active_authentications: BoundedVec::truncate_from(
+                bioauth_validators.into_iter().map(authentication).collect(),
+            ),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let storage = config.build_storage().unwrap();
+
+    // Make test externalities from the storage.
+    let mut ext: sp_io::TestExternalities = storage.into();
+    // Events are not deposited at the genesis block.
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,212 @@
+//! The selection of the bioauth validators for the session when there are more candidates than
+//! the validator slots.
+
+use codec::Encode;
+use frame_support::traits::Randomness;
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// Selects the validators for a session from the list of candidates.
+pub trait ValidatorSelector<Candidate> {
+    /// Select at most `max` of the `candidates` to be the validators at the given session.
+    ///
+    /// The candidates are passed in a stable order, and when they all fit into `max` they are all
+    /// expected to be selected.
+    fn select(session_index: u32, candidates: Vec<Candidate>, max: usize) -> Vec<Candidate>;
+}
+
+/// Always selects the first candidates, in the order they are passed.
+pub struct FirstCandidates;
+
+impl<Candidate> ValidatorSelector<Candidate> for FirstCandidates {
+    fn select(_session_index: u32, mut candidates: Vec<Candidate>, max: usize) -> Vec<Candidate> {
+        candidates.truncate(max);
+        candidates
+    }
+}
+
+/// Moves the selection window over the candidates by `max` with every session, so that all of
+/// the candidates get selected in turns.
+pub struct RoundRobin;
+
+impl<Candidate> ValidatorSelector<Candidate> for RoundRobin {
+    fn select(session_index: u32, mut candidates: Vec<Candidate>, max: usize) -> Vec<Candidate> {
+        if candidates.len() <= max {
+            return candidates;
+        }
+
+        let offset = match (u64::try_from(max), u64::try_from(candidates.len())) {
+            (Ok(max), Ok(total)) => u64::from(session_index)
+                .saturating_mul(max)
+                .checked_rem(total)
+                .and_then(|offset| usize::try_from(offset).ok())
+                .unwrap_or_default(),
+            // Unreachable in practice, as `usize` always fits into `u64`.
+            _ => 0,
+        };
+
+        candidates.rotate_left(offset);
+        candidates.truncate(max);
+        candidates
+    }
+}
+
+/// The subject for the randomness used at the validators selection.
+const RANDOM_SHUFFLE_SUBJECT: &[u8] = b"humanode-session/validator-selector";
+
+/// Selects the random candidates, seeded by the provided randomness source for every session.
+pub struct RandomShuffle<R, Output, BlockNumber>(PhantomData<(R, Output, BlockNumber)>);
+
+impl<Candidate, R, Output, BlockNumber> ValidatorSelector<Candidate>
+    for RandomShuffle<R, Output, BlockNumber>
+where
+    R: Randomness<Output, BlockNumber>,
+    Output: Encode,
+{
+    fn select(session_index: u32, mut candidates: Vec<Candidate>, max: usize) -> Vec<Candidate> {
+        if candidates.len() <= max {
+            return candidates;
+        }
+
+        let (seed, _) = R::random(&(RANDOM_SHUFFLE_SUBJECT, session_index).encode());
+        let seed = seed.encode();
+
+        // Partial Fisher-Yates shuffle - we only need the first `max` elements to be shuffled.
+        let total = candidates.len();
+        for index in 0..max {
+            let remaining = total.saturating_sub(index);
+            let random = random_number(&seed, index);
+            let offset = random
+                .checked_rem(u64::try_from(remaining).unwrap_or(u64::MAX))
+                .and_then(|offset| usize::try_from(offset).ok())
+                .unwrap_or_default();
+            candidates.swap(index, index.saturating_add(offset));
+        }
+
+        candidates.truncate(max);
+        candidates
+    }
+}
+
+/// Derive the random number for the given position from the seed.
+fn random_number(seed: &[u8], position: usize) -> u64 {
+    let position = u64::try_from(position).unwrap_or(u64::MAX);
+    let hash = sp_io::hashing::blake2_256(&(seed, position).encode());
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&hash[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    // Allow simple integer arithmetic in tests.
+    #![allow(clippy::arithmetic_side_effects)]
+
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    /// The randomness that is deterministically derived from the subject.
+    struct MockRandomness;
+
+    impl Randomness<[u8; 32], u64> for MockRandomness {
+        fn random(subject: &[u8]) -> ([u8; 32], u64) {
+            (sp_io::hashing::blake2_256(subject), 0)
+        }
+    }
+
+    type MockRandomShuffle = RandomShuffle<MockRandomness, [u8; 32], u64>;
+
+    /// Run the selector over the given amount of sessions and count how many times each of
+    /// the candidates got selected.
+    fn selection_counts<S: ValidatorSelector<u32>>(
+        total: u32,
+        max: usize,
+        sessions: u32,
+    ) -> Vec<usize> {
+        let mut counts = vec![0; total as usize];
+        for session_index in 0..sessions {
+            let selected = S::select(session_index, (0..total).collect(), max);
+
+            assert_eq!(selected.len(), max);
+            let unique: BTreeSet<_> = selected.iter().collect();
+            assert_eq!(unique.len(), max, "candidates must not be selected twice");
+
+            for candidate in selected {
+                counts[candidate as usize] += 1;
+            }
+        }
+        counts
+    }
+
+    /// This test verifies that all of the candidates are selected when they fit.
+    #[test]
+    fn all_candidates_fit() {
+        let candidates = vec![3, 1, 2];
+        assert_eq!(
+            FirstCandidates::select(5, candidates.clone(), 3),
+            candidates
+        );
+        assert_eq!(RoundRobin::select(5, candidates.clone(), 3), candidates);
+        assert_eq!(
+            MockRandomShuffle::select(5, candidates.clone(), 4),
+            candidates
+        );
+    }
+
+    /// This test verifies that the first candidates selector always picks the same candidates,
+    /// which is what the other selectors are meant to fix.
+    #[test]
+    fn first_candidates_is_unfair() {
+        let counts = selection_counts::<FirstCandidates>(10, 3, 100);
+        assert_eq!(counts, vec![100, 100, 100, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    /// This test verifies that the round robin selector gives every candidate the same amount of
+    /// the sessions.
+    #[test]
+    fn round_robin_is_fair() {
+        // Over the whole number of rounds everyone gets exactly the same amount of sessions.
+        let counts = selection_counts::<RoundRobin>(7, 3, 70);
+        assert_eq!(counts, vec![30; 7]);
+
+        // Over an arbitrary number of sessions the difference is at most one session.
+        let counts = selection_counts::<RoundRobin>(10, 4, 37);
+        let min = counts.iter().min().unwrap();
+        let max = counts.iter().max().unwrap();
+        assert!(max - min <= 1, "{counts:?}");
+    }
+
+    /// This test verifies that the round robin selector moves the window with the sessions.
+    #[test]
+    fn round_robin_window() {
+        let candidates: Vec<u32> = (0..5).collect();
+        assert_eq!(RoundRobin::select(0, candidates.clone(), 2), vec![0, 1]);
+        assert_eq!(RoundRobin::select(1, candidates.clone(), 2), vec![2, 3]);
+        assert_eq!(RoundRobin::select(2, candidates.clone(), 2), vec![4, 0]);
+        assert_eq!(RoundRobin::select(3, candidates, 2), vec![1, 2]);
+    }
+
+    /// This test verifies that the random shuffle selector is deterministic for a given session.
+    #[test]
+    fn random_shuffle_is_deterministic() {
+        let candidates: Vec<u32> = (0..20).collect();
+        assert_eq!(
+            MockRandomShuffle::select(42, candidates.clone(), 5),
+            MockRandomShuffle::select(42, candidates, 5),
+        );
+    }
+
+    /// This test verifies that the random shuffle selector gives every candidate roughly the same
+    /// amount of the sessions.
+    #[test]
+    fn random_shuffle_is_fair() {
+        let sessions = 3000;
+        let counts = selection_counts::<MockRandomShuffle>(10, 3, sessions);
+
+        // Every candidate is expected to be selected in 30% of the sessions.
+        let expected = sessions as usize * 3 / 10;
+        for count in &counts {
+            assert!(count.abs_diff(expected) < expected / 10, "{counts:?}");
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! The tests for the pallet.
+
+// Allow simple integer arithmetic in tests.
+#![allow(clippy::arithmetic_side_effects)]
+
+use frame_support::BoundedVec;
+use sp_runtime::traits::Convert;
+
+use crate::{mock::*, *};
+
+/// Both of the ways to wire the pallet into the session pallet.
+const WIRINGS: [bool; 2] = [true, false];
+
+/// Replace the active bioauth authentications with the ones for the given validators.
+fn set_bioauth_validators(validators: Vec<AccountId>) {
+    <pallet_bioauth::ActiveAuthentications<Test>>::put(BoundedVec::truncate_from(
+        validators
+            .into_iter()
+            .map(authentication)
+            .collect::<Vec<_>>(),
+    ));
+}
+
+/// Collect the identities of the given session, sorted by the account id.
+fn sorted_session_identities(session_index: u32) -> Vec<IdentificationTupleFor<Test>> {
+    let mut identities = HumanodeSession::session_identities(session_index);
+    identities.sort_by_key(|(account_id, _)| *account_id);
+    identities
+}
+
+/// This test verifies that the genesis authorities are the bootnodes and the bioauth validators,
+/// with either of the wirings.
+#[test]
+fn genesis_authorities() {
+    for historical in WIRINGS {
+        new_test_ext_with(historical, vec![1], vec![10, 11]).execute_with(|| {
+            // Assert the session state.
+            assert_eq!(Session::validators(), vec![1, 10, 11]);
+            assert_eq!(<CurrentSessionIndex<Test>>::get(), Some(0));
+
+            // Assert the identities of the current and the next sessions.
+            for session_index in [0, 1] {
+                assert_eq!(
+                    sorted_session_identities(session_index),
+                    vec![
+                        (1, Identification::Bootnode(1)),
+                        (10, Identification::Bioauth(authentication(10))),
+                        (11, Identification::Bioauth(authentication(11))),
+                    ]
+                );
+            }
+        });
+    }
+}
+
+/// This test verifies that both of the wirings produce the same authorities and identities
+/// as the bioauth validators change over the sessions.
+#[test]
+fn wirings_are_equivalent() {
+    let run = |historical: bool| {
+        new_test_ext_with(historical, vec![1], vec![10, 11]).execute_with(|| {
+            let mut observed = vec![];
+
+            for validators in [vec![10, 12], vec![12, 13, 14, 15], vec![13]] {
+                // Prepare the test state.
+                set_bioauth_validators(validators);
+
+                // Invoke the function under test.
+                Session::rotate_session();
+
+                // Collect the observed state.
+                let session_index = Session::current_index();
+                observed.push((
+                    Session::validators(),
+                    sorted_session_identities(session_index),
+                    sorted_session_identities(session_index + 1),
+                    <CurrentSessionIndex<Test>>::get(),
+                ));
+            }
+
+            observed
+        })
+    };
+
+    let historical = run(true);
+    let plain = run(false);
+
+    // Assert the wirings are equivalent.
+    assert_eq!(historical, plain);
+
+    // Assert the authorities follow the bioauth validators, with a one session delay.
+    let authorities = historical
+        .into_iter()
+        .map(|(validators, ..)| validators)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        authorities,
+        vec![
+            vec![1, 10, 11],
+            vec![1, 10, 12],
+            // The bioauth validators are capped at `MAX_BIOAUTH_VALIDATORS`.
+            vec![1, 12, 13, 14],
+        ]
+    );
+}
+
+/// This test verifies that the historical root is only noted with the historical wiring.
+#[test]
+fn historical_root_is_noted_with_historical_wiring() {
+    for historical in WIRINGS {
+        new_test_ext_with(historical, vec![1], vec![10]).execute_with(|| {
+            // Invoke the function under test.
+            Session::rotate_session();
+
+            // Assert state changes.
+            assert_eq!(Historical::historical_root(2).is_some(), historical);
+        });
+    }
+}
+
+/// This test verifies that the validator set falling below the minimum is padded with
+/// the bootnodes left out due to the bootnode validators cap.
+#[test]
+fn below_minimum_is_padded_with_bootnodes() {
+    new_test_ext_with(true, vec![1, 2, 3], vec![10]).execute_with(|| {
+        // Prepare the test state.
+        MinValidatorSetSize::set(3);
+        set_bioauth_validators(vec![]);
+
+        // Invoke the function under test.
+        Session::rotate_session();
+        Session::rotate_session();
+
+        // Assert state changes.
+        assert_eq!(Session::validators(), vec![1, 2, 3]);
+        System::assert_has_event(RuntimeEvent::HumanodeSession(
+            Event::ValidatorSetBelowMinimum {
+                session_index: 2,
+                computed_size: MAX_BOOTNODE_VALIDATORS,
+                minimum: 3,
+                resolution: BelowMinimumResolution::PaddedWithBootnodes,
+            },
+        ));
+    });
+}
+
+/// This test verifies that the authorities of the previous session are kept when all of
+/// the authentications expire at once.
+#[test]
+fn below_minimum_keeps_previous_authorities() {
+    for historical in WIRINGS {
+        new_test_ext_with(historical, vec![1], vec![10, 11]).execute_with(|| {
+            // Prepare the test state.
+            MinValidatorSetSize::set(3);
+            set_bioauth_validators(vec![]);
+
+            // Invoke the function under test.
+            for _ in 0..3 {
+                Session::rotate_session();
+            }
+
+            // Assert state changes.
+            let mut validators = Session::validators();
+            validators.sort();
+            assert_eq!(validators, vec![1, 10, 11]);
+            assert_eq!(
+                HumanodeSession::session_identification(Session::current_index() + 1, &10),
+                Some(Identification::Bioauth(authentication(10)))
+            );
+            System::assert_has_event(RuntimeEvent::HumanodeSession(
+                Event::ValidatorSetBelowMinimum {
+                    session_index: 2,
+                    computed_size: 1,
+                    minimum: 3,
+                    resolution: BelowMinimumResolution::KeptPreviousAuthorities,
+                },
+            ));
+        });
+    }
+}
+
+/// This test verifies that the validator set is left as computed when there is no way to make
+/// it bigger.
+#[test]
+fn below_minimum_unresolved() {
+    new_test_ext_with(true, vec![1], vec![]).execute_with(|| {
+        // Prepare the test state.
+        MinValidatorSetSize::set(3);
+
+        // Invoke the function under test.
+        Session::rotate_session();
+        Session::rotate_session();
+
+        // Assert state changes.
+        assert_eq!(Session::validators(), vec![1]);
+        System::assert_has_event(RuntimeEvent::HumanodeSession(
+            Event::ValidatorSetBelowMinimum {
+                session_index: 2,
+                computed_size: 1,
+                minimum: 3,
+                resolution: BelowMinimumResolution::Unresolved,
+            },
+        ));
+    });
+}
+
+/// This test verifies that the identities are retained for the configured amount of
+/// the past sessions, with each of the retained sessions keeping its own identities, and
+/// the current session identification is available.
+#[test]
+fn session_identities_are_retained() {
+    for historical in WIRINGS {
+        new_test_ext_with(historical, vec![1], vec![10]).execute_with(|| {
+            // Invoke the function under test, with a different bioauth validator every session.
+            let rotations = SESSION_IDENTITIES_RETENTION + 3;
+            for rotation in 0..rotations {
+                set_bioauth_validators(vec![20 + rotation]);
+                Session::rotate_session();
+            }
+
+            // Assert state changes.
+            let current_index = Session::current_index();
+            assert_eq!(current_index, rotations);
+            let oldest_retained_index = current_index - SESSION_IDENTITIES_RETENTION;
+            for session_index in 0..oldest_retained_index {
+                assert!(HumanodeSession::session_identities(session_index).is_empty());
+            }
+            for session_index in oldest_retained_index..=current_index + 1 {
+                // The session `n` is planned two sessions ahead, at the rotation `n - 2`.
+                let validator = 20 + session_index - 2;
+                assert_eq!(
+                    sorted_session_identities(session_index),
+                    vec![
+                        (1, Identification::Bootnode(1)),
+                        (
+                            validator,
+                            Identification::Bioauth(authentication(validator))
+                        ),
+                    ]
+                );
+            }
+            assert_eq!(
+                CurrentSessionIdentificationOf::<Test>::convert(1),
+                Some(Identification::Bootnode(1))
+            );
+            assert_eq!(
+                CurrentSessionIdentificationOf::<Test>::convert(20 + current_index - 2),
+                Some(Identification::Bioauth(authentication(
+                    20 + current_index - 2
+                )))
+            );
+            assert_eq!(CurrentSessionIdentificationOf::<Test>::convert(2), None);
+        });
+    }
+}
@@ -0,0 +1,189 @@
+//! The benchmarks for the pallet.
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{assert_ok, traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+
+use crate::*;
+
+/// The benchmark interface into the environment.
+pub trait Interface: super::Config {
+    /// Obtain an Account ID of a validator, and make the validator authenticated.
+    ///
+    /// The validator is supposed to be the worst case to look up among the authenticated
+    /// validators.
+    fn authenticated_validator() -> <Self as frame_system::Config>::AccountId;
+}
+
+/// Make the given amount of the unbonding chunks of the min delegation each, unlocking at
+/// the blocks provided by `unlock_at` for every chunk index.
+fn unbonding_chunks<T: Config>(
+    count: u32,
+    unlock_at: impl Fn(u32) -> T::BlockNumber,
+) -> BoundedVec<UnbondingChunkFor<T>, T::MaxUnbondingChunks> {
+    let chunks = (0..count)
+        .map(|index| UnbondingChunk {
+            amount: T::MinDelegation::get(),
+            unlock_at: unlock_at(index),
+        })
+        .collect::<Vec<_>>();
+    BoundedVec::try_from(chunks).unwrap()
+}
+
+/// Make a delegator account with the given amount of the unbonding chunks, and enough free
+/// balance to delegate the min delegation on top of them.
+fn make_delegator<T: Config>(unbonding_chunks_count: u32) -> T::AccountId {
+    let delegator: T::AccountId = account("delegator", 0, 0);
+
+    let balance =
+        T::MinDelegation::get().saturating_mul(unbonding_chunks_count.saturating_add(2).into());
+    T::Currency::make_free_balance_be(&delegator, balance);
+
+    <Unbonding<T>>::insert(
+        &delegator,
+        unbonding_chunks::<T>(unbonding_chunks_count, |_| {
+            frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingPeriod::get())
+        }),
+    );
+    Pallet::<T>::update_lock(&delegator, Pallet::<T>::unbonding_total(&delegator));
+
+    delegator
+}
+
+/// Make an authenticated validator with the commission set.
+fn make_validator<T: Interface>() -> T::AccountId {
+    let validator = <T as Interface>::authenticated_validator();
+    <Commissions<T>>::insert(&validator, T::MaxCommission::get());
+    validator
+}
+
+benchmarks! {
+    where_clause {
+        where
+            T: Interface,
+    }
+
+    set_commission {
+        let validator = <T as Interface>::authenticated_validator();
+        let commission = T::MaxCommission::get();
+    }: _(RawOrigin::Signed(validator.clone()), commission)
+    verify {
+        assert_eq!(<Commissions<T>>::get(&validator), Some(commission));
+    }
+
+    delegate {
+        let validator = make_validator::<T>();
+        // The worst case is the new delegator with the most unbonding chunks to sum up.
+        let delegator = make_delegator::<T>(T::MaxUnbondingChunks::get());
+        let amount = T::MinDelegation::get();
+    }: _(RawOrigin::Signed(delegator.clone()), validator.clone(), amount)
+    verify {
+        assert_eq!(<Delegations<T>>::get(&validator, &delegator), amount);
+        assert_eq!(<ValidatorStakes<T>>::get(&validator).delegators, 1);
+    }
+
+    undelegate {
+        let validator = make_validator::<T>();
+        // Leave the room for one more unbonding chunk.
+        let delegator = make_delegator::<T>(T::MaxUnbondingChunks::get().saturating_sub(1));
+        let amount = T::MinDelegation::get();
+        assert_ok!(Pallet::<T>::delegate(
+            RawOrigin::Signed(delegator.clone()).into(),
+            validator.clone(),
+            amount,
+        ));
+        // Have the rewards to settle.
+        <RewardsPerStake<T>>::insert(&validator, FixedU128::saturating_from_integer(1u32));
+    }: _(RawOrigin::Signed(delegator.clone()), validator.clone(), amount)
+    verify {
+        assert!(!<Delegations<T>>::contains_key(&validator, &delegator));
+        assert!(!<UnclaimedRewards<T>>::get(&delegator).is_zero());
+        assert_eq!(
+            u32::try_from(<Unbonding<T>>::get(&delegator).len()).unwrap(),
+            T::MaxUnbondingChunks::get(),
+        );
+    }
+
+    withdraw_unbonded {
+        let delegator = make_delegator::<T>(0);
+
+        // Populate the max amount of the unbonding chunks, with only half of them unlocked,
+        // so that the still unbonding ones have to be written back.
+        let now = T::UnbondingPeriod::get();
+        frame_system::Pallet::<T>::set_block_number(now);
+        let chunks = unbonding_chunks::<T>(T::MaxUnbondingChunks::get(), |index| {
+            if index % 2 == 0 {
+                now
+            } else {
+                now.saturating_add(T::UnbondingPeriod::get())
+            }
+        });
+        let still_unbonding = chunks.iter().filter(|chunk| chunk.unlock_at > now).count();
+        T::Currency::make_free_balance_be(
+            &delegator,
+            T::MinDelegation::get().saturating_mul(T::MaxUnbondingChunks::get().into()),
+        );
+        <Unbonding<T>>::insert(&delegator, chunks);
+        Pallet::<T>::update_lock(&delegator, Pallet::<T>::unbonding_total(&delegator));
+    }: _(RawOrigin::Signed(delegator.clone()))
+    verify {
+        assert_eq!(<Unbonding<T>>::get(&delegator).len(), still_unbonding);
+    }
+
+    claim_rewards {
+        let validator = make_validator::<T>();
+        let delegator = make_delegator::<T>(0);
+        let amount = T::MinDelegation::get();
+        assert_ok!(Pallet::<T>::delegate(
+            RawOrigin::Signed(delegator.clone()).into(),
+            validator.clone(),
+            amount,
+        ));
+        // The worst case is having the rewards of the delegation to settle on top of the ones
+        // already settled.
+        let reward = amount.saturating_mul(10u32.into());
+        T::Currency::make_free_balance_be(
+            &T::RewardsPotAccountId::get(),
+            reward.saturating_mul(2u32.into()),
+        );
+        <UnclaimedRewards<T>>::insert(&delegator, amount);
+        <TotalUnclaimedRewards<T>>::put(amount);
+        <Pallet<T> as pallet_session_rewards::RewardSplit<_, _>>::split(&validator, reward);
+        let balance_before = T::Currency::free_balance(&delegator);
+    }: _(RawOrigin::Signed(delegator.clone()), validator.clone())
+    verify {
+        assert!(T::Currency::free_balance(&delegator) > balance_before.saturating_add(amount));
+        assert!(<UnclaimedRewards<T>>::get(&delegator).is_zero());
+    }
+
+    split_reward {
+        let validator = make_validator::<T>();
+        let delegator = make_delegator::<T>(0);
+        let amount = T::MinDelegation::get();
+        assert_ok!(Pallet::<T>::delegate(
+            RawOrigin::Signed(delegator).into(),
+            validator.clone(),
+            amount,
+        ));
+    }: {
+        <Pallet<T> as pallet_session_rewards::RewardSplit<_, _>>::split(&validator, amount);
+    }
+    verify {
+        assert!(!<RewardsPerStake<T>>::get(&validator).is_zero());
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::mock::new_test_ext_with(vec![]),
+        crate::mock::Test,
+    );
+}
+
+#[cfg(test)]
+impl Interface for crate::mock::Test {
+    fn authenticated_validator() -> <Self as frame_system::Config>::AccountId {
+        let validator = 42;
+        crate::mock::AuthenticatedValidators::mutate(|validators| validators.push(validator));
+        validator
+    }
+}
@@ -0,0 +1,599 @@
+//! A substrate pallet for delegating the stake to the bioauth-powered validators.
+//!
+//! The token holders delegate their balance to the accounts of the authenticated validators,
+//! and the validators declare the commission they take of the session rewards. The rest of
+//! the validator session rewards is split between the delegators proportionally to their stake,
+//! and is kept in the rewards pot for the delegators to claim.
+//!
+//! The split does not touch the individual delegations: the rewards are accumulated per unit of
+//! the validator stake, and are settled to the delegators whenever their delegations change or
+//! the rewards are claimed.
+//!
+//! The delegated balance is locked, and stays locked for the unbonding period after it is
+//! undelegated.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    traits::{
+        Contains, Currency, ExistenceRequirement, Get, LockIdentifier, LockableCurrency,
+        StorageVersion, WithdrawReasons,
+    },
+    weights::Weight,
+};
+pub use pallet::*;
+use sp_runtime::{
+    traits::{CheckedSub, Convert, SaturatedConversion, Saturating, Zero},
+    FixedPointNumber, FixedU128, Perbill,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+pub use weights::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+/// The balance type for a given config.
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// The stake delegated to a validator.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    scale_info::TypeInfo,
+    codec::MaxEncodedLen,
+    codec::Encode,
+    codec::Decode,
+)]
+pub struct ValidatorStake<Balance> {
+    /// The total amount delegated to the validator.
+    pub total: Balance,
+    /// The amount of the delegators of the validator.
+    pub delegators: u32,
+}
+
+/// A chunk of the undelegated balance that is still locked.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    scale_info::TypeInfo,
+    codec::MaxEncodedLen,
+    codec::Encode,
+    codec::Decode,
+)]
+pub struct UnbondingChunk<Balance, BlockNumber> {
+    /// The amount of the balance being unbonded.
+    pub amount: Balance,
+    /// The number of the block at which the balance can be withdrawn.
+    pub unlock_at: BlockNumber,
+}
+
+/// The unbonding chunk type for a given config.
+pub type UnbondingChunkFor<T> =
+    UnbondingChunk<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+// We have to temporarily allow some clippy lints. Later on we'll send patches to substrate to
+// fix them at their end.
+#[allow(clippy::missing_docs_in_private_items)]
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    use super::*;
+
+    /// Configure the pallet by specifying the parameters and types on which it depends.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The currency to delegate and lock.
+        type Currency: LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
+
+        /// The lock identifier to use for the delegated balance.
+        type LockId: Get<LockIdentifier>;
+
+        /// The validators that are currently authenticated, and thus can be delegated to.
+        type AuthenticatedValidators: Contains<Self::AccountId>;
+
+        /// The max commission the validators can declare.
+        type MaxCommission: Get<Perbill>;
+
+        /// The min amount that can be delegated to a validator.
+        type MinDelegation: Get<BalanceOf<Self>>;
+
+        /// The max amount of the delegators of a single validator.
+        type MaxDelegatorsPerValidator: Get<u32>;
+
+        /// The max amount of the unbonding chunks of a single delegator.
+        type MaxUnbondingChunks: Get<u32>;
+
+        /// The amount of blocks the undelegated balance stays locked for.
+        type UnbondingPeriod: Get<Self::BlockNumber>;
+
+        /// The account of the pot the session rewards are paid from, that also keeps
+        /// the unclaimed rewards of the delegators.
+        type RewardsPotAccountId: Get<Self::AccountId>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    /// The commissions declared by the validators.
+    #[pallet::storage]
+    #[pallet::getter(fn commission)]
+    pub type Commissions<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, Perbill, OptionQuery>;
+
+    /// The amounts delegated to the validators, by the validator and the delegator.
+    #[pallet::storage]
+    #[pallet::getter(fn delegation)]
+    pub type Delegations<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// The stake delegated to the validators.
+    #[pallet::storage]
+    #[pallet::getter(fn validator_stake)]
+    pub type ValidatorStakes<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, ValidatorStake<BalanceOf<T>>, ValueQuery>;
+
+    /// The total amount currently delegated by the delegators.
+    #[pallet::storage]
+    #[pallet::getter(fn bonded)]
+    pub type Bonded<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// The undelegated balance of the delegators that is still locked.
+    #[pallet::storage]
+    #[pallet::getter(fn unbonding)]
+    pub type Unbonding<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<UnbondingChunkFor<T>, T::MaxUnbondingChunks>,
+        ValueQuery,
+    >;
+
+    /// The rewards accumulated per unit of the stake delegated to the validators.
+    #[pallet::storage]
+    #[pallet::getter(fn rewards_per_stake)]
+    pub type RewardsPerStake<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, FixedU128, ValueQuery>;
+
+    /// The [`RewardsPerStake`] of the validators as of the last settlement of the rewards to
+    /// the delegators, by the validator and the delegator.
+    #[pallet::storage]
+    pub type SettledRewardsPerStake<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        T::AccountId,
+        FixedU128,
+        ValueQuery,
+    >;
+
+    /// The rewards settled to the delegators that are not yet claimed.
+    #[pallet::storage]
+    #[pallet::getter(fn unclaimed_rewards)]
+    pub type UnclaimedRewards<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// The total of the rewards split off to the delegators that are not yet claimed, both
+    /// settled and not.
+    #[pallet::storage]
+    #[pallet::getter(fn total_unclaimed_rewards)]
+    pub type TotalUnclaimedRewards<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The validator has declared the commission.
+        CommissionSet {
+            /// The validator.
+            validator: T::AccountId,
+            /// The declared commission.
+            commission: Perbill,
+        },
+        /// The balance was delegated to the validator.
+        Delegated {
+            /// The delegator.
+            delegator: T::AccountId,
+            /// The validator.
+            validator: T::AccountId,
+            /// The delegated amount.
+            amount: BalanceOf<T>,
+        },
+        /// The balance was undelegated from the validator, and is now unbonding.
+        Undelegated {
+            /// The delegator.
+            delegator: T::AccountId,
+            /// The validator.
+            validator: T::AccountId,
+            /// The undelegated amount.
+            amount: BalanceOf<T>,
+            /// The number of the block at which the amount can be withdrawn.
+            unlock_at: T::BlockNumber,
+        },
+        /// The unbonded balance was unlocked.
+        Withdrawn {
+            /// The delegator.
+            delegator: T::AccountId,
+            /// The unlocked amount.
+            amount: BalanceOf<T>,
+        },
+        /// The accrued rewards were claimed.
+        RewardsClaimed {
+            /// The delegator.
+            delegator: T::AccountId,
+            /// The claimed amount.
+            amount: BalanceOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The validator is not authenticated.
+        NotAuthenticated,
+        /// The commission is above the max commission.
+        CommissionTooHigh,
+        /// The validator has not declared the commission yet.
+        CommissionNotSet,
+        /// The amount is zero.
+        ZeroAmount,
+        /// The delegation would be below the min delegation.
+        DelegationTooSmall,
+        /// The validator has too many delegators.
+        TooManyDelegators,
+        /// The free balance is not enough to cover the delegation.
+        InsufficientBalance,
+        /// The delegation is smaller than the amount to undelegate.
+        NotEnoughDelegated,
+        /// The delegator has too many unbonding chunks.
+        TooManyUnbondingChunks,
+        /// There is no unbonded balance to withdraw.
+        NothingToWithdraw,
+        /// There are no rewards to claim.
+        NothingToClaim,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Declare the commission taken of the session rewards by the validator.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_commission())]
+        pub fn set_commission(origin: OriginFor<T>, commission: Perbill) -> DispatchResult {
+            let validator = ensure_signed(origin)?;
+
+            ensure!(
+                T::AuthenticatedValidators::contains(&validator),
+                Error::<T>::NotAuthenticated
+            );
+            ensure!(
+                commission <= T::MaxCommission::get(),
+                Error::<T>::CommissionTooHigh
+            );
+
+            <Commissions<T>>::insert(&validator, commission);
+
+            Self::deposit_event(Event::CommissionSet {
+                validator,
+                commission,
+            });
+
+            Ok(())
+        }
+
+        /// Delegate the balance to the validator.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::delegate())]
+        pub fn delegate(
+            origin: OriginFor<T>,
+            validator: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+
+            ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+            ensure!(
+                T::AuthenticatedValidators::contains(&validator),
+                Error::<T>::NotAuthenticated
+            );
+            ensure!(
+                <Commissions<T>>::contains_key(&validator),
+                Error::<T>::CommissionNotSet
+            );
+
+            let delegation = <Delegations<T>>::get(&validator, &delegator);
+            let new_delegation = delegation.saturating_add(amount);
+            ensure!(
+                new_delegation >= T::MinDelegation::get(),
+                Error::<T>::DelegationTooSmall
+            );
+
+            let mut stake = <ValidatorStakes<T>>::get(&validator);
+            if delegation.is_zero() {
+                stake.delegators = stake.delegators.saturating_add(1);
+                ensure!(
+                    stake.delegators <= T::MaxDelegatorsPerValidator::get(),
+                    Error::<T>::TooManyDelegators
+                );
+            }
+            stake.total = stake.total.saturating_add(amount);
+
+            let bonded = <Bonded<T>>::get(&delegator).saturating_add(amount);
+            let locked = bonded.saturating_add(Self::unbonding_total(&delegator));
+            ensure!(
+                T::Currency::free_balance(&delegator) >= locked,
+                Error::<T>::InsufficientBalance
+            );
+
+            Self::settle_rewards(&validator, &delegator, delegation);
+            <Delegations<T>>::insert(&validator, &delegator, new_delegation);
+            <ValidatorStakes<T>>::insert(&validator, stake);
+            <Bonded<T>>::insert(&delegator, bonded);
+            Self::update_lock(&delegator, locked);
+
+            Self::deposit_event(Event::Delegated {
+                delegator,
+                validator,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Undelegate the balance from the validator.
+        ///
+        /// The balance stays locked for the unbonding period, and has to be withdrawn after.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::undelegate())]
+        pub fn undelegate(
+            origin: OriginFor<T>,
+            validator: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+
+            ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+            let delegation = <Delegations<T>>::get(&validator, &delegator);
+            let new_delegation = delegation
+                .checked_sub(&amount)
+                .ok_or(Error::<T>::NotEnoughDelegated)?;
+            ensure!(
+                new_delegation.is_zero() || new_delegation >= T::MinDelegation::get(),
+                Error::<T>::DelegationTooSmall
+            );
+
+            let unlock_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingPeriod::get());
+            <Unbonding<T>>::try_mutate(&delegator, |chunks| {
+                chunks.try_push(UnbondingChunk { amount, unlock_at })
+            })
+            .map_err(|_| Error::<T>::TooManyUnbondingChunks)?;
+
+            Self::settle_rewards(&validator, &delegator, delegation);
+            <ValidatorStakes<T>>::mutate_exists(&validator, |maybe_stake| {
+                let mut stake = maybe_stake.take().unwrap_or_default();
+                stake.total = stake.total.saturating_sub(amount);
+                if new_delegation.is_zero() {
+                    stake.delegators = stake.delegators.saturating_sub(1);
+                }
+                if stake.delegators > 0 {
+                    *maybe_stake = Some(stake);
+                }
+            });
+            if new_delegation.is_zero() {
+                <Delegations<T>>::remove(&validator, &delegator);
+                <SettledRewardsPerStake<T>>::remove(&validator, &delegator);
+            } else {
+                <Delegations<T>>::insert(&validator, &delegator, new_delegation);
+            }
+            <Bonded<T>>::mutate_exists(&delegator, |maybe_bonded| {
+                let bonded = maybe_bonded.unwrap_or_default().saturating_sub(amount);
+                *maybe_bonded = (!bonded.is_zero()).then_some(bonded);
+            });
+
+            Self::deposit_event(Event::Undelegated {
+                delegator,
+                validator,
+                amount,
+                unlock_at,
+            });
+
+            Ok(())
+        }
+
+        /// Unlock the balance that has finished unbonding.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::withdraw_unbonded())]
+        pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let (unlocked, still_unbonding): (Vec<_>, Vec<_>) = <Unbonding<T>>::get(&delegator)
+                .into_iter()
+                .partition(|chunk| chunk.unlock_at <= now);
+
+            let amount = unlocked
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |total, chunk| {
+                    total.saturating_add(chunk.amount)
+                });
+            ensure!(!amount.is_zero(), Error::<T>::NothingToWithdraw);
+
+            if still_unbonding.is_empty() {
+                <Unbonding<T>>::remove(&delegator);
+            } else {
+                // The chunks were only filtered, so they still fit.
+                <Unbonding<T>>::insert(&delegator, BoundedVec::truncate_from(still_unbonding));
+            }
+            let locked =
+                <Bonded<T>>::get(&delegator).saturating_add(Self::unbonding_total(&delegator));
+            Self::update_lock(&delegator, locked);
+
+            Self::deposit_event(Event::Withdrawn { delegator, amount });
+
+            Ok(())
+        }
+
+        /// Claim the share of the session rewards accrued to the delegator.
+        ///
+        /// The rewards of the delegation to the given validator are settled first, while
+        /// the rewards already settled from the other delegations are claimed as well.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::claim_rewards())]
+        pub fn claim_rewards(origin: OriginFor<T>, validator: T::AccountId) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+
+            let delegation = <Delegations<T>>::get(&validator, &delegator);
+            if !delegation.is_zero() {
+                Self::settle_rewards(&validator, &delegator, delegation);
+            }
+
+            let amount = <UnclaimedRewards<T>>::get(&delegator);
+            ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+            T::Currency::transfer(
+                &T::RewardsPotAccountId::get(),
+                &delegator,
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+
+            <UnclaimedRewards<T>>::remove(&delegator);
+            <TotalUnclaimedRewards<T>>::mutate(|total| *total = total.saturating_sub(amount));
+
+            Self::deposit_event(Event::RewardsClaimed { delegator, amount });
+
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// The total amount of the balance the delegator has unbonding.
+    fn unbonding_total(delegator: &T::AccountId) -> BalanceOf<T> {
+        <Unbonding<T>>::get(delegator)
+            .iter()
+            .fold(Zero::zero(), |total: BalanceOf<T>, chunk| {
+                total.saturating_add(chunk.amount)
+            })
+    }
+
+    /// Set the lock of the delegator to the given amount, removing the lock if there is nothing
+    /// left to lock.
+    fn update_lock(delegator: &T::AccountId, locked: BalanceOf<T>) {
+        if locked.is_zero() {
+            T::Currency::remove_lock(T::LockId::get(), delegator);
+        } else {
+            T::Currency::set_lock(T::LockId::get(), delegator, locked, WithdrawReasons::all());
+        }
+    }
+
+    /// Settle the rewards accumulated for the given delegation since the last settlement to
+    /// the delegator, for the delegator to claim later.
+    ///
+    /// Must be called before every change of the delegation.
+    fn settle_rewards(
+        validator: &T::AccountId,
+        delegator: &T::AccountId,
+        delegation: BalanceOf<T>,
+    ) {
+        let rewards_per_stake = <RewardsPerStake<T>>::get(validator);
+        let settled_rewards_per_stake = <SettledRewardsPerStake<T>>::get(validator, delegator);
+
+        let reward: BalanceOf<T> = rewards_per_stake
+            .saturating_sub(settled_rewards_per_stake)
+            .saturating_mul_int(delegation.saturated_into::<u128>())
+            .saturated_into();
+        if !reward.is_zero() {
+            <UnclaimedRewards<T>>::mutate(delegator, |unclaimed| {
+                *unclaimed = unclaimed.saturating_add(reward)
+            });
+        }
+
+        <SettledRewardsPerStake<T>>::insert(validator, delegator, rewards_per_stake);
+    }
+}
+
+impl<T: Config> pallet_session_rewards::RewardSplit<T::AccountId, BalanceOf<T>> for Pallet<T> {
+    fn split(validator: &T::AccountId, reward: BalanceOf<T>) -> BalanceOf<T> {
+        let stake = <ValidatorStakes<T>>::get(validator);
+        if stake.total.is_zero() {
+            return reward;
+        }
+
+        let commission = <Commissions<T>>::get(validator).unwrap_or_else(T::MaxCommission::get);
+        let delegators_share = reward.saturating_sub(commission.mul_floor(reward));
+
+        // The stake is not zero, as checked above.
+        let reward_per_stake = FixedU128::saturating_from_rational(
+            delegators_share.saturated_into::<u128>(),
+            stake.total.saturated_into::<u128>(),
+        );
+
+        <RewardsPerStake<T>>::mutate(validator, |rewards_per_stake| {
+            *rewards_per_stake = rewards_per_stake.saturating_add(reward_per_stake)
+        });
+        // The settled rewards are rounded down, so the whole share is kept for the delegators
+        // to never claim more than accounted for.
+        <TotalUnclaimedRewards<T>>::mutate(|total| *total = total.saturating_add(delegators_share));
+
+        reward.saturating_sub(delegators_share)
+    }
+
+    fn unclaimed() -> BalanceOf<T> {
+        <TotalUnclaimedRewards<T>>::get()
+    }
+
+    fn split_weight() -> Weight {
+        T::WeightInfo::split_reward()
+    }
+}
+
+/// The validators with an active bioauth authentication, with the validator public keys
+/// converted into the accounts via the `ValidatorIdOf`.
+pub struct BioauthAuthenticated<T, ValidatorIdOf>(PhantomData<(T, ValidatorIdOf)>);
+
+impl<T, ValidatorIdOf> Contains<T::AccountId> for BioauthAuthenticated<T, ValidatorIdOf>
+where
+    T: pallet_bioauth::Config,
+    ValidatorIdOf: Convert<T::ValidatorPublicKey, Option<T::AccountId>>,
+{
+    fn contains(account_id: &T::AccountId) -> bool {
+        <pallet_bioauth::Pallet<T>>::active_authentications()
+            .into_iter()
+            .any(|authentication| {
+                ValidatorIdOf::convert(authentication.public_key).as_ref() == Some(account_id)
+            })
+    }
+}
@@ -0,0 +1,136 @@
+//! The mock for the pallet.
+
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, ConstU64, Contains, LockIdentifier},
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, Perbill,
+};
+
+use crate::{self as pallet_humanode_staking};
+
+pub(crate) const EXISTENTIAL_DEPOSIT: u64 = 1;
+
+/// The min delegation in tests.
+pub(crate) const MIN_DELEGATION: u64 = 100;
+
+/// The max amount of the delegators of a single validator in tests.
+pub(crate) const MAX_DELEGATORS_PER_VALIDATOR: u32 = 2;
+
+/// The max amount of the unbonding chunks of a single delegator in tests.
+pub(crate) const MAX_UNBONDING_CHUNKS: u32 = 2;
+
+/// The unbonding period in tests.
+pub(crate) const UNBONDING_PERIOD: u64 = 10;
+
+/// The account of the rewards pot in tests.
+pub(crate) const POT: AccountId = 100;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type AccountId = u64;
+pub(crate) type Balance = u64;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub struct Test
+    where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        HumanodeStaking: pallet_humanode_staking,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<EXISTENTIAL_DEPOSIT>;
+    type AccountStore = System;
+    type MaxLocks = ConstU32<8>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const LockId: LockIdentifier = *b"staking_";
+    pub const MaxCommission: Perbill = Perbill::from_percent(50);
+    pub const RewardsPotAccountId: AccountId = POT;
+    pub static AuthenticatedValidators: Vec<AccountId> = vec![];
+}
+
+/// The authenticated validators as listed in the [`AuthenticatedValidators`].
+pub struct TestAuthenticatedValidators;
+
+impl Contains<AccountId> for TestAuthenticatedValidators {
+    fn contains(account_id: &AccountId) -> bool {
+        AuthenticatedValidators::get().contains(account_id)
+    }
+}
+
+impl pallet_humanode_staking::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type LockId = LockId;
+    type AuthenticatedValidators = TestAuthenticatedValidators;
+    type MaxCommission = MaxCommission;
+    type MinDelegation = ConstU64<MIN_DELEGATION>;
+    type MaxDelegatorsPerValidator = ConstU32<MAX_DELEGATORS_PER_VALIDATOR>;
+    type MaxUnbondingChunks = ConstU32<MAX_UNBONDING_CHUNKS>;
+    type UnbondingPeriod = ConstU64<UNBONDING_PERIOD>;
+    type RewardsPotAccountId = RewardsPotAccountId;
+    type WeightInfo = ();
+}
+
+/// Build test externalities with the given endowed accounts.
+pub fn new_test_ext_with(balances: Vec<(AccountId, Balance)>) -> sp_io::TestExternalities {
+    // Build genesis.
+    let config = GenesisConfig {
+        balances: pallet_balances::GenesisConfig { balances },
+        ..Default::default()
+    };
+    let storage = config.build_storage().unwrap();
+
+    // Make test externalities from the storage.
+    let mut ext: sp_io::TestExternalities = storage.into();
+    // Events are not deposited at the genesis block.
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,367 @@
+//! The tests for the pallet.
+
+// Allow simple integer arithmetic in tests.
+#![allow(clippy::arithmetic_side_effects)]
+
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use pallet_session_rewards::RewardSplit;
+
+use crate::{mock::*, *};
+
+/// The validator in tests.
+const VALIDATOR: AccountId = 1;
+
+/// The delegators in tests.
+const DELEGATORS: [AccountId; 3] = [10, 11, 12];
+
+/// Build test externalities with the authenticated validator and the endowed delegators.
+fn new_test_ext() -> sp_io::TestExternalities {
+    AuthenticatedValidators::set(vec![VALIDATOR]);
+    new_test_ext_with(
+        DELEGATORS
+            .into_iter()
+            .map(|delegator| (delegator, 1000))
+            .collect(),
+    )
+}
+
+/// The balance locked by the pallet.
+fn locked(who: AccountId) -> Balance {
+    Balances::locks(who)
+        .iter()
+        .find(|lock| lock.id == LockId::get())
+        .map(|lock| lock.amount)
+        .unwrap_or_default()
+}
+
+/// This test verifies that only the authenticated validators can declare the commission,
+/// and only up to the max commission.
+#[test]
+fn set_commission_works() {
+    new_test_ext().execute_with(|| {
+        // Invoke the function under test.
+        assert_ok!(HumanodeStaking::set_commission(
+            RuntimeOrigin::signed(VALIDATOR),
+            Perbill::from_percent(10)
+        ));
+
+        // Assert state changes.
+        assert_eq!(
+            HumanodeStaking::commission(VALIDATOR),
+            Some(Perbill::from_percent(10))
+        );
+        System::assert_has_event(RuntimeEvent::HumanodeStaking(Event::CommissionSet {
+            validator: VALIDATOR,
+            commission: Perbill::from_percent(10),
+        }));
+
+        // Assert the failures.
+        assert_noop!(
+            HumanodeStaking::set_commission(RuntimeOrigin::signed(VALIDATOR), Perbill::one()),
+            Error::<Test>::CommissionTooHigh
+        );
+        assert_noop!(
+            HumanodeStaking::set_commission(RuntimeOrigin::signed(2), Perbill::zero()),
+            Error::<Test>::NotAuthenticated
+        );
+    });
+}
+
+/// This test verifies that the delegated balance is accounted for and locked.
+#[test]
+fn delegate_locks_balance() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        assert_ok!(HumanodeStaking::set_commission(
+            RuntimeOrigin::signed(VALIDATOR),
+            Perbill::from_percent(10)
+        ));
+
+        // Invoke the function under test.
+        assert_ok!(HumanodeStaking::delegate(
+            RuntimeOrigin::signed(10),
+            VALIDATOR,
+            500
+        ));
+
+        // Assert state changes.
+        assert_eq!(HumanodeStaking::delegation(VALIDATOR, 10), 500);
+        assert_eq!(
+            HumanodeStaking::validator_stake(VALIDATOR),
+            ValidatorStake {
+                total: 500,
+                delegators: 1,
+            }
+        );
+        assert_eq!(HumanodeStaking::bonded(10), 500);
+        assert_eq!(locked(10), 500);
+        assert_noop!(
+            Balances::transfer(RuntimeOrigin::signed(10), 20, 600),
+            pallet_balances::Error::<Test>::LiquidityRestrictions
+        );
+        System::assert_has_event(RuntimeEvent::HumanodeStaking(Event::Delegated {
+            delegator: 10,
+            validator: VALIDATOR,
+            amount: 500,
+        }));
+    });
+}
+
+/// This test verifies that the delegations are checked.
+#[test]
+fn delegate_is_checked() {
+    new_test_ext().execute_with(|| {
+        // Assert the validator must be authenticated and declare the commission.
+        assert_noop!(
+            HumanodeStaking::delegate(RuntimeOrigin::signed(10), 2, 500),
+            Error::<Test>::NotAuthenticated
+        );
+        assert_noop!(
+            HumanodeStaking::delegate(RuntimeOrigin::signed(10), VALIDATOR, 500),
+            Error::<Test>::CommissionNotSet
+        );
+        assert_ok!(HumanodeStaking::set_commission(
+            RuntimeOrigin::signed(VALIDATOR),
+            Perbill::from_percent(10)
+        ));
+
+        // Assert the amount is checked.
+        assert_noop!(
+            HumanodeStaking::delegate(RuntimeOrigin::signed(10), VALIDATOR, 0),
+            Error::<Test>::ZeroAmount
+        );
+        assert_noop!(
+            HumanodeStaking::delegate(RuntimeOrigin::signed(10), VALIDATOR, MIN_DELEGATION - 1),
+            Error::<Test>::DelegationTooSmall
+        );
+        assert_noop!(
+            HumanodeStaking::delegate(RuntimeOrigin::signed(10), VALIDATOR, 1001),
+            Error::<Test>::InsufficientBalance
+        );
+
+        // Assert the delegators are bounded.
+        for delegator in DELEGATORS
+            .into_iter()
+            .take(MAX_DELEGATORS_PER_VALIDATOR as usize)
+        {
+            assert_ok!(HumanodeStaking::delegate(
+                RuntimeOrigin::signed(delegator),
+                VALIDATOR,
+                MIN_DELEGATION
+            ));
+        }
+        assert_noop!(
+            HumanodeStaking::delegate(RuntimeOrigin::signed(12), VALIDATOR, MIN_DELEGATION),
+            Error::<Test>::TooManyDelegators
+        );
+    });
+}
+
+/// This test verifies that the undelegated balance stays locked for the unbonding period.
+#[test]
+fn undelegated_balance_unbonds() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        assert_ok!(HumanodeStaking::set_commission(
+            RuntimeOrigin::signed(VALIDATOR),
+            Perbill::from_percent(10)
+        ));
+        assert_ok!(HumanodeStaking::delegate(
+            RuntimeOrigin::signed(10),
+            VALIDATOR,
+            500
+        ));
+
+        // Invoke the function under test.
+        assert_ok!(HumanodeStaking::undelegate(
+            RuntimeOrigin::signed(10),
+            VALIDATOR,
+            200
+        ));
+
+        // Assert state changes.
+        assert_eq!(HumanodeStaking::delegation(VALIDATOR, 10), 300);
+        assert_eq!(HumanodeStaking::validator_stake(VALIDATOR).total, 300);
+        assert_eq!(HumanodeStaking::bonded(10), 300);
+        assert_eq!(
+            HumanodeStaking::unbonding(10).into_inner(),
+            vec![UnbondingChunk {
+                amount: 200,
+                unlock_at: 1 + UNBONDING_PERIOD,
+            }]
+        );
+        assert_eq!(locked(10), 500);
+        assert_noop!(
+            HumanodeStaking::undelegate(RuntimeOrigin::signed(10), VALIDATOR, 250),
+            Error::<Test>::DelegationTooSmall
+        );
+
+        // Assert the balance can not be withdrawn until the unbonding period passes.
+        System::set_block_number(UNBONDING_PERIOD);
+        assert_noop!(
+            HumanodeStaking::withdraw_unbonded(RuntimeOrigin::signed(10)),
+            Error::<Test>::NothingToWithdraw
+        );
+        System::set_block_number(1 + UNBONDING_PERIOD);
+        assert_ok!(HumanodeStaking::withdraw_unbonded(RuntimeOrigin::signed(
+            10
+        )));
+        assert_eq!(locked(10), 300);
+        assert!(HumanodeStaking::unbonding(10).is_empty());
+        System::assert_has_event(RuntimeEvent::HumanodeStaking(Event::Withdrawn {
+            delegator: 10,
+            amount: 200,
+        }));
+
+        // Assert the lock is removed once everything is withdrawn.
+        assert_ok!(HumanodeStaking::undelegate(
+            RuntimeOrigin::signed(10),
+            VALIDATOR,
+            300
+        ));
+        assert_eq!(
+            HumanodeStaking::validator_stake(VALIDATOR),
+            ValidatorStake::default()
+        );
+        System::set_block_number(1 + 2 * UNBONDING_PERIOD);
+        assert_ok!(HumanodeStaking::withdraw_unbonded(RuntimeOrigin::signed(
+            10
+        )));
+        assert_eq!(locked(10), 0);
+        assert_eq!(HumanodeStaking::bonded(10), 0);
+    });
+}
+
+/// This test verifies that the reward is split between the validator and the delegators
+/// according to the commission and the stake.
+#[test]
+fn reward_split_works() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        assert_ok!(HumanodeStaking::set_commission(
+            RuntimeOrigin::signed(VALIDATOR),
+            Perbill::from_percent(10)
+        ));
+        assert_ok!(HumanodeStaking::delegate(
+            RuntimeOrigin::signed(10),
+            VALIDATOR,
+            300
+        ));
+        assert_ok!(HumanodeStaking::delegate(
+            RuntimeOrigin::signed(11),
+            VALIDATOR,
+            100
+        ));
+
+        // Invoke the function under test.
+        let validator_reward = HumanodeStaking::split(&VALIDATOR, 1001);
+
+        // Assert the rewards are accumulated for the delegators, and not settled yet.
+        assert_eq!(validator_reward, 100);
+        assert_eq!(
+            HumanodeStaking::rewards_per_stake(VALIDATOR),
+            FixedU128::from_rational(901, 400)
+        );
+        assert_eq!(HumanodeStaking::unclaimed_rewards(10), 0);
+        assert_eq!(HumanodeStaking::total_unclaimed_rewards(), 901);
+        assert_eq!(HumanodeStaking::unclaimed(), 901);
+
+        // Assert the rewards are settled, rounded down, as the delegations change.
+        assert_ok!(HumanodeStaking::undelegate(
+            RuntimeOrigin::signed(10),
+            VALIDATOR,
+            100
+        ));
+        assert_ok!(HumanodeStaking::delegate(
+            RuntimeOrigin::signed(11),
+            VALIDATOR,
+            100
+        ));
+        assert_eq!(HumanodeStaking::unclaimed_rewards(10), 675);
+        assert_eq!(HumanodeStaking::unclaimed_rewards(11), 225);
+
+        // Assert the validators without the delegators get the whole reward.
+        assert_eq!(HumanodeStaking::split(&2, 1001), 1001);
+    });
+}
+
+/// This test verifies that the rewards follow the delegations as they change.
+#[test]
+fn rewards_follow_delegations() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        Balances::make_free_balance_be(&POT, 10000);
+        assert_ok!(HumanodeStaking::set_commission(
+            RuntimeOrigin::signed(VALIDATOR),
+            Perbill::from_percent(10)
+        ));
+        assert_ok!(HumanodeStaking::delegate(
+            RuntimeOrigin::signed(10),
+            VALIDATOR,
+            100
+        ));
+
+        // Invoke the function under test.
+        HumanodeStaking::split(&VALIDATOR, 1000);
+        assert_ok!(HumanodeStaking::delegate(
+            RuntimeOrigin::signed(11),
+            VALIDATOR,
+            200
+        ));
+        HumanodeStaking::split(&VALIDATOR, 1000);
+
+        // Assert the delegator that joined later only gets the later rewards.
+        assert_ok!(HumanodeStaking::claim_rewards(
+            RuntimeOrigin::signed(10),
+            VALIDATOR
+        ));
+        assert_ok!(HumanodeStaking::claim_rewards(
+            RuntimeOrigin::signed(11),
+            VALIDATOR
+        ));
+        assert_eq!(Balances::free_balance(10), 1000 + 900 + 300);
+        assert_eq!(Balances::free_balance(11), 1000 + 600);
+        assert_eq!(HumanodeStaking::total_unclaimed_rewards(), 0);
+    });
+}
+
+/// This test verifies that the accrued rewards are paid out of the pot when claimed.
+#[test]
+fn claim_rewards_works() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        Balances::make_free_balance_be(&POT, 1000);
+        assert_ok!(HumanodeStaking::set_commission(
+            RuntimeOrigin::signed(VALIDATOR),
+            Perbill::from_percent(10)
+        ));
+        assert_ok!(HumanodeStaking::delegate(
+            RuntimeOrigin::signed(10),
+            VALIDATOR,
+            300
+        ));
+        HumanodeStaking::split(&VALIDATOR, 1000);
+
+        // Invoke the function under test.
+        assert_ok!(HumanodeStaking::claim_rewards(
+            RuntimeOrigin::signed(10),
+            VALIDATOR
+        ));
+
+        // Assert state changes.
+        assert_eq!(Balances::free_balance(10), 1000 + 900);
+        assert_eq!(Balances::free_balance(POT), 1000 - 900);
+        assert_eq!(HumanodeStaking::unclaimed_rewards(10), 0);
+        assert_eq!(HumanodeStaking::total_unclaimed_rewards(), 0);
+        System::assert_has_event(RuntimeEvent::HumanodeStaking(Event::RewardsClaimed {
+            delegator: 10,
+            amount: 900,
+        }));
+
+        // Assert there is nothing left to claim.
+        assert_noop!(
+            HumanodeStaking::claim_rewards(RuntimeOrigin::signed(10), VALIDATOR),
+            Error::<Test>::NothingToClaim
+        );
+    });
+}
@@ -0,0 +1,50 @@
+//! Weights definition for pallet-humanode-staking.
+
+use frame_support::weights::Weight;
+
+/// Weight functions needed for pallet-humanode-staking.
+pub trait WeightInfo {
+    /// A function to calculate required weights for `set_commission` call.
+    fn set_commission() -> Weight;
+
+    /// A function to calculate required weights for `delegate` call.
+    fn delegate() -> Weight;
+
+    /// A function to calculate required weights for `undelegate` call.
+    fn undelegate() -> Weight;
+
+    /// A function to calculate required weights for `withdraw_unbonded` call.
+    fn withdraw_unbonded() -> Weight;
+
+    /// A function to calculate required weights for `claim_rewards` call.
+    fn claim_rewards() -> Weight;
+
+    /// A function to calculate required weights for splitting a validator session reward.
+    fn split_reward() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn set_commission() -> Weight {
+        Weight::zero()
+    }
+
+    fn delegate() -> Weight {
+        Weight::zero()
+    }
+
+    fn undelegate() -> Weight {
+        Weight::zero()
+    }
+
+    fn withdraw_unbonded() -> Weight {
+        Weight::zero()
+    }
+
+    fn claim_rewards() -> Weight {
+        Weight::zero()
+    }
+
+    fn split_reward() -> Weight {
+        Weight::zero()
+    }
+}
@@ -0,0 +1,83 @@
+//! The benchmarks for the pallet.
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+
+use crate::*;
+
+/// The benchmark interface into the environment.
+pub trait Interface: super::Config {
+    /// Make the given accounts the validators of the current session.
+    fn set_validators(validators: Vec<<Self as frame_system::Config>::AccountId>);
+
+    /// The max amount of the validators in a session.
+    fn max_validators() -> u32;
+}
+
+/// Fill the rewards pot with plenty of the balance to reward from.
+fn fill_pot<T: Config>() {
+    T::Currency::make_free_balance_be(
+        &T::RewardsPotAccountId::get(),
+        T::Currency::minimum_balance().saturating_mul(1_000_000_000u32.into()),
+    );
+}
+
+benchmarks! {
+    where_clause {
+        where
+            T: Interface,
+    }
+
+    end_session {
+        let v in 1 .. <T as Interface>::max_validators();
+
+        let validators = (0..v)
+            .map(|index| account("validator", index, 0))
+            .collect::<Vec<T::AccountId>>();
+        // The worst case is every validator having participated, and thus being rewarded, while
+        // having both of the participation records to clear.
+        for validator in &validators {
+            <BlocksAuthored<T>>::insert(validator, 1);
+            <Unresponsive<T>>::insert(validator, ());
+        }
+        <T as Interface>::set_validators(validators.clone());
+        fill_pot::<T>();
+    }: {
+        Pallet::<T>::end_session(0);
+    }
+    verify {
+        assert!(validators
+            .iter()
+            .all(|validator| !<UnclaimedRewards<T>>::get(validator).is_zero()));
+    }
+
+    claim_rewards {
+        let validator: T::AccountId = account("validator", 0, 0);
+        // The worst case is the claim to a new account.
+        let amount = T::Currency::minimum_balance();
+        fill_pot::<T>();
+        <UnclaimedRewards<T>>::insert(&validator, amount);
+        <TotalUnclaimedRewards<T>>::put(amount);
+    }: _(RawOrigin::Signed(validator.clone()))
+    verify {
+        assert_eq!(T::Currency::free_balance(&validator), amount);
+        assert!(<TotalUnclaimedRewards<T>>::get().is_zero());
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::mock::new_test_ext(),
+        crate::mock::Test,
+    );
+}
+
+#[cfg(test)]
+impl Interface for crate::mock::Test {
+    fn set_validators(validators: Vec<<Self as frame_system::Config>::AccountId>) {
+        crate::mock::Validators::set(validators);
+    }
+
+    fn max_validators() -> u32 {
+        100
+    }
+}
@@ -0,0 +1,144 @@
+//! The formulas for computing the session rewards.
+
+use frame_support::traits::Get;
+use sp_runtime::{traits::AtLeast32BitUnsigned, Perbill};
+use sp_std::{marker::PhantomData, prelude::*};
+
+use crate::ParticipationRecord;
+
+/// The formula that computes the rewards for the session participants.
+pub trait RewardFormula<AccountId, Balance> {
+    /// Compute the rewards for the validators of the ended session out of the `available` balance.
+    ///
+    /// The total of the returned rewards must not exceed the `available` balance.
+    fn rewards(
+        available: Balance,
+        participants: &[(AccountId, ParticipationRecord)],
+    ) -> Vec<(AccountId, Balance)>;
+}
+
+/// Split the `Share` of the available balance equally among the validators that participated in
+/// the session.
+pub struct EqualShare<Share>(PhantomData<Share>);
+
+impl<AccountId, Balance, Share> RewardFormula<AccountId, Balance> for EqualShare<Share>
+where
+    AccountId: Clone,
+    Balance: AtLeast32BitUnsigned + Copy,
+    Share: Get<Perbill>,
+{
+    fn rewards(
+        available: Balance,
+        participants: &[(AccountId, ParticipationRecord)],
+    ) -> Vec<(AccountId, Balance)> {
+        let participated = participants
+            .iter()
+            .filter(|(_, record)| record.participated())
+            .collect::<Vec<_>>();
+
+        let participated_count = match u32::try_from(participated.len()) {
+            Ok(count) => count,
+            Err(_) => return Vec::new(),
+        };
+
+        let budget = Share::get().mul_floor(available);
+        let reward = match budget.checked_div(&Balance::from(participated_count)) {
+            Some(reward) => reward,
+            None => return Vec::new(),
+        };
+
+        participated
+            .into_iter()
+            .map(|(account_id, _)| (account_id.clone(), reward))
+            .collect()
+    }
+}
+
+/// Split the `Share` of the available balance among the validators proportionally to the amount
+/// of the blocks they have authored during the session.
+pub struct AuthorshipProportional<Share>(PhantomData<Share>);
+
+impl<AccountId, Balance, Share> RewardFormula<AccountId, Balance> for AuthorshipProportional<Share>
+where
+    AccountId: Clone,
+    Balance: AtLeast32BitUnsigned + Copy,
+    Share: Get<Perbill>,
+{
+    fn rewards(
+        available: Balance,
+        participants: &[(AccountId, ParticipationRecord)],
+    ) -> Vec<(AccountId, Balance)> {
+        let total_blocks = participants.iter().fold(0u32, |total, (_, record)| {
+            total.saturating_add(record.blocks_authored)
+        });
+
+        let budget = Share::get().mul_floor(available);
+
+        participants
+            .iter()
+            .filter(|(_, record)| record.blocks_authored > 0)
+            .map(|(account_id, record)| {
+                let share = Perbill::from_rational(record.blocks_authored, total_blocks);
+                (account_id.clone(), share.mul_floor(budget))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use frame_support::parameter_types;
+
+    use super::*;
+
+    parameter_types! {
+        pub const Half: Perbill = Perbill::from_percent(50);
+    }
+
+    fn record(blocks_authored: u32, responsive: bool) -> ParticipationRecord {
+        ParticipationRecord {
+            blocks_authored,
+            responsive,
+        }
+    }
+
+    /// This test verifies that the equal share is split only among the participated validators.
+    #[test]
+    fn equal_share_skips_absent_validators() {
+        let participants = vec![
+            (1u64, record(3, true)),
+            (2, record(0, true)),
+            (3, record(0, false)),
+            (4, record(1, false)),
+        ];
+
+        let rewards = <EqualShare<Half> as RewardFormula<_, u64>>::rewards(1000, &participants);
+
+        assert_eq!(rewards, vec![(1, 166), (2, 166), (4, 166)]);
+    }
+
+    /// This test verifies that the equal share computes no rewards when nobody participated.
+    #[test]
+    fn equal_share_without_participants() {
+        let participants = vec![(1u64, record(0, false))];
+
+        let rewards = <EqualShare<Half> as RewardFormula<_, u64>>::rewards(1000, &participants);
+
+        assert_eq!(rewards, vec![]);
+    }
+
+    /// This test verifies that the authorship proportional share follows the authored blocks.
+    #[test]
+    fn authorship_proportional_follows_blocks() {
+        let participants = vec![
+            (1u64, record(3, true)),
+            (2, record(1, true)),
+            (3, record(0, true)),
+        ];
+
+        let rewards =
+            <AuthorshipProportional<Half> as RewardFormula<_, u64>>::rewards(1000, &participants);
+
+        assert_eq!(rewards, vec![(1, 375), (2, 125)]);
+    }
+}
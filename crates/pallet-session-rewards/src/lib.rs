@@ -0,0 +1,391 @@
+//! A substrate pallet that records the participation of the validators in the sessions, and
+//! rewards the participated validators at the session end.
+//!
+//! The participation consists of the blocks authored by the validator (as reported by
+//! the `pallet_authorship`) and the responsiveness of the validator (as judged by the ImOnline
+//! heartbeats).
+//!
+//! The rewards are not paid out at the session end, but are accrued in the rewards pot for
+//! the validators to claim, so that the session end only does a bounded amount of work per
+//! validator.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    dispatch::DispatchClass,
+    traits::{Currency, ExistenceRequirement, Get, StorageVersion, ValidatorSet as ValidatorSetT},
+    weights::Weight,
+};
+pub use pallet::*;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_staking::{
+    offence::{Offence, OffenceError, ReportOffence},
+    SessionIndex,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+pub use weights::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+pub mod formula;
+pub mod weights;
+
+pub use formula::RewardFormula;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+/// The balance type for a given config.
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// The participation of a validator in a session.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    scale_info::TypeInfo,
+    codec::MaxEncodedLen,
+    codec::Encode,
+    codec::Decode,
+)]
+pub struct ParticipationRecord {
+    /// The amount of blocks the validator has authored during the session.
+    pub blocks_authored: u32,
+    /// Whether the validator was not reported as unresponsive by the ImOnline.
+    pub responsive: bool,
+}
+
+impl ParticipationRecord {
+    /// Whether the validator has participated in the session at all.
+    pub fn participated(&self) -> bool {
+        self.blocks_authored > 0 || self.responsive
+    }
+}
+
+/// The split of the validator reward between the validator and the ones backing it.
+pub trait RewardSplit<AccountId, Balance> {
+    /// Split the reward of the validator, returning the part of the reward due to
+    /// the validator itself.
+    ///
+    /// The rest of the reward stays in the rewards pot for the ones backing the validator to
+    /// claim, and must be accounted for in the [`RewardSplit::unclaimed`] until then.
+    fn split(validator: &AccountId, reward: Balance) -> Balance;
+
+    /// The total of the rewards split off to the ones backing the validators that is still
+    /// unclaimed.
+    fn unclaimed() -> Balance;
+
+    /// The max weight of a single [`RewardSplit::split`].
+    fn split_weight() -> Weight;
+}
+
+impl<AccountId, Balance: Zero> RewardSplit<AccountId, Balance> for () {
+    fn split(_validator: &AccountId, reward: Balance) -> Balance {
+        reward
+    }
+
+    fn unclaimed() -> Balance {
+        Zero::zero()
+    }
+
+    fn split_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+// We have to temporarily allow some clippy lints. Later on we'll send patches to substrate to
+// fix them at their end.
+#[allow(clippy::missing_docs_in_private_items)]
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    use super::*;
+
+    /// Configure the pallet by specifying the parameters and types on which it depends.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The currency to pay the rewards in.
+        type Currency: Currency<Self::AccountId>;
+
+        /// The account of the pot to pay the rewards from, that also keeps the unclaimed rewards.
+        type RewardsPotAccountId: Get<Self::AccountId>;
+
+        /// The set of the validators of the current session.
+        type ValidatorSet: ValidatorSetT<Self::AccountId, ValidatorId = Self::AccountId>;
+
+        /// The formula for computing the rewards of the session participants.
+        type RewardFormula: RewardFormula<Self::AccountId, BalanceOf<Self>>;
+
+        /// The split of the validator rewards between the validators and their delegators.
+        type RewardSplit: RewardSplit<Self::AccountId, BalanceOf<Self>>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    /// The amount of blocks authored by the validators during the current session.
+    #[pallet::storage]
+    #[pallet::getter(fn blocks_authored)]
+    pub type BlocksAuthored<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+    /// The validators reported as unresponsive for the current session.
+    #[pallet::storage]
+    pub type Unresponsive<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+    /// The rewards accrued to the validators that are not yet claimed.
+    #[pallet::storage]
+    #[pallet::getter(fn unclaimed_rewards)]
+    pub type UnclaimedRewards<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// The total of the [`UnclaimedRewards`].
+    #[pallet::storage]
+    #[pallet::getter(fn total_unclaimed_rewards)]
+    pub type TotalUnclaimedRewards<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The reward was accrued to the validator for participating in the session.
+        ValidatorRewarded {
+            /// The index of the session the validator was rewarded for.
+            session_index: SessionIndex,
+            /// The rewarded validator.
+            validator: T::AccountId,
+            /// The participation of the validator in the session.
+            participation: ParticipationRecord,
+            /// The reward accrued.
+            amount: BalanceOf<T>,
+        },
+        /// The accrued rewards were claimed.
+        RewardsClaimed {
+            /// The validator that claimed the rewards.
+            validator: T::AccountId,
+            /// The claimed amount.
+            amount: BalanceOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// There are no rewards to claim.
+        NothingToClaim,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Claim the rewards accrued to the validator.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::claim_rewards())]
+        pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResult {
+            let validator = ensure_signed(origin)?;
+
+            let amount = <UnclaimedRewards<T>>::get(&validator);
+            ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+            T::Currency::transfer(
+                &T::RewardsPotAccountId::get(),
+                &validator,
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+
+            <UnclaimedRewards<T>>::remove(&validator);
+            <TotalUnclaimedRewards<T>>::mutate(|total| *total = total.saturating_sub(amount));
+
+            Self::deposit_event(Event::RewardsClaimed { validator, amount });
+
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// The participation of the validator in the current session so far.
+    pub fn participation(validator: &T::AccountId) -> ParticipationRecord {
+        ParticipationRecord {
+            blocks_authored: <BlocksAuthored<T>>::get(validator),
+            responsive: !<Unresponsive<T>>::contains_key(validator),
+        }
+    }
+
+    /// Account for the block authored by the validator.
+    fn note_block_author(author: &T::AccountId) {
+        <BlocksAuthored<T>>::mutate(author, |blocks| *blocks = blocks.saturating_add(1));
+    }
+
+    /// Account for the validator being reported as unresponsive.
+    fn note_unresponsive(validator: &T::AccountId) {
+        <Unresponsive<T>>::insert(validator, ());
+    }
+
+    /// Accrue the rewards to the validators of the ending session, reset the participation
+    /// records, and register the weight of all that.
+    fn end_session(session_index: SessionIndex) {
+        let participants = T::ValidatorSet::validators()
+            .into_iter()
+            .map(|validator| {
+                let participation = Self::participation(&validator);
+                (validator, participation)
+            })
+            .collect::<Vec<_>>();
+        let validators_count = u32::try_from(participants.len()).unwrap_or(u32::MAX);
+
+        let _ = <BlocksAuthored<T>>::clear(u32::MAX, None);
+        let _ = <Unresponsive<T>>::clear(u32::MAX, None);
+
+        let pot = T::RewardsPotAccountId::get();
+        // The pot is kept alive, so we can only use the balance above the existential deposit,
+        // and the unclaimed rewards are already due.
+        let available = T::Currency::free_balance(&pot)
+            .saturating_sub(T::Currency::minimum_balance())
+            .saturating_sub(<TotalUnclaimedRewards<T>>::get())
+            .saturating_sub(T::RewardSplit::unclaimed());
+
+        for (validator, reward) in T::RewardFormula::rewards(available, &participants) {
+            if reward.is_zero() {
+                continue;
+            }
+
+            let participation = participants
+                .iter()
+                .find(|(participant, _)| participant == &validator)
+                .map(|(_, participation)| *participation)
+                .unwrap_or_default();
+
+            let amount = T::RewardSplit::split(&validator, reward);
+            if amount.is_zero() {
+                continue;
+            }
+
+            <UnclaimedRewards<T>>::mutate(&validator, |unclaimed| {
+                *unclaimed = unclaimed.saturating_add(amount)
+            });
+            <TotalUnclaimedRewards<T>>::mutate(|total| *total = total.saturating_add(amount));
+
+            Self::deposit_event(Event::ValidatorRewarded {
+                session_index,
+                validator,
+                participation,
+                amount,
+            });
+        }
+
+        let weight = T::WeightInfo::end_session(validators_count)
+            .saturating_add(T::RewardSplit::split_weight().saturating_mul(validators_count.into()));
+        frame_system::Pallet::<T>::register_extra_weight_unchecked(
+            weight,
+            DispatchClass::Mandatory,
+        );
+    }
+}
+
+impl<T: Config, BlockNumber> pallet_authorship::EventHandler<T::AccountId, BlockNumber>
+    for Pallet<T>
+{
+    fn note_author(author: T::AccountId) {
+        Self::note_block_author(&author);
+    }
+}
+
+/// The session manager that accrues the session rewards at the session end, and otherwise
+/// delegates to the `Inner` session manager.
+pub struct RewardingSessionManager<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T, Inner, FullIdentification>
+    pallet_session::historical::SessionManager<T::AccountId, FullIdentification>
+    for RewardingSessionManager<T, Inner>
+where
+    T: Config,
+    Inner: pallet_session::historical::SessionManager<T::AccountId, FullIdentification>,
+{
+    fn new_session(new_index: SessionIndex) -> Option<Vec<(T::AccountId, FullIdentification)>> {
+        Inner::new_session(new_index)
+    }
+
+    fn new_session_genesis(
+        new_index: SessionIndex,
+    ) -> Option<Vec<(T::AccountId, FullIdentification)>> {
+        Inner::new_session_genesis(new_index)
+    }
+
+    fn start_session(start_index: SessionIndex) {
+        Inner::start_session(start_index)
+    }
+
+    fn end_session(end_index: SessionIndex) {
+        <Pallet<T>>::end_session(end_index);
+        Inner::end_session(end_index)
+    }
+}
+
+impl<T, Inner> pallet_session::SessionManager<T::AccountId> for RewardingSessionManager<T, Inner>
+where
+    T: Config,
+    Inner: pallet_session::SessionManager<T::AccountId>,
+{
+    fn new_session(new_index: SessionIndex) -> Option<Vec<T::AccountId>> {
+        Inner::new_session(new_index)
+    }
+
+    fn new_session_genesis(new_index: SessionIndex) -> Option<Vec<T::AccountId>> {
+        Inner::new_session_genesis(new_index)
+    }
+
+    fn start_session(start_index: SessionIndex) {
+        Inner::start_session(start_index)
+    }
+
+    fn end_session(end_index: SessionIndex) {
+        <Pallet<T>>::end_session(end_index);
+        Inner::end_session(end_index)
+    }
+}
+
+/// The offence reporter that notes the offenders as unresponsive, and then passes the offence
+/// on to the `Inner` reporter.
+///
+/// Intended to be used as the ImOnline unresponsiveness reporter.
+pub struct NoteUnresponsiveness<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T, Inner, Reporter, FullIdentification, O>
+    ReportOffence<Reporter, (T::AccountId, FullIdentification), O>
+    for NoteUnresponsiveness<T, Inner>
+where
+    T: Config,
+    O: Offence<(T::AccountId, FullIdentification)>,
+    Inner: ReportOffence<Reporter, (T::AccountId, FullIdentification), O>,
+{
+    fn report_offence(reporters: Vec<Reporter>, offence: O) -> Result<(), OffenceError> {
+        for (validator, _) in offence.offenders() {
+            <Pallet<T>>::note_unresponsive(&validator);
+        }
+        Inner::report_offence(reporters, offence)
+    }
+
+    fn is_known_offence(
+        offenders: &[(T::AccountId, FullIdentification)],
+        time_slot: &O::TimeSlot,
+    ) -> bool {
+        Inner::is_known_offence(offenders, time_slot)
+    }
+}
@@ -0,0 +1,183 @@
+//! The mock for the pallet.
+
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, ConstU64},
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, ConvertInto, IdentityLookup},
+    BuildStorage, Perbill,
+};
+use sp_staking::{
+    offence::{Kind, Offence},
+    SessionIndex,
+};
+
+use crate::{self as pallet_session_rewards};
+
+pub(crate) const EXISTENTIAL_DEPOSIT: u64 = 10;
+
+/// The account of the rewards pot in tests.
+pub(crate) const POT: AccountId = 100;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type AccountId = u64;
+type Balance = u64;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub struct Test
+    where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        SessionRewards: pallet_session_rewards,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<EXISTENTIAL_DEPOSIT>;
+    type AccountStore = System;
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const RewardsPotAccountId: AccountId = POT;
+    pub const RewardsShare: Perbill = Perbill::from_percent(50);
+    pub static Validators: Vec<AccountId> = vec![];
+    pub static BackedValidators: Vec<AccountId> = vec![];
+    pub static SplitOff: Balance = 0;
+}
+
+/// The validator set that reports the [`Validators`].
+pub struct TestValidatorSet;
+
+impl frame_support::traits::ValidatorSet<AccountId> for TestValidatorSet {
+    type ValidatorId = AccountId;
+    type ValidatorIdOf = ConvertInto;
+
+    fn session_index() -> SessionIndex {
+        0
+    }
+
+    fn validators() -> Vec<AccountId> {
+        Validators::get()
+    }
+}
+
+/// The reward split that splits off half of the reward of the validators listed in
+/// the [`BackedValidators`], and keeps track of the split off rewards in the [`SplitOff`].
+pub struct TestRewardSplit;
+
+impl pallet_session_rewards::RewardSplit<AccountId, Balance> for TestRewardSplit {
+    fn split(validator: &AccountId, reward: Balance) -> Balance {
+        if !BackedValidators::get().contains(validator) {
+            return reward;
+        }
+
+        let backers_share = reward / 2;
+        SplitOff::mutate(|split_off| *split_off += backers_share);
+        reward - backers_share
+    }
+
+    fn unclaimed() -> Balance {
+        SplitOff::get()
+    }
+
+    fn split_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+impl pallet_session_rewards::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type RewardsPotAccountId = RewardsPotAccountId;
+    type ValidatorSet = TestValidatorSet;
+    type RewardFormula = pallet_session_rewards::formula::EqualShare<RewardsShare>;
+    type RewardSplit = TestRewardSplit;
+    type WeightInfo = ();
+}
+
+/// An offence to report in tests.
+pub struct TestOffence {
+    /// The offenders.
+    pub offenders: Vec<(AccountId, ())>,
+}
+
+impl Offence<(AccountId, ())> for TestOffence {
+    const ID: Kind = *b"test:offence____";
+    type TimeSlot = u64;
+
+    fn offenders(&self) -> Vec<(AccountId, ())> {
+        self.offenders.clone()
+    }
+
+    fn session_index(&self) -> SessionIndex {
+        0
+    }
+
+    fn validator_set_count(&self) -> u32 {
+        0
+    }
+
+    fn time_slot(&self) -> Self::TimeSlot {
+        0
+    }
+
+    fn slash_fraction(&self, _offenders_count: u32) -> Perbill {
+        Perbill::zero()
+    }
+}
+
+/// Build test externalities from the default genesis.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = GenesisConfig::default().build_storage().unwrap();
+
+    // Make test externalities from the storage.
+    let mut ext: sp_io::TestExternalities = storage.into();
+    // Events are not deposited at the genesis block.
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,208 @@
+//! The tests for the pallet.
+
+// Allow simple integer arithmetic in tests.
+#![allow(clippy::arithmetic_side_effects)]
+
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use pallet_authorship::EventHandler;
+use pallet_session::historical::SessionManager;
+use sp_staking::offence::ReportOffence;
+
+use crate::{mock::*, *};
+
+/// The session manager under test, with no-op inner session manager.
+type TestSessionManager = RewardingSessionManager<Test, ()>;
+
+/// The reporter under test, with no-op inner reporter.
+type TestReporter = NoteUnresponsiveness<Test, ()>;
+
+/// This test verifies that the authored blocks are accounted for.
+#[test]
+fn block_authorship_is_recorded() {
+    new_test_ext().execute_with(|| {
+        // Invoke the function under test.
+        <SessionRewards as EventHandler<_, u64>>::note_author(1);
+        <SessionRewards as EventHandler<_, u64>>::note_author(1);
+        <SessionRewards as EventHandler<_, u64>>::note_author(2);
+
+        // Assert state changes.
+        assert_eq!(SessionRewards::blocks_authored(1), 2);
+        assert_eq!(SessionRewards::blocks_authored(2), 1);
+        assert_eq!(SessionRewards::blocks_authored(3), 0);
+    });
+}
+
+/// This test verifies that the validators reported as unresponsive are accounted for.
+#[test]
+fn unresponsiveness_is_recorded() {
+    new_test_ext().execute_with(|| {
+        // Invoke the function under test.
+        let offence = TestOffence {
+            offenders: vec![(2, ())],
+        };
+        <TestReporter as ReportOffence<AccountId, _, _>>::report_offence(vec![], offence).unwrap();
+
+        // Assert state changes.
+        assert!(SessionRewards::participation(&1).responsive);
+        assert!(!SessionRewards::participation(&2).responsive);
+    });
+}
+
+/// This test verifies that the rewards are accrued to the participated validators at
+/// the session end, and the participation records are reset.
+#[test]
+fn participated_validators_are_rewarded() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        Validators::set(vec![1, 2, 3]);
+        Balances::make_free_balance_be(&POT, 1000 + EXISTENTIAL_DEPOSIT);
+        <SessionRewards as EventHandler<_, u64>>::note_author(1);
+        <Unresponsive<Test>>::insert(3, ());
+
+        // Invoke the function under test.
+        <TestSessionManager as SessionManager<_, ()>>::end_session(5);
+
+        // Assert state changes.
+        assert_eq!(Balances::free_balance(POT), 1000 + EXISTENTIAL_DEPOSIT);
+        assert_eq!(SessionRewards::unclaimed_rewards(1), 250);
+        assert_eq!(SessionRewards::unclaimed_rewards(2), 250);
+        assert_eq!(SessionRewards::unclaimed_rewards(3), 0);
+        assert_eq!(SessionRewards::total_unclaimed_rewards(), 500);
+        System::assert_has_event(RuntimeEvent::SessionRewards(Event::ValidatorRewarded {
+            session_index: 5,
+            validator: 1,
+            participation: ParticipationRecord {
+                blocks_authored: 1,
+                responsive: true,
+            },
+            amount: 250,
+        }));
+        System::assert_has_event(RuntimeEvent::SessionRewards(Event::ValidatorRewarded {
+            session_index: 5,
+            validator: 2,
+            participation: ParticipationRecord {
+                blocks_authored: 0,
+                responsive: true,
+            },
+            amount: 250,
+        }));
+        assert_eq!(<BlocksAuthored<Test>>::iter().count(), 0);
+        assert_eq!(<Unresponsive<Test>>::iter().count(), 0);
+    });
+}
+
+/// This test verifies that nothing is rewarded out of the empty pot.
+#[test]
+fn empty_pot_pays_nothing() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        Validators::set(vec![1]);
+        Balances::make_free_balance_be(&POT, EXISTENTIAL_DEPOSIT);
+        <SessionRewards as EventHandler<_, u64>>::note_author(1);
+
+        // Invoke the function under test.
+        <TestSessionManager as SessionManager<_, ()>>::end_session(1);
+
+        // Assert state changes.
+        assert_eq!(SessionRewards::unclaimed_rewards(1), 0);
+        assert_eq!(SessionRewards::total_unclaimed_rewards(), 0);
+        assert!(System::events()
+            .iter()
+            .all(|record| !matches!(record.event, RuntimeEvent::SessionRewards(_))));
+    });
+}
+
+/// This test verifies that the rewards still unclaimed from the pot are not given out again.
+#[test]
+fn unclaimed_rewards_are_not_given_out_again() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        Validators::set(vec![1, 2]);
+        Balances::make_free_balance_be(&POT, 1000 + EXISTENTIAL_DEPOSIT);
+        <UnclaimedRewards<Test>>::insert(1, 400);
+        <TotalUnclaimedRewards<Test>>::put(400);
+        SplitOff::set(200);
+
+        // Invoke the function under test.
+        <TestSessionManager as SessionManager<_, ()>>::end_session(1);
+
+        // Assert state changes.
+        assert_eq!(SessionRewards::unclaimed_rewards(1), 400 + 100);
+        assert_eq!(SessionRewards::unclaimed_rewards(2), 100);
+        assert_eq!(SessionRewards::total_unclaimed_rewards(), 600);
+    });
+}
+
+/// This test verifies that the validator reward is split with the ones backing the validator.
+#[test]
+fn reward_is_split() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        Validators::set(vec![1]);
+        BackedValidators::set(vec![1]);
+        Balances::make_free_balance_be(&POT, 100 + EXISTENTIAL_DEPOSIT);
+
+        // Invoke the function under test.
+        <TestSessionManager as SessionManager<_, ()>>::end_session(2);
+
+        // Assert state changes.
+        assert_eq!(SessionRewards::unclaimed_rewards(1), 25);
+        assert_eq!(SplitOff::get(), 25);
+        System::assert_has_event(RuntimeEvent::SessionRewards(Event::ValidatorRewarded {
+            session_index: 2,
+            validator: 1,
+            participation: ParticipationRecord {
+                blocks_authored: 0,
+                responsive: true,
+            },
+            amount: 25,
+        }));
+    });
+}
+
+/// This test verifies that the accrued rewards are paid out of the pot when claimed.
+#[test]
+fn claim_rewards_works() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        Balances::make_free_balance_be(&POT, 100 + EXISTENTIAL_DEPOSIT);
+        <UnclaimedRewards<Test>>::insert(1, 100);
+        <TotalUnclaimedRewards<Test>>::put(100);
+
+        // Invoke the function under test.
+        assert_ok!(SessionRewards::claim_rewards(RuntimeOrigin::signed(1)));
+
+        // Assert state changes.
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::free_balance(POT), EXISTENTIAL_DEPOSIT);
+        assert_eq!(SessionRewards::unclaimed_rewards(1), 0);
+        assert_eq!(SessionRewards::total_unclaimed_rewards(), 0);
+        System::assert_has_event(RuntimeEvent::SessionRewards(Event::RewardsClaimed {
+            validator: 1,
+            amount: 100,
+        }));
+
+        // Assert there is nothing left to claim.
+        assert_noop!(
+            SessionRewards::claim_rewards(RuntimeOrigin::signed(1)),
+            Error::<Test>::NothingToClaim
+        );
+    });
+}
+
+/// This test verifies that the rewards stay unclaimed if they can not be paid out.
+#[test]
+fn failed_claim_keeps_rewards() {
+    new_test_ext().execute_with(|| {
+        // Prepare the test state.
+        Balances::make_free_balance_be(&POT, 100 + EXISTENTIAL_DEPOSIT);
+        <UnclaimedRewards<Test>>::insert(1, EXISTENTIAL_DEPOSIT - 1);
+        <TotalUnclaimedRewards<Test>>::put(EXISTENTIAL_DEPOSIT - 1);
+
+        // Invoke the function under test.
+        assert_noop!(
+            SessionRewards::claim_rewards(RuntimeOrigin::signed(1)),
+            pallet_balances::Error::<Test>::ExistentialDeposit
+        );
+    });
+}
@@ -0,0 +1,23 @@
+//! Weights definition for pallet-session-rewards.
+
+use frame_support::weights::Weight;
+
+/// Weight functions needed for pallet-session-rewards.
+pub trait WeightInfo {
+    /// A function to calculate required weights for the session end with the given amount of
+    /// the validators, not counting the reward splits.
+    fn end_session(validators: u32) -> Weight;
+
+    /// A function to calculate required weights for `claim_rewards` call.
+    fn claim_rewards() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn end_session(_validators: u32) -> Weight {
+        Weight::zero()
+    }
+
+    fn claim_rewards() -> Weight {
+        Weight::zero()
+    }
+}
@@ -176,7 +176,8 @@ impl pallet_bioauth::Config for Test {
     type Moment = UnixMilliseconds;
     type DisplayMoment = DisplayMoment;
     type CurrentMoment = MockCurrentMomentProvider;
-    type AuthenticationsExpireAfter = ConstU64<AUTHENTICATIONS_EXPIRE_AFTER>;
+    type ExpiryPolicy = pallet_bioauth::ExpireAfter<ConstU64<AUTHENTICATIONS_EXPIRE_AFTER>>;
+    type ExpiryWarningPeriod = ConstU64<0>;
     type WeightInfo = ();
     type MaxAuthentications = ConstU32<MAX_AUTHENTICATIONS>;
     type MaxNonces = ConstU32<MAX_NONCES>;
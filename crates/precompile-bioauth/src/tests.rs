@@ -4,17 +4,11 @@ use precompile_utils::{Bytes, EvmDataWriter};
 
 use crate::{mock::*, *};
 
-type TestAuthentication = pallet_bioauth::Authentication<
-    <Test as pallet_bioauth::Config>::ValidatorPublicKey,
-    <Test as pallet_bioauth::Config>::Moment,
->;
+type TestAuthentication = pallet_bioauth::AuthenticationFor<Test>;
 
 fn make_bounded_authentications(
-    authentications: Vec<pallet_bioauth::Authentication<ValidatorPublicKey, UnixMilliseconds>>,
-) -> BoundedVec<
-    pallet_bioauth::Authentication<ValidatorPublicKey, UnixMilliseconds>,
-    ConstU32<MAX_AUTHENTICATIONS>,
-> {
+    authentications: Vec<TestAuthentication>,
+) -> BoundedVec<TestAuthentication, ConstU32<MAX_AUTHENTICATIONS>> {
     BoundedVec::<_, ConstU32<MAX_AUTHENTICATIONS>>::try_from(authentications).unwrap()
 }
 
@@ -90,6 +84,7 @@ fn test_is_authenticated_true() {
             TestAuthentication {
                 public_key: sample_key,
                 expires_at: 1,
+                ..Default::default()
             },
         ]));
 
@@ -0,0 +1,290 @@
+//! Client API for the Humanode's Bioauth Robonode.
+
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::{error_response::ErrorResponse, Client, Error};
+
+impl Client {
+    /// Perform the enroll and authenticate call to the server.
+    pub async fn enroll_and_authenticate(
+        &self,
+        req: EnrollAndAuthenticateRequest<'_>,
+    ) -> Result<EnrollAndAuthenticateResponse, Error<EnrollAndAuthenticateError>> {
+        let url = format!("{}/enroll-and-authenticate", self.base_url);
+        let res = self.reqwest.post(url).json(&req).send().await?;
+        match res.status() {
+            StatusCode::OK => Ok(res.json().await?),
+            status => Err(Error::Call(EnrollAndAuthenticateError::from_response(
+                status,
+                res.text().await?,
+            ))),
+        }
+    }
+}
+
+/// Input data for the enroll and authenticate request.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnrollAndAuthenticateRequest<'a> {
+    /// The public key to be used as an identity.
+    pub public_key: &'a [u8],
+    /// An opaque liveness data, containing the FaceScan to associate with the identity and
+    /// the rest of the parameters necessary to conduct a liveness check.
+    pub liveness_data: &'a [u8],
+    /// The signature of the liveness data with the private key of the node.
+    /// Proves the posession of the private key by the liveness data bearer.
+    pub liveness_data_signature: &'a [u8],
+}
+
+/// Response data for the enroll and authenticate request.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EnrollAndAuthenticateResponse {
+    /// An opaque auth ticket generated for the enrolled public key.
+    pub auth_ticket: Box<[u8]>,
+    /// The robonode signature for this opaque auth ticket.
+    pub auth_ticket_signature: Box<[u8]>,
+}
+
+/// The enroll-and-authenticate-specific error condition.
+#[derive(Error, Debug, PartialEq)]
+pub enum EnrollAndAuthenticateError {
+    /// The public key is invalid.
+    #[error("invalid public key")]
+    InvalidPublicKey,
+    /// The liveness data is invalid.
+    #[error("invalid liveness data")]
+    InvalidLivenessData,
+    /// The liveness data signature is invalid.
+    #[error("signature invalid")]
+    SignatureInvalid,
+    /// The face scan was rejeted.
+    #[error("face scan rejected")]
+    FaceScanRejected,
+    /// The public key is already used.
+    #[error("public key already used")]
+    PublicKeyAlreadyUsed,
+    /// The person is already enrolled.
+    #[error("person already enrolled")]
+    PersonAlreadyEnrolled,
+    /// A logic internal error occured on the server end.
+    #[error("logic internal error")]
+    LogicInternal,
+    /// An error with an unknown code occured.
+    #[error("unknown error code: {0}")]
+    UnknownCode(String),
+    /// Some other error occured.
+    #[error("unknown error: {0}")]
+    Unknown(String),
+}
+
+impl EnrollAndAuthenticateError {
+    /// Parse the error response.
+    fn from_response(_status: StatusCode, body: String) -> Self {
+        let error_code = match body.try_into() {
+            Ok(ErrorResponse { error_code }) => error_code,
+            Err(body) => return Self::Unknown(body),
+        };
+        match error_code.as_str() {
+            "ENROLL_AND_AUTHENTICATE_INVALID_PUBLIC_KEY" => Self::InvalidPublicKey,
+            "ENROLL_AND_AUTHENTICATE_INVALID_LIVENESS_DATA" => Self::InvalidLivenessData,
+            "ENROLL_AND_AUTHENTICATE_SIGNATURE_INVALID" => Self::SignatureInvalid,
+            "ENROLL_AND_AUTHENTICATE_FACE_SCAN_REJECTED" => Self::FaceScanRejected,
+            "ENROLL_AND_AUTHENTICATE_PUBLIC_KEY_ALREADY_USED" => Self::PublicKeyAlreadyUsed,
+            "ENROLL_AND_AUTHENTICATE_PERSON_ALREADY_ENROLLED" => Self::PersonAlreadyEnrolled,
+            "LOGIC_INTERNAL_ERROR" => Self::LogicInternal,
+            _ => Self::UnknownCode(error_code),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::test_utils::mkerr;
+
+    #[test]
+    fn request_serialization() {
+        let expected_request = serde_json::json!({
+            "livenessData": [1, 2, 3],
+            "publicKey": [4, 5, 6],
+            "livenessDataSignature": [7, 8, 9],
+        });
+
+        let actual_request = serde_json::to_value(&EnrollAndAuthenticateRequest {
+            liveness_data: &[1, 2, 3],
+            public_key: &[4, 5, 6],
+            liveness_data_signature: &[7, 8, 9],
+        })
+        .unwrap();
+
+        assert_eq!(expected_request, actual_request);
+    }
+
+    #[test]
+    fn response_deserialization() {
+        let sample_response = serde_json::json!({
+            "authTicket": [1, 2, 3],
+            "authTicketSignature": [4, 5, 6],
+        });
+
+        let response: EnrollAndAuthenticateResponse =
+            serde_json::from_value(sample_response).unwrap();
+        assert_eq!(
+            response,
+            EnrollAndAuthenticateResponse {
+                auth_ticket: vec![1, 2, 3].into(),
+                auth_ticket_signature: vec![4, 5, 6].into(),
+            }
+        )
+    }
+
+    #[tokio::test]
+    async fn mock_success() {
+        let mock_server = MockServer::start().await;
+
+        let sample_request = EnrollAndAuthenticateRequest {
+            liveness_data: b"dummy liveness data",
+            public_key: b"123",
+            liveness_data_signature: b"signature",
+        };
+        let sample_response = serde_json::json!({
+            "authTicket": b"456",
+            "authTicketSignature": b"789",
+        });
+
+        let expected_response: EnrollAndAuthenticateResponse =
+            serde_json::from_value(sample_response.clone()).unwrap();
+
+        Mock::given(matchers::method("POST"))
+            .and(matchers::path("/enroll-and-authenticate"))
+            .and(matchers::body_json(&sample_request))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&sample_response))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client {
+            base_url: mock_server.uri(),
+            reqwest: reqwest::Client::new(),
+        };
+
+        let actual_response = client
+            .enroll_and_authenticate(sample_request)
+            .await
+            .unwrap();
+        assert_eq!(actual_response, expected_response);
+    }
+
+    #[tokio::test]
+    async fn mock_error_response() {
+        let cases = [
+            (
+                StatusCode::BAD_REQUEST,
+                "ENROLL_AND_AUTHENTICATE_INVALID_PUBLIC_KEY",
+                EnrollAndAuthenticateError::InvalidPublicKey,
+            ),
+            (
+                StatusCode::BAD_REQUEST,
+                "ENROLL_AND_AUTHENTICATE_INVALID_LIVENESS_DATA",
+                EnrollAndAuthenticateError::InvalidLivenessData,
+            ),
+            (
+                StatusCode::BAD_REQUEST,
+                "ENROLL_AND_AUTHENTICATE_SIGNATURE_INVALID",
+                EnrollAndAuthenticateError::SignatureInvalid,
+            ),
+            (
+                StatusCode::FORBIDDEN,
+                "ENROLL_AND_AUTHENTICATE_FACE_SCAN_REJECTED",
+                EnrollAndAuthenticateError::FaceScanRejected,
+            ),
+            (
+                StatusCode::CONFLICT,
+                "ENROLL_AND_AUTHENTICATE_PUBLIC_KEY_ALREADY_USED",
+                EnrollAndAuthenticateError::PublicKeyAlreadyUsed,
+            ),
+            (
+                StatusCode::CONFLICT,
+                "ENROLL_AND_AUTHENTICATE_PERSON_ALREADY_ENROLLED",
+                EnrollAndAuthenticateError::PersonAlreadyEnrolled,
+            ),
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "LOGIC_INTERNAL_ERROR",
+                EnrollAndAuthenticateError::LogicInternal,
+            ),
+            (
+                StatusCode::BAD_REQUEST,
+                "MY_ERR_CODE",
+                EnrollAndAuthenticateError::UnknownCode("MY_ERR_CODE".to_owned()),
+            ),
+        ];
+
+        for case in cases {
+            let mock_server = MockServer::start().await;
+
+            let sample_request = EnrollAndAuthenticateRequest {
+                liveness_data: b"dummy liveness data",
+                liveness_data_signature: b"signature",
+                public_key: b"123",
+            };
+
+            let response = ResponseTemplate::new(case.0).set_body_json(mkerr(case.1));
+
+            Mock::given(matchers::method("POST"))
+                .and(matchers::path("/enroll-and-authenticate"))
+                .and(matchers::body_json(&sample_request))
+                .respond_with(response)
+                .mount(&mock_server)
+                .await;
+
+            let client = Client {
+                base_url: mock_server.uri(),
+                reqwest: reqwest::Client::new(),
+            };
+
+            let actual_error = client
+                .enroll_and_authenticate(sample_request)
+                .await
+                .unwrap_err();
+            assert_matches!(actual_error, Error::Call(err) if err == case.2);
+        }
+    }
+
+    #[tokio::test]
+    async fn mock_error_unknown() {
+        let mock_server = MockServer::start().await;
+
+        let sample_request = EnrollAndAuthenticateRequest {
+            liveness_data: b"dummy liveness data",
+            liveness_data_signature: b"signature",
+            public_key: b"123",
+        };
+        let sample_response = "Some error text";
+
+        Mock::given(matchers::method("POST"))
+            .and(matchers::path("/enroll-and-authenticate"))
+            .and(matchers::body_json(&sample_request))
+            .respond_with(ResponseTemplate::new(500).set_body_string(sample_response))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client {
+            base_url: mock_server.uri(),
+            reqwest: reqwest::Client::new(),
+        };
+
+        let actual_error = client
+            .enroll_and_authenticate(sample_request)
+            .await
+            .unwrap_err();
+        assert_matches!(
+            actual_error,
+            Error::Call(EnrollAndAuthenticateError::Unknown(error_text)) if error_text == sample_response
+        );
+    }
+}
@@ -4,6 +4,7 @@ use thiserror::Error;
 
 mod authenticate;
 mod enroll;
+mod enroll_and_authenticate;
 mod error_response;
 mod get_facetec_device_sdk_params;
 mod get_facetec_session_token;
@@ -12,6 +13,7 @@ mod test_utils;
 
 pub use authenticate::*;
 pub use enroll::*;
+pub use enroll_and_authenticate::*;
 pub use get_facetec_device_sdk_params::*;
 pub use get_facetec_session_token::*;
 
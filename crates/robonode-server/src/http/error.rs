@@ -3,8 +3,8 @@
 use warp::hyper::StatusCode;
 
 use crate::logic::{
-    op_authenticate, op_enroll, op_get_facetec_device_sdk_params, op_get_facetec_session_token,
-    op_get_public_key,
+    op_authenticate, op_enroll, op_enroll_and_authenticate, op_get_facetec_device_sdk_params,
+    op_get_facetec_session_token, op_get_public_key,
 };
 
 /// A logic error.
@@ -92,6 +92,61 @@ impl From<op_authenticate::Error> for Logic {
     }
 }
 
+impl From<op_enroll_and_authenticate::Error> for Logic {
+    fn from(err: op_enroll_and_authenticate::Error) -> Self {
+        match err {
+            op_enroll_and_authenticate::Error::Enroll(op_enroll::Error::InvalidPublicKey) => {
+                Self::new(
+                    StatusCode::BAD_REQUEST,
+                    "ENROLL_AND_AUTHENTICATE_INVALID_PUBLIC_KEY",
+                )
+            }
+            op_enroll_and_authenticate::Error::Enroll(op_enroll::Error::InvalidLivenessData(_)) => {
+                Self::new(
+                    StatusCode::BAD_REQUEST,
+                    "ENROLL_AND_AUTHENTICATE_INVALID_LIVENESS_DATA",
+                )
+            }
+            op_enroll_and_authenticate::Error::Enroll(op_enroll::Error::SignatureInvalid) => {
+                Self::new(
+                    StatusCode::BAD_REQUEST,
+                    "ENROLL_AND_AUTHENTICATE_SIGNATURE_INVALID",
+                )
+            }
+            op_enroll_and_authenticate::Error::Enroll(op_enroll::Error::FaceScanRejected) => {
+                Self::new(
+                    StatusCode::FORBIDDEN,
+                    "ENROLL_AND_AUTHENTICATE_FACE_SCAN_REJECTED",
+                )
+            }
+            op_enroll_and_authenticate::Error::Enroll(op_enroll::Error::PublicKeyAlreadyUsed) => {
+                Self::new(
+                    StatusCode::CONFLICT,
+                    "ENROLL_AND_AUTHENTICATE_PUBLIC_KEY_ALREADY_USED",
+                )
+            }
+            op_enroll_and_authenticate::Error::Enroll(op_enroll::Error::PersonAlreadyEnrolled) => {
+                Self::new(
+                    StatusCode::CONFLICT,
+                    "ENROLL_AND_AUTHENTICATE_PERSON_ALREADY_ENROLLED",
+                )
+            }
+            op_enroll_and_authenticate::Error::Enroll(
+                op_enroll::Error::InternalErrorEnrollment(_)
+                | op_enroll::Error::InternalErrorEnrollmentUnsuccessful
+                | op_enroll::Error::InternalErrorDbSearch(_)
+                | op_enroll::Error::InternalErrorDbSearchUnsuccessful
+                | op_enroll::Error::InternalErrorDbEnroll(_)
+                | op_enroll::Error::InternalErrorSignatureVerificationFailed
+                | op_enroll::Error::InternalErrorDbEnrollUnsuccessful,
+            )
+            | op_enroll_and_authenticate::Error::InternalErrorAuthTicketSigningFailed => {
+                INTERNAL.clone()
+            }
+        }
+    }
+}
+
 impl From<op_get_facetec_device_sdk_params::Error> for Logic {
     fn from(err: op_get_facetec_device_sdk_params::Error) -> Self {
         match err {}
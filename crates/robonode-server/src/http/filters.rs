@@ -8,8 +8,8 @@ use warp::Filter;
 use crate::{
     http::{error, handlers},
     logic::{
-        op_authenticate, op_enroll, op_get_facetec_device_sdk_params, op_get_facetec_session_token,
-        op_get_public_key, LogicOp,
+        op_authenticate, op_enroll, op_enroll_and_authenticate, op_get_facetec_device_sdk_params,
+        op_get_facetec_session_token, op_get_public_key, LogicOp,
     },
 };
 
@@ -43,6 +43,7 @@ pub fn root<L>(
 where
     L: LogicOp<op_authenticate::Request>
         + LogicOp<op_enroll::Request>
+        + LogicOp<op_enroll_and_authenticate::Request>
         + LogicOp<op_get_facetec_device_sdk_params::Request>
         + LogicOp<op_get_facetec_session_token::Request>
         + LogicOp<op_get_public_key::Request>
@@ -51,6 +52,8 @@ where
     <L as LogicOp<op_enroll::Request>>::Error: Into<error::Logic>,
     <L as LogicOp<op_authenticate::Request>>::Error: Into<error::Logic>,
     <L as LogicOp<op_authenticate::Request>>::Response: Serialize,
+    <L as LogicOp<op_enroll_and_authenticate::Request>>::Error: Into<error::Logic>,
+    <L as LogicOp<op_enroll_and_authenticate::Request>>::Response: Serialize,
     <L as LogicOp<op_get_facetec_device_sdk_params::Request>>::Error: Into<error::Logic>,
     <L as LogicOp<op_get_facetec_device_sdk_params::Request>>::Response: Serialize,
     <L as LogicOp<op_get_facetec_session_token::Request>>::Error: Into<error::Logic>,
@@ -60,6 +63,7 @@ where
 {
     enroll(Arc::clone(&logic))
         .or(authenticate(Arc::clone(&logic)))
+        .or(enroll_and_authenticate(Arc::clone(&logic)))
         .or(get_facetec_session_token(Arc::clone(&logic)))
         .or(get_facetec_device_sdk_params(Arc::clone(&logic)))
         .or(get_public_key(logic))
@@ -96,6 +100,22 @@ where
         .and_then(handlers::authenticate)
 }
 
+/// POST /enroll-and-authenticate with JSON body.
+fn enroll_and_authenticate<L>(
+    logic: Arc<L>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    L: LogicOp<op_enroll_and_authenticate::Request> + Send + Sync,
+    L::Error: Into<error::Logic>,
+    L::Response: Serialize,
+{
+    warp::path!("enroll-and-authenticate")
+        .and(warp::post())
+        .and(with_arc(logic))
+        .and(json_body::<op_enroll_and_authenticate::Request>())
+        .and_then(handlers::enroll_and_authenticate)
+}
+
 /// GET /facetec-session-token.
 fn get_facetec_session_token<L>(
     logic: Arc<L>,
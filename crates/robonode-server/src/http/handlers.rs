@@ -8,8 +8,8 @@ use warp::Reply;
 
 use super::error;
 use crate::logic::{
-    op_authenticate, op_enroll, op_get_facetec_device_sdk_params, op_get_facetec_session_token,
-    op_get_public_key, LogicOp,
+    op_authenticate, op_enroll, op_enroll_and_authenticate, op_get_facetec_device_sdk_params,
+    op_get_facetec_session_token, op_get_public_key, LogicOp,
 };
 
 /// Enroll operation HTTP transport coupling.
@@ -42,6 +42,23 @@ where
     Ok(reply.into_response())
 }
 
+/// Enroll and authenticate operation HTTP transport coupling.
+pub async fn enroll_and_authenticate<L>(
+    logic: Arc<L>,
+    input: op_enroll_and_authenticate::Request,
+) -> Result<impl warp::Reply, warp::Rejection>
+where
+    L: LogicOp<op_enroll_and_authenticate::Request>,
+    L::Error: Into<error::Logic>,
+    L::Response: Serialize,
+{
+    let res = logic.call(input).await.map_err(Into::into)?;
+
+    let reply = warp::reply::json(&res);
+    let reply = warp::reply::with_status(reply, StatusCode::OK);
+    Ok(reply.into_response())
+}
+
 /// Get FaceTec Session Token operation HTTP transport coupling.
 pub async fn get_facetec_session_token<L>(
     logic: Arc<L>,
@@ -13,8 +13,8 @@ use warp::{hyper::StatusCode, Filter, Reply};
 use crate::{
     http::{rejection, root},
     logic::{
-        op_authenticate, op_enroll, op_get_facetec_device_sdk_params, op_get_facetec_session_token,
-        op_get_public_key, LogicOp,
+        op_authenticate, op_enroll, op_enroll_and_authenticate, op_get_facetec_device_sdk_params,
+        op_get_facetec_session_token, op_get_public_key, LogicOp,
     },
 };
 
@@ -22,6 +22,7 @@ mock! {
     Logic {
         fn enroll(&self, req: op_enroll::Request) -> Result<op_enroll::Response, op_enroll::Error>;
         fn authenticate(&self, req: op_authenticate::Request) -> Result<op_authenticate::Response, op_authenticate::Error>;
+        fn enroll_and_authenticate(&self, req: op_enroll_and_authenticate::Request) -> Result<op_enroll_and_authenticate::Response, op_enroll_and_authenticate::Error>;
         fn get_facetec_session_token(&self, req: op_get_facetec_session_token::Request) -> Result<op_get_facetec_session_token::Response, op_get_facetec_session_token::Error>;
         fn get_facetec_device_sdk_params(&self, req: op_get_facetec_device_sdk_params::Request) -> Result<op_get_facetec_device_sdk_params::Response, op_get_facetec_device_sdk_params::Error>;
         fn get_public_key(&self, req: op_get_public_key::Request) -> Result<op_get_public_key::Response, op_get_public_key::Error>;
@@ -152,6 +153,14 @@ impl_Logic!(
     authenticate
 );
 
+impl_Logic!(
+    MockLogic,
+    op_enroll_and_authenticate::Request,
+    op_enroll_and_authenticate::Response,
+    op_enroll_and_authenticate::Error,
+    enroll_and_authenticate
+);
+
 impl_Logic!(
     MockLogic,
     op_get_facetec_session_token::Request,
@@ -236,6 +245,29 @@ trivial_success_tests! [
         })),
     },
 
+    /// This test verifies getting expected HTTP response during
+    /// succesfull enroll and authenticate request.
+    {
+        test_name = enroll_and_authenticate_success,
+        method = "POST",
+        path = "/enroll-and-authenticate",
+        input = op_enroll_and_authenticate::Request {
+            public_key: b"key".to_vec(),
+            liveness_data: OpaqueLivenessData(b"data".to_vec()),
+            liveness_data_signature: b"signature".to_vec(),
+        },
+        mocked_call = expect_enroll_and_authenticate,
+        injected_response = op_enroll_and_authenticate::Response {
+            auth_ticket: OpaqueAuthTicket(b"ticket".to_vec()),
+            auth_ticket_signature: b"signature".to_vec(),
+        },
+        expected_status = StatusCode::OK,
+        expected_response = SuccessResponse::Json(serde_json::json!({
+            "authTicket": b"ticket".to_vec(),
+            "authTicketSignature": b"signature".to_vec(),
+        })),
+    },
+
     /// This test verifies getting expected HTTP response during
     /// succesfull get_facetec_session_token request.
     {
@@ -739,6 +771,57 @@ trivial_error_tests! [
         expected_code = "LOGIC_INTERNAL_ERROR",
     },
 
+    /// This test verifies getting expected HTTP response
+    /// during failer enroll and authenticate request with PersonAlreadyEnrolled error.
+    {
+        test_name = enroll_and_authenticate_error_person_already_enrolled,
+        method = "POST",
+        path = "/enroll-and-authenticate",
+        input = op_enroll_and_authenticate::Request {
+            public_key: b"key".to_vec(),
+            liveness_data: OpaqueLivenessData(b"data".to_vec()),
+            liveness_data_signature: b"signature".to_vec(),
+        },
+        mocked_call = expect_enroll_and_authenticate,
+        injected_error = op_enroll_and_authenticate::Error::Enroll(op_enroll::Error::PersonAlreadyEnrolled),
+        expected_status = StatusCode::CONFLICT,
+        expected_code = "ENROLL_AND_AUTHENTICATE_PERSON_ALREADY_ENROLLED",
+    },
+
+    /// This test verifies getting expected HTTP response
+    /// during failer enroll and authenticate request with FaceScanRejected error.
+    {
+        test_name = enroll_and_authenticate_error_face_scan_rejected,
+        method = "POST",
+        path = "/enroll-and-authenticate",
+        input = op_enroll_and_authenticate::Request {
+            public_key: b"key".to_vec(),
+            liveness_data: OpaqueLivenessData(b"data".to_vec()),
+            liveness_data_signature: b"signature".to_vec(),
+        },
+        mocked_call = expect_enroll_and_authenticate,
+        injected_error = op_enroll_and_authenticate::Error::Enroll(op_enroll::Error::FaceScanRejected),
+        expected_status = StatusCode::FORBIDDEN,
+        expected_code = "ENROLL_AND_AUTHENTICATE_FACE_SCAN_REJECTED",
+    },
+
+    /// This test verifies getting expected HTTP response
+    /// during failer enroll and authenticate request with InternalErrorAuthTicketSigningFailed error.
+    {
+        test_name = enroll_and_authenticate_error_internal_auth_ticket_signing_failed,
+        method = "POST",
+        path = "/enroll-and-authenticate",
+        input = op_enroll_and_authenticate::Request {
+            public_key: b"key".to_vec(),
+            liveness_data: OpaqueLivenessData(b"data".to_vec()),
+            liveness_data_signature: b"signature".to_vec(),
+        },
+        mocked_call = expect_enroll_and_authenticate,
+        injected_error = op_enroll_and_authenticate::Error::InternalErrorAuthTicketSigningFailed,
+        expected_status = StatusCode::INTERNAL_SERVER_ERROR,
+        expected_code = "LOGIC_INTERNAL_ERROR",
+    },
+
     /// This test verifies getting expected HTTP response during
     /// failer get_facetec_session_token request with internal error.
     {
@@ -1,4 +1,4 @@
-//! Common logic parameters.
+//! Common logic parameters and utilities.
 
 /// This is the error message that FaceTec server returns when it
 /// encounters an `externalDatabaseRefID` that is already in use.
@@ -11,3 +11,12 @@ pub const EXTERNAL_DATABASE_REF_ID_ALREADY_IN_USE_ERROR_MESSAGE: &str =
 pub const DB_GROUP_NAME: &str = "humanode";
 /// The match level to use throughout the code.
 pub const MATCH_LEVEL: i64 = 10;
+
+/// Make an authentication nonce.
+// TODO(#306): we don't want to expose our internal sequence number, so this value should
+// be hashed, or obfuscated by other means.
+pub fn make_authentication_nonce(execution_id: uuid::Uuid, sequence_value: u64) -> Vec<u8> {
+    let mut data = Vec::from(&execution_id.as_bytes()[..]);
+    data.extend_from_slice(&sequence_value.to_ne_bytes()[..]);
+    data
+}
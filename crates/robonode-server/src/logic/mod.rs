@@ -11,6 +11,7 @@ mod common;
 mod facetec_utils;
 pub mod op_authenticate;
 pub mod op_enroll;
+pub mod op_enroll_and_authenticate;
 pub mod op_get_facetec_device_sdk_params;
 pub mod op_get_facetec_session_token;
 pub mod op_get_public_key;
@@ -208,15 +208,6 @@ fn make_tmp_external_database_ref_id(execution_id: uuid::Uuid, sequence_value: u
     format!("tmp-{execution_id}-{sequence_value}")
 }
 
-/// Make an authentication nonce.
-// TODO(#306): we don't want to expose our internal sequence number, so this value should
-// be hashed, or obfuscated by other means.
-fn make_authentication_nonce(execution_id: uuid::Uuid, sequence_value: u64) -> Vec<u8> {
-    let mut data = Vec::from(&execution_id.as_bytes()[..]);
-    data.extend_from_slice(&sequence_value.to_ne_bytes()[..]);
-    data
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,100 @@
+//! Enroll and authenticate operation.
+//!
+//! Enrolls the validator and issues the auth ticket for it with a single FaceScan, since
+//! a successful enrollment already proves the FaceScan is associated with the public key.
+
+use primitives_auth_ticket::{AuthTicket, OpaqueAuthTicket};
+use primitives_liveness_data::OpaqueLivenessData;
+use serde::{Deserialize, Serialize};
+
+use super::{common::*, op_enroll, Logic, LogicOp, Signer, Verifier};
+
+/// The request for the enroll and authenticate operation.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+    /// The public key of the validator.
+    pub public_key: Vec<u8>,
+    /// The liveness data that the validator owner provided.
+    pub liveness_data: OpaqueLivenessData,
+    /// The signature of the liveness data with the private key of the node.
+    /// Proves the posession of the private key by the liveness data bearer.
+    pub liveness_data_signature: Vec<u8>,
+}
+
+/// The response for the enroll and authenticate operation.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    /// An opaque auth ticket generated for the enrolled public key.
+    pub auth_ticket: OpaqueAuthTicket,
+    /// The signature of the auth ticket, signed with the robonode's private key.
+    pub auth_ticket_signature: Vec<u8>,
+}
+
+/// The errors on the enroll and authenticate operation.
+#[derive(Debug)]
+pub enum Error {
+    /// The enrollment has failed.
+    Enroll(op_enroll::Error),
+    /// Internal error when signing auth ticket.
+    InternalErrorAuthTicketSigningFailed,
+}
+
+#[async_trait::async_trait]
+impl<S, PK> LogicOp<Request> for Logic<S, PK>
+where
+    S: Signer<Vec<u8>> + Send + 'static + Sync,
+    PK: Send + Sync + for<'a> TryFrom<&'a [u8]> + AsRef<[u8]> + Verifier<Vec<u8>>,
+{
+    type Response = Response;
+    type Error = Error;
+
+    /// An enroll and authenticate invocation handler.
+    async fn call(&self, req: Request) -> Result<Self::Response, Self::Error> {
+        let public_key = req.public_key.clone();
+
+        LogicOp::<op_enroll::Request>::call(
+            self,
+            op_enroll::Request {
+                public_key: req.public_key,
+                liveness_data: req.liveness_data,
+                liveness_data_signature: req.liveness_data_signature,
+            },
+        )
+        .await
+        .map_err(Error::Enroll)?;
+
+        let mut unlocked = self.locked.lock().await;
+
+        // Bump the sequence counter.
+        unlocked.sequence.inc();
+        let sequence_value = unlocked.sequence.get();
+
+        // Prepare an authentication nonce from the sequence number.
+        let authentication_nonce = make_authentication_nonce(unlocked.execution_id, sequence_value);
+
+        // Prepare the raw auth ticket for the public key that has just been enrolled.
+        let auth_ticket = AuthTicket {
+            public_key,
+            authentication_nonce,
+        };
+
+        // Prepare an opaque auth ticket, get ready for signing.
+        #[allow(clippy::needless_borrow)]
+        let opaque_auth_ticket = (&auth_ticket).into();
+
+        // Sign the auth ticket with our private key, so that later on it's possible to validate
+        // this ticket was issues by us.
+        let auth_ticket_signature = unlocked
+            .signer
+            .sign(&opaque_auth_ticket)
+            .await
+            .map_err(|_| Error::InternalErrorAuthTicketSigningFailed)?;
+
+        Ok(Response {
+            auth_ticket: opaque_auth_ticket,
+            auth_ticket_signature,
+        })
+    }
+}
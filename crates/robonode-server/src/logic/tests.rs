@@ -257,3 +257,31 @@ async fn double_enroll() {
         super::op_enroll::Error::PersonAlreadyEnrolled
     ));
 }
+
+#[tokio::test]
+#[tracing_test::traced_test]
+async fn combined_enroll_and_authenticate() {
+    let (_guard, test_params, logic) = setup().await;
+
+    let res = logic
+        .call(super::op_enroll_and_authenticate::Request {
+            liveness_data: test_params.enroll_liveness_data,
+            liveness_data_signature: b"qwe".to_vec(),
+            public_key: TEST_PUBLIC_KEY.to_vec(),
+        })
+        .await
+        .unwrap();
+
+    let auth_ticket = primitives_auth_ticket::AuthTicket::try_from(&res.auth_ticket).unwrap();
+    assert_eq!(auth_ticket.public_key, TEST_PUBLIC_KEY);
+
+    info!("enroll and authenticate complete, authenticating now");
+
+    logic
+        .call(super::op_authenticate::Request {
+            liveness_data: test_params.authenticate_liveness_data,
+            liveness_data_signature: b"qwe".to_vec(),
+        })
+        .await
+        .unwrap();
+}
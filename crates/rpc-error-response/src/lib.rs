@@ -6,6 +6,8 @@ use jsonrpsee::{
 };
 use serde::Serialize;
 
+pub mod registry;
+
 /// A simple error without the custom error data.
 pub fn simple(code: i32, message: impl Into<String>) -> Error {
     raw(code, message, Option::<()>::None)
@@ -21,3 +23,89 @@ pub fn raw<T: Serialize>(code: i32, message: impl Into<String>, data: Option<T>)
     let error_object = ErrorObject::owned(code, message, data);
     Error::Call(CallError::Custom(error_object))
 }
+
+/// A registered error without the typed payload.
+pub fn coded(error_code: &registry::ErrorCode, message: impl Into<String>) -> Error {
+    typed(error_code, message, NoPayload {})
+}
+
+/// A registered error with the typed payload.
+///
+/// The payload fields are served alongside the error code in the error data.
+pub fn typed<T: Serialize>(
+    error_code: &registry::ErrorCode,
+    message: impl Into<String>,
+    payload: T,
+) -> Error {
+    data(
+        error_code.category,
+        message,
+        TypedData {
+            error_code: error_code.code,
+            payload,
+        },
+    )
+}
+
+/// The error data of the registered errors.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TypedData<T> {
+    /// The registered error code.
+    error_code: i32,
+    /// The typed payload.
+    #[serde(flatten)]
+    payload: T,
+}
+
+/// The empty payload.
+#[derive(Debug, Serialize)]
+struct NoPayload {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(error: Error) -> String {
+        let error: ErrorObject = error.into();
+        serde_json::to_string(&error).unwrap()
+    }
+
+    #[test]
+    fn coded_error() {
+        assert_eq!(
+            render(coded(&registry::SIGN_FAILED, "signing failed")),
+            "{\"code\":100,\"message\":\"signing failed\",\"data\":{\"errorCode\":101}}"
+        );
+    }
+
+    #[test]
+    fn typed_error() {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Payload {
+            should_retry: bool,
+        }
+
+        assert_eq!(
+            render(typed(
+                &registry::ROBONODE_FACE_SCAN_REJECTED,
+                "face scan rejected",
+                Payload { should_retry: true }
+            )),
+            "{\"code\":200,\"message\":\"face scan rejected\",\"data\":{\"errorCode\":204,\"shouldRetry\":true}}"
+        );
+    }
+
+    #[test]
+    fn typed_error_optional_payload() {
+        assert_eq!(
+            render(typed(
+                &registry::TX_FAILED,
+                "Transaction failed",
+                Option::<NoPayload>::None
+            )),
+            "{\"code\":400,\"message\":\"Transaction failed\",\"data\":{\"errorCode\":407}}"
+        );
+    }
+}
@@ -0,0 +1,469 @@
+//! The shared registry of the machine-readable RPC error codes.
+//!
+//! Every error is served with the code of its category as the JSON-RPC error code, and with
+//! a stable fine-grained error code as the `errorCode` field of the error data, accompanied by
+//! the typed payload fields specific to the error.
+
+use serde_json::{json, Map, Value};
+
+/// The JSON-RPC error codes of the error categories.
+pub mod category {
+    /// Signer has failed.
+    pub const SIGN: i32 = 100;
+
+    /// Request to robonode has failed.
+    pub const ROBONODE: i32 = 200;
+
+    /// Call to runtime api has failed.
+    pub const RUNTIME_API: i32 = 300;
+
+    /// Transaction has failed.
+    pub const TRANSACTION: i32 = 400;
+
+    /// Validator key is not available.
+    pub const MISSING_VALIDATOR_KEY: i32 = 500;
+
+    /// Validator key extraction has failed.
+    pub const VALIDATOR_KEY_EXTRACTION: i32 = 600;
+
+    /// The request is not acceptable.
+    pub const REQUEST: i32 = 700;
+}
+
+/// A registered error code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorCode {
+    /// The stable numeric error code.
+    pub code: i32,
+    /// The category of the error, used as the JSON-RPC error code.
+    pub category: i32,
+    /// The stable symbolic name of the error.
+    pub name: &'static str,
+    /// The description of the error.
+    pub description: &'static str,
+    /// The payload fields that the error data carries in addition to the error code.
+    pub payload: &'static [PayloadField],
+}
+
+/// A field of the typed error payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PayloadField {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The JSON type of the field.
+    pub json_type: &'static str,
+    /// The description of the field.
+    pub description: &'static str,
+}
+
+/// The payload of the errors that request a retry with a new liveness data.
+const SHOULD_RETRY_PAYLOAD: &[PayloadField] = &[PayloadField {
+    name: "shouldRetry",
+    json_type: "boolean",
+    description: "Always true, the request should be retried with a new liveness data.",
+}];
+
+/// The payload of the missing validator key errors.
+const VALIDATOR_KEY_NOT_AVAILABLE_PAYLOAD: &[PayloadField] = &[PayloadField {
+    name: "validatorKeyNotAvailable",
+    json_type: "boolean",
+    description: "Always true, the validator key has to be inserted into the keystore.",
+}];
+
+/// The payload of the transaction pool errors.
+const TX_ERROR_DETAILS_PAYLOAD: &[PayloadField] = &[
+    PayloadField {
+        name: "kind",
+        json_type: "string",
+        description: "The transaction error kind.",
+    },
+    PayloadField {
+        name: "message",
+        json_type: "string",
+        description: "The human-friendly message for what happened.",
+    },
+    PayloadField {
+        name: "innerError",
+        json_type: "string",
+        description: "The message from the inner transaction pool error.",
+    },
+];
+
+/// Signing with the validator key has failed.
+pub const SIGN_FAILED: ErrorCode = ErrorCode {
+    code: 101,
+    category: category::SIGN,
+    name: "SIGN_FAILED",
+    description: "Signing with the validator key has failed.",
+    payload: &[],
+};
+
+/// The robonode rejected the public key as invalid.
+pub const ROBONODE_INVALID_PUBLIC_KEY: ErrorCode = ErrorCode {
+    code: 201,
+    category: category::ROBONODE,
+    name: "ROBONODE_INVALID_PUBLIC_KEY",
+    description: "The robonode rejected the public key as invalid.",
+    payload: &[],
+};
+
+/// The robonode rejected the liveness data as invalid.
+pub const ROBONODE_INVALID_LIVENESS_DATA: ErrorCode = ErrorCode {
+    code: 202,
+    category: category::ROBONODE,
+    name: "ROBONODE_INVALID_LIVENESS_DATA",
+    description: "The robonode rejected the liveness data as invalid.",
+    payload: &[],
+};
+
+/// The robonode rejected the liveness data signature.
+pub const ROBONODE_SIGNATURE_INVALID: ErrorCode = ErrorCode {
+    code: 203,
+    category: category::ROBONODE,
+    name: "ROBONODE_SIGNATURE_INVALID",
+    description: "The robonode rejected the liveness data signature.",
+    payload: &[],
+};
+
+/// The robonode rejected the face scan.
+pub const ROBONODE_FACE_SCAN_REJECTED: ErrorCode = ErrorCode {
+    code: 204,
+    category: category::ROBONODE,
+    name: "ROBONODE_FACE_SCAN_REJECTED",
+    description: "The robonode rejected the face scan, the capture should be retried.",
+    payload: SHOULD_RETRY_PAYLOAD,
+};
+
+/// The public key is already used.
+pub const ROBONODE_PUBLIC_KEY_ALREADY_USED: ErrorCode = ErrorCode {
+    code: 205,
+    category: category::ROBONODE,
+    name: "ROBONODE_PUBLIC_KEY_ALREADY_USED",
+    description: "The public key is already used.",
+    payload: &[],
+};
+
+/// The person is already enrolled.
+pub const ROBONODE_PERSON_ALREADY_ENROLLED: ErrorCode = ErrorCode {
+    code: 206,
+    category: category::ROBONODE,
+    name: "ROBONODE_PERSON_ALREADY_ENROLLED",
+    description: "The person is already enrolled.",
+    payload: &[],
+};
+
+/// The person was not found.
+pub const ROBONODE_PERSON_NOT_FOUND: ErrorCode = ErrorCode {
+    code: 207,
+    category: category::ROBONODE,
+    name: "ROBONODE_PERSON_NOT_FOUND",
+    description: "The person was not found, likely because they haven't enrolled yet.",
+    payload: &[],
+};
+
+/// An internal error occured at the robonode.
+pub const ROBONODE_LOGIC_INTERNAL: ErrorCode = ErrorCode {
+    code: 208,
+    category: category::ROBONODE,
+    name: "ROBONODE_LOGIC_INTERNAL",
+    description: "An internal error occured at the robonode.",
+    payload: &[],
+};
+
+/// The robonode responded with an unknown error.
+pub const ROBONODE_UNKNOWN: ErrorCode = ErrorCode {
+    code: 209,
+    category: category::ROBONODE,
+    name: "ROBONODE_UNKNOWN",
+    description: "The robonode responded with an unknown error.",
+    payload: &[],
+};
+
+/// The robonode could not be reached.
+pub const ROBONODE_TRANSPORT: ErrorCode = ErrorCode {
+    code: 210,
+    category: category::ROBONODE,
+    name: "ROBONODE_TRANSPORT",
+    description: "The robonode could not be reached.",
+    payload: &[],
+};
+
+/// The runtime api call has failed.
+pub const RUNTIME_API_CALL: ErrorCode = ErrorCode {
+    code: 301,
+    category: category::RUNTIME_API,
+    name: "RUNTIME_API_CALL",
+    description: "The runtime api call has failed.",
+    payload: &[],
+};
+
+/// The session keys could not be decoded.
+pub const RUNTIME_API_SESSION_KEYS_DECODING: ErrorCode = ErrorCode {
+    code: 302,
+    category: category::RUNTIME_API,
+    name: "RUNTIME_API_SESSION_KEYS_DECODING",
+    description: "The session keys could not be decoded.",
+    payload: &[],
+};
+
+/// The signed extrinsic could not be created.
+pub const RUNTIME_API_SIGNED_EXTRINSIC_CREATION: ErrorCode = ErrorCode {
+    code: 303,
+    category: category::RUNTIME_API,
+    name: "RUNTIME_API_SIGNED_EXTRINSIC_CREATION",
+    description: "The signed extrinsic could not be created.",
+    payload: &[],
+};
+
+/// The unsigned extrinsic data provided for the signing outside of the node is invalid.
+pub const RUNTIME_API_INVALID_UNSIGNED_EXTRINSIC: ErrorCode = ErrorCode {
+    code: 304,
+    category: category::RUNTIME_API,
+    name: "RUNTIME_API_INVALID_UNSIGNED_EXTRINSIC",
+    description: "The provided unsigned extrinsic data is invalid.",
+    payload: &[],
+};
+
+/// The signature made outside of the node is invalid.
+pub const RUNTIME_API_INVALID_SIGNATURE: ErrorCode = ErrorCode {
+    code: 305,
+    category: category::RUNTIME_API,
+    name: "RUNTIME_API_INVALID_SIGNATURE",
+    description: "The provided signature is invalid.",
+    payload: &[],
+};
+
+/// The auth ticket signature is invalid.
+pub const TX_AUTH_TICKET_SIGNATURE_INVALID: ErrorCode = ErrorCode {
+    code: 401,
+    category: category::TRANSACTION,
+    name: "TX_AUTH_TICKET_SIGNATURE_INVALID",
+    description: "The auth ticket signature is invalid.",
+    payload: TX_ERROR_DETAILS_PAYLOAD,
+};
+
+/// The validly signed auth ticket could not be parsed.
+pub const TX_UNABLE_TO_PARSE_AUTH_TICKET: ErrorCode = ErrorCode {
+    code: 402,
+    category: category::TRANSACTION,
+    name: "TX_UNABLE_TO_PARSE_AUTH_TICKET",
+    description: "The validly signed auth ticket could not be parsed.",
+    payload: TX_ERROR_DETAILS_PAYLOAD,
+};
+
+/// The auth ticket nonce has already been used.
+pub const TX_NONCE_ALREADY_USED: ErrorCode = ErrorCode {
+    code: 403,
+    category: category::TRANSACTION,
+    name: "TX_NONCE_ALREADY_USED",
+    description: "The auth ticket nonce has already been used.",
+    payload: TX_ERROR_DETAILS_PAYLOAD,
+};
+
+/// The active authentication already exists.
+pub const TX_ALREADY_AUTHENTICATED: ErrorCode = ErrorCode {
+    code: 404,
+    category: category::TRANSACTION,
+    name: "TX_ALREADY_AUTHENTICATED",
+    description: "The active authentication already exists.",
+    payload: TX_ERROR_DETAILS_PAYLOAD,
+};
+
+/// The fees can not be paid.
+pub const TX_NO_FUNDS: ErrorCode = ErrorCode {
+    code: 405,
+    category: category::TRANSACTION,
+    name: "TX_NO_FUNDS",
+    description: "The fees can not be paid, the account balance is too low.",
+    payload: TX_ERROR_DETAILS_PAYLOAD,
+};
+
+/// The transaction was accepted by the pool, but did not make it into a block.
+pub const TX_NOT_INCLUDED: ErrorCode = ErrorCode {
+    code: 406,
+    category: category::TRANSACTION,
+    name: "TX_NOT_INCLUDED",
+    description: "The transaction was accepted by the pool, but did not make it into a block.",
+    payload: &[],
+};
+
+/// The transaction has failed for some other reason.
+pub const TX_FAILED: ErrorCode = ErrorCode {
+    code: 407,
+    category: category::TRANSACTION,
+    name: "TX_FAILED",
+    description: "The transaction has failed for some other reason.",
+    payload: &[],
+};
+
+/// The authentication was rejected by the checks that run before it.
+pub const TX_REJECTED_BY_BEFORE_AUTH_HOOK: ErrorCode = ErrorCode {
+    code: 408,
+    category: category::TRANSACTION,
+    name: "TX_REJECTED_BY_BEFORE_AUTH_HOOK",
+    description: "The authentication was rejected by the checks that run before it, \
+        e.g. due to the insufficient balance or a ban.",
+    payload: TX_ERROR_DETAILS_PAYLOAD,
+};
+
+/// The validator key is not available.
+pub const VALIDATOR_KEY_NOT_AVAILABLE: ErrorCode = ErrorCode {
+    code: 501,
+    category: category::MISSING_VALIDATOR_KEY,
+    name: "VALIDATOR_KEY_NOT_AVAILABLE",
+    description: "The validator key is not available.",
+    payload: VALIDATOR_KEY_NOT_AVAILABLE_PAYLOAD,
+};
+
+/// The validator key extraction has failed.
+pub const VALIDATOR_KEY_EXTRACTION_FAILED: ErrorCode = ErrorCode {
+    code: 601,
+    category: category::VALIDATOR_KEY_EXTRACTION,
+    name: "VALIDATOR_KEY_EXTRACTION_FAILED",
+    description: "The validator key extraction has failed.",
+    payload: &[],
+};
+
+/// The request carries more items than allowed in a single batch.
+pub const REQUEST_BATCH_TOO_LARGE: ErrorCode = ErrorCode {
+    code: 701,
+    category: category::REQUEST,
+    name: "REQUEST_BATCH_TOO_LARGE",
+    description: "The request carries more items than allowed in a single batch.",
+    payload: &[],
+};
+
+/// All of the registered error codes.
+pub const ALL: &[ErrorCode] = &[
+    SIGN_FAILED,
+    ROBONODE_INVALID_PUBLIC_KEY,
+    ROBONODE_INVALID_LIVENESS_DATA,
+    ROBONODE_SIGNATURE_INVALID,
+    ROBONODE_FACE_SCAN_REJECTED,
+    ROBONODE_PUBLIC_KEY_ALREADY_USED,
+    ROBONODE_PERSON_ALREADY_ENROLLED,
+    ROBONODE_PERSON_NOT_FOUND,
+    ROBONODE_LOGIC_INTERNAL,
+    ROBONODE_UNKNOWN,
+    ROBONODE_TRANSPORT,
+    RUNTIME_API_CALL,
+    RUNTIME_API_SESSION_KEYS_DECODING,
+    RUNTIME_API_SIGNED_EXTRINSIC_CREATION,
+    RUNTIME_API_INVALID_UNSIGNED_EXTRINSIC,
+    RUNTIME_API_INVALID_SIGNATURE,
+    TX_AUTH_TICKET_SIGNATURE_INVALID,
+    TX_UNABLE_TO_PARSE_AUTH_TICKET,
+    TX_NONCE_ALREADY_USED,
+    TX_ALREADY_AUTHENTICATED,
+    TX_NO_FUNDS,
+    TX_NOT_INCLUDED,
+    TX_FAILED,
+    TX_REJECTED_BY_BEFORE_AUTH_HOOK,
+    VALIDATOR_KEY_NOT_AVAILABLE,
+    VALIDATOR_KEY_EXTRACTION_FAILED,
+    REQUEST_BATCH_TOO_LARGE,
+];
+
+/// Generate the JSON schema of the error data for all of the registered error codes.
+pub fn json_schema() -> Value {
+    let codes: Vec<Value> = ALL
+        .iter()
+        .map(|error_code| {
+            json!({
+                "const": error_code.code,
+                "title": error_code.name,
+                "description": error_code.description,
+                "x-category": error_code.category,
+            })
+        })
+        .collect();
+
+    let payloads: Vec<Value> = ALL
+        .iter()
+        .filter(|error_code| !error_code.payload.is_empty())
+        .map(|error_code| {
+            let properties: Map<String, Value> = error_code
+                .payload
+                .iter()
+                .map(|field| {
+                    (
+                        field.name.to_owned(),
+                        json!({ "type": field.json_type, "description": field.description }),
+                    )
+                })
+                .collect();
+            let required: Vec<&str> = error_code.payload.iter().map(|field| field.name).collect();
+            json!({
+                "if": { "properties": { "errorCode": { "const": error_code.code } } },
+                "then": { "properties": properties, "required": required },
+            })
+        })
+        .collect();
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "RpcErrorData",
+        "description": "The data of the RPC errors, identified by the error code.",
+        "type": "object",
+        "required": ["errorCode"],
+        "properties": {
+            "errorCode": { "type": "integer", "oneOf": codes },
+        },
+        "allOf": payloads,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    // Allow simple integer arithmetic in tests.
+    #![allow(clippy::arithmetic_side_effects)]
+
+    use std::collections::HashSet;
+
+    use super::*;
+
+    /// The path to the published JSON schema.
+    const SCHEMA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/error-codes.schema.json");
+
+    #[test]
+    fn codes_are_unique() {
+        let codes: HashSet<_> = ALL.iter().map(|error_code| error_code.code).collect();
+        assert_eq!(codes.len(), ALL.len());
+
+        let names: HashSet<_> = ALL.iter().map(|error_code| error_code.name).collect();
+        assert_eq!(names.len(), ALL.len());
+    }
+
+    #[test]
+    fn codes_belong_to_their_categories() {
+        for error_code in ALL {
+            assert_eq!(
+                error_code.code / 100 * 100,
+                error_code.category,
+                "{}",
+                error_code.name
+            );
+            assert_ne!(error_code.code, error_code.category, "{}", error_code.name);
+        }
+    }
+
+    /// Set the `RPC_ERROR_SCHEMA_UPDATE` env var to regenerate the published schema.
+    #[test]
+    fn published_schema_is_up_to_date() {
+        let schema = json_schema();
+
+        if std::env::var_os("RPC_ERROR_SCHEMA_UPDATE").is_some() {
+            let mut contents = serde_json::to_string_pretty(&schema).unwrap();
+            contents.push('\n');
+            std::fs::write(SCHEMA_PATH, contents).unwrap();
+            return;
+        }
+
+        let published: Value =
+            serde_json::from_str(&std::fs::read_to_string(SCHEMA_PATH).unwrap()).unwrap();
+        assert_eq!(
+            published, schema,
+            "the published schema is outdated, rerun the tests with RPC_ERROR_SCHEMA_UPDATE=1"
+        );
+    }
+}
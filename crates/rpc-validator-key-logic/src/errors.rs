@@ -1,13 +1,8 @@
 //! The validator related error.
 
-/// Custom rpc error codes.
-pub mod api_error_code {
-    /// Validator key is not available.
-    pub const MISSING_VALIDATOR_KEY: i32 = 500;
+use rpc_error_response::registry;
 
-    /// Validator key extraction has failed.
-    pub const VALIDATOR_KEY_EXTRACTION: i32 = 600;
-}
+use crate::error_data;
 
 /// The validator related error kinds.
 #[derive(Debug, thiserror::Error)]
@@ -19,3 +14,51 @@ pub enum Error {
     #[error("unable to extract own key")]
     ValidatorKeyExtraction,
 }
+
+impl From<Error> for jsonrpsee::core::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::MissingValidatorKey => rpc_error_response::typed(
+                &registry::VALIDATOR_KEY_NOT_AVAILABLE,
+                err.to_string(),
+                error_data::ValidatorKeyNotAvailable,
+            ),
+            Error::ValidatorKeyExtraction => rpc_error_response::coded(
+                &registry::VALIDATOR_KEY_EXTRACTION_FAILED,
+                err.to_string(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonrpsee::types::ErrorObject;
+
+    use super::*;
+
+    #[test]
+    fn error_validator_key_extraction() {
+        let error: jsonrpsee::core::Error = Error::ValidatorKeyExtraction.into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message =
+            "{\"code\":600,\"message\":\"unable to extract own key\",\"data\":{\"errorCode\":601}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+
+    #[test]
+    fn error_missing_validator_key() {
+        let error: jsonrpsee::core::Error = Error::MissingValidatorKey.into();
+        let error: ErrorObject = error.into();
+
+        let expected_error_message = "{\"code\":500,\"message\":\"validator key not available\",\"data\":{\"errorCode\":501,\"validatorKeyNotAvailable\":true}}";
+        assert_eq!(
+            expected_error_message,
+            serde_json::to_string(&error).unwrap()
+        );
+    }
+}
@@ -27,3 +27,114 @@ where
         .ok_or(Error::MissingValidatorKey)?;
     Ok(validator_public_key)
 }
+
+/// Try to extract the validator key, or use the requested one if it is available.
+pub fn select_validator_public_key<VKE>(
+    validator_key_exctractor: &VKE,
+    requested_public_key: Option<VKE::PublicKeyType>,
+) -> Result<VKE::PublicKeyType, Error>
+where
+    VKE: KeyExtractorT,
+    VKE::PublicKeyType: PartialEq,
+    VKE::Error: std::fmt::Debug,
+{
+    let requested_public_key = match requested_public_key {
+        Some(requested_public_key) => requested_public_key,
+        None => return validator_public_key(validator_key_exctractor),
+    };
+
+    let available_public_keys = validator_key_exctractor
+        .extract_all_keys()
+        .map_err(|error| {
+            tracing::error!(
+                message = "Unable to extract own keys at bioauth flow RPC",
+                ?error
+            );
+            Error::ValidatorKeyExtraction
+        })?;
+
+    if !available_public_keys.contains(&requested_public_key) {
+        return Err(Error::MissingValidatorKey);
+    }
+
+    Ok(requested_public_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The key extractor serving the keys it is constructed with.
+    struct MockKeyExtractor {
+        /// The available keys, or `None` if the extraction should fail.
+        keys: Option<Vec<u8>>,
+    }
+
+    impl KeyExtractorT for MockKeyExtractor {
+        type Error = &'static str;
+        type PublicKeyType = u8;
+
+        fn extract_key(&self) -> Result<Option<Self::PublicKeyType>, Self::Error> {
+            let keys = self.keys.as_ref().ok_or("extraction failed")?;
+            Ok(keys.first().copied())
+        }
+
+        fn extract_all_keys(&self) -> Result<Vec<Self::PublicKeyType>, Self::Error> {
+            self.keys.clone().ok_or("extraction failed")
+        }
+    }
+
+    #[test]
+    fn selects_extracted_key_when_not_requested() {
+        let extractor = MockKeyExtractor {
+            keys: Some(vec![1, 2]),
+        };
+
+        assert_eq!(select_validator_public_key(&extractor, None).unwrap(), 1);
+    }
+
+    #[test]
+    fn selects_requested_key_when_available() {
+        let extractor = MockKeyExtractor {
+            keys: Some(vec![1, 2]),
+        };
+
+        assert_eq!(select_validator_public_key(&extractor, Some(2)).unwrap(), 2);
+    }
+
+    #[test]
+    fn missing_key_when_not_requested() {
+        let extractor = MockKeyExtractor { keys: Some(vec![]) };
+
+        assert!(matches!(
+            select_validator_public_key(&extractor, None),
+            Err(Error::MissingValidatorKey)
+        ));
+    }
+
+    #[test]
+    fn missing_key_when_requested_key_not_available() {
+        let extractor = MockKeyExtractor {
+            keys: Some(vec![1, 2]),
+        };
+
+        assert!(matches!(
+            select_validator_public_key(&extractor, Some(3)),
+            Err(Error::MissingValidatorKey)
+        ));
+    }
+
+    #[test]
+    fn extraction_failure() {
+        let extractor = MockKeyExtractor { keys: None };
+
+        assert!(matches!(
+            select_validator_public_key(&extractor, None),
+            Err(Error::ValidatorKeyExtraction)
+        ));
+        assert!(matches!(
+            select_validator_public_key(&extractor, Some(1)),
+            Err(Error::ValidatorKeyExtraction)
+        ));
+    }
+}